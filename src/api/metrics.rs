@@ -0,0 +1,130 @@
+//! Timing and outcome of API requests, for the Stats screen's network section
+
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// How many finished requests are kept for the recent list
+const RECENT_REQUESTS: usize = 10;
+
+/// One finished API call, including any retries
+#[derive(Debug, Clone)]
+pub struct RequestRecord {
+    pub endpoint: &'static str,
+    pub latency: Duration,
+    pub ok: bool,
+    pub retries: u32,
+    pub finished_at: DateTime<Local>,
+}
+
+/// Running totals plus the last few requests. Shared between the client and
+/// the UI behind a mutex
+#[derive(Debug, Default)]
+pub struct RequestMetrics {
+    pub total: u64,
+    pub failures: u64,
+    pub retries: u64,
+    total_latency: Duration,
+    recent: VecDeque<RequestRecord>,
+    // Retries not yet copied into the debug log
+    retry_notes: Vec<String>,
+}
+
+impl RequestMetrics {
+    pub fn record(&mut self, record: RequestRecord) {
+        self.total += 1;
+        if !record.ok {
+            self.failures += 1;
+        }
+        self.retries += record.retries as u64;
+        self.total_latency += record.latency;
+
+        if self.recent.len() == RECENT_REQUESTS {
+            self.recent.pop_back();
+        }
+        self.recent.push_front(record);
+    }
+
+    pub fn average_latency(&self) -> Option<Duration> {
+        (self.total > 0).then(|| self.total_latency / self.total as u32)
+    }
+
+    /// Share of requests that failed, as a percentage
+    pub fn error_rate(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.failures as f64 * 100.0 / self.total as f64
+        }
+    }
+
+    /// Note a retry about to happen, for the debug log
+    pub fn note_retry(&mut self, note: String) {
+        self.retry_notes.push(note);
+    }
+
+    /// Retries noted since the last call, oldest first
+    pub fn take_retry_notes(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.retry_notes)
+    }
+
+    /// The most recent requests, newest first
+    pub fn recent(&self) -> impl Iterator<Item = &RequestRecord> {
+        self.recent.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(endpoint: &'static str, millis: u64, ok: bool, retries: u32) -> RequestRecord {
+        RequestRecord {
+            endpoint,
+            latency: Duration::from_millis(millis),
+            ok,
+            retries,
+            finished_at: Local::now(),
+        }
+    }
+
+    #[test]
+    fn empty_metrics_have_no_latency_or_errors() {
+        let metrics = RequestMetrics::default();
+        assert_eq!(metrics.average_latency(), None);
+        assert_eq!(metrics.error_rate(), 0.0);
+        assert_eq!(metrics.recent().count(), 0);
+    }
+
+    #[test]
+    fn record_keeps_running_totals() {
+        let mut metrics = RequestMetrics::default();
+        metrics.record(request("posts", 100, true, 0));
+        metrics.record(request("stats", 300, false, 2));
+        metrics.record(request("posts", 200, true, 1));
+        metrics.record(request("posts", 400, false, 0));
+
+        assert_eq!(metrics.total, 4);
+        assert_eq!(metrics.failures, 2);
+        assert_eq!(metrics.retries, 3);
+        assert_eq!(metrics.average_latency(), Some(Duration::from_millis(250)));
+        assert_eq!(metrics.error_rate(), 50.0);
+    }
+
+    #[test]
+    fn recent_keeps_the_newest_requests_first() {
+        let mut metrics = RequestMetrics::default();
+        for millis in 0..RECENT_REQUESTS as u64 + 3 {
+            metrics.record(request("posts", millis, true, 0));
+        }
+
+        let latencies: Vec<u64> = metrics
+            .recent()
+            .map(|record| record.latency.as_millis() as u64)
+            .collect();
+        let expected: Vec<u64> = (3..RECENT_REQUESTS as u64 + 3).rev().collect();
+        assert_eq!(latencies, expected);
+        // Evicted requests still count toward the totals
+        assert_eq!(metrics.total, RECENT_REQUESTS as u64 + 3);
+    }
+}
@@ -1,8 +1,13 @@
 mod client;
+mod metrics;
 mod models;
 
-pub use client::ApiClient;
+pub use client::{ApiClient, ErrorKind, RetryPolicy};
+pub use metrics::RequestMetrics;
 pub use models::{
     AgentProfile, AgentProfileResponse, Comment, LeaderboardAgent, Post, RecentAgent, SortOrder,
     Stats, SubmoltFull, TimeFilter, TopHuman,
 };
+// Raw response shapes, for deserializing the samples in `fixtures/`
+#[cfg(test)]
+pub(crate) use models::{LeaderboardResponse, PostDetailResponse, PostsResponse, SubmoltsResponse};
@@ -56,6 +56,11 @@ pub struct LeaderboardAgent {
     pub avatar_url: Option<String>,
     pub rank: u32,
     pub owner: Option<AgentOwner>,
+    // Not always included by the leaderboard endpoint
+    #[serde(default, alias = "followerCount")]
+    pub follower_count: Option<i64>,
+    #[serde(default, alias = "postCount", alias = "posts_count")]
+    pub post_count: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -90,6 +95,8 @@ pub struct AgentProfile {
     pub created_at: String,
     pub is_claimed: bool,
     pub owner: Option<AgentOwnerFull>,
+    #[serde(default)]
+    pub avatar_url: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -152,11 +159,6 @@ pub struct PostDetailResponse {
     pub comments: Vec<Comment>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CommentsResponse {
-    pub comments: Vec<Comment>,
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LeaderboardResponse {
     pub leaderboard: Vec<LeaderboardAgent>,
@@ -172,6 +174,12 @@ pub(crate) struct SubmoltsResponse {
     pub submolts: Vec<SubmoltFull>,
 }
 
+#[derive(Debug, Deserialize)]
+pub(crate) struct AnnouncementResponse {
+    #[serde(default, alias = "announcement", alias = "text")]
+    pub message: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TopHuman {
     pub id: String,
@@ -210,6 +218,16 @@ impl SortOrder {
             SortOrder::Random => "random",
         }
     }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "new" => Some(SortOrder::New),
+            "top" => Some(SortOrder::Top),
+            "comments" | "discussed" => Some(SortOrder::Discussed),
+            "random" => Some(SortOrder::Random),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for SortOrder {
@@ -244,6 +262,18 @@ impl TimeFilter {
             TimeFilter::All => "all",
         }
     }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "hour" => Some(TimeFilter::Hour),
+            "day" => Some(TimeFilter::Day),
+            "week" => Some(TimeFilter::Week),
+            "month" => Some(TimeFilter::Month),
+            "year" => Some(TimeFilter::Year),
+            "all" => Some(TimeFilter::All),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for TimeFilter {
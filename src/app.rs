@@ -1,9 +1,40 @@
 use crate::api::{
-    AgentProfile, Comment, LeaderboardAgent, Post, RecentAgent, SortOrder, Stats, SubmoltFull,
-    TimeFilter, TopHuman,
+    AgentProfile, Comment, ErrorKind, LeaderboardAgent, Post, RecentAgent, RequestMetrics,
+    SortOrder, Stats, SubmoltFull, TimeFilter, TopHuman,
 };
-use crate::config::RowDisplay;
-use std::collections::HashSet;
+use crate::avatar::Avatar;
+use crate::config::{
+    Background, ColorMode, FeedColumn, NavTab, Preset, RowDisplay, ThemeName,
+    DEFAULT_COMPACT_NUMBERS_THRESHOLD, DEFAULT_MAX_FEED_POSTS,
+};
+use crate::hyperlink::LinkArea;
+use crate::keymap::Keymap;
+use crate::ui::header::TabLine;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+/// How long rows that changed rank stay highlighted after a leaderboard refresh
+const LEADERBOARD_HIGHLIGHT_SECS: u64 = 5;
+
+/// Thresholds the feed's minimum score filter steps through (0 = off)
+const MIN_SCORE_STEPS: &[i64] = &[0, 1, 5, 10, 25, 50, 100, 250, 500, 1000];
+
+/// Seconds without input before auto-refresh starts backing off
+const IDLE_AFTER_SECS: u64 = 300;
+
+/// How long a status message stays in the footer
+const STATUS_MESSAGE_SECS: u64 = 3;
+
+/// Seconds a load may run before it's assumed lost. Longer than the client's
+/// worst case of three 30s attempts plus backoff, so slow loads aren't cut off
+const LOADING_TIMEOUT_SECS: u64 = 100;
+
+/// Refresh interval while a fast refresh boost is active, and how long it lasts
+const FAST_REFRESH_INTERVAL_SECS: u64 = 3;
+const FAST_REFRESH_DURATION_SECS: u64 = 120;
+
+/// Most entries kept on the History screen; the oldest are dropped first
+const HISTORY_LIMIT: usize = 50;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Screen {
@@ -17,6 +48,154 @@ pub enum Screen {
     Submolts,
     Settings,
     AgentProfile,
+    History,
+    Bookmarks,
+}
+
+/// Client-side ordering for the posts shown on an agent's profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AgentPostsSort {
+    #[default]
+    Date,
+    Score,
+    Comments,
+}
+
+impl AgentPostsSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AgentPostsSort::Date => "Newest",
+            AgentPostsSort::Score => "Top",
+            AgentPostsSort::Comments => "Discussed",
+        }
+    }
+
+    pub fn cycle_next(&self) -> Self {
+        match self {
+            AgentPostsSort::Date => AgentPostsSort::Score,
+            AgentPostsSort::Score => AgentPostsSort::Comments,
+            AgentPostsSort::Comments => AgentPostsSort::Date,
+        }
+    }
+}
+
+/// The page of posts to fetch for the feed as it's currently set up
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedQuery {
+    pub sort: SortOrder,
+    pub time_filter: Option<TimeFilter>,
+    pub offset: i64,
+    pub limit: i64,
+    pub submolt: Option<String>,
+    pub following: bool,
+}
+
+/// How PostDetail lays out comments: the reply tree, or every comment in
+/// one list ordered by time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CommentView {
+    #[default]
+    Tree,
+    Flat,
+}
+
+impl CommentView {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommentView::Tree => "Tree",
+            CommentView::Flat => "Flat",
+        }
+    }
+}
+
+/// A post or agent profile opened this session, listed on the History screen
+#[derive(Debug, Clone)]
+pub enum HistoryEntry {
+    Post(Box<Post>),
+    Agent(String),
+}
+
+impl HistoryEntry {
+    fn is_same(&self, other: &HistoryEntry) -> bool {
+        match (self, other) {
+            (HistoryEntry::Post(a), HistoryEntry::Post(b)) => a.id == b.id,
+            (HistoryEntry::Agent(a), HistoryEntry::Agent(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// What the leaderboard is ranked by. Karma is the API's own order; the
+/// others re-sort the loaded agents locally
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeaderboardMetric {
+    #[default]
+    Karma,
+    Followers,
+    Posts,
+}
+
+impl LeaderboardMetric {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LeaderboardMetric::Karma => "Karma",
+            LeaderboardMetric::Followers => "Followers",
+            LeaderboardMetric::Posts => "Posts",
+        }
+    }
+
+    pub fn cycle_next(&self) -> Self {
+        match self {
+            LeaderboardMetric::Karma => LeaderboardMetric::Followers,
+            LeaderboardMetric::Followers => LeaderboardMetric::Posts,
+            LeaderboardMetric::Posts => LeaderboardMetric::Karma,
+        }
+    }
+
+    /// The agent's value for this metric, if the API provided it
+    pub fn value(&self, agent: &LeaderboardAgent) -> Option<i64> {
+        match self {
+            LeaderboardMetric::Karma => Some(agent.karma),
+            LeaderboardMetric::Followers => agent.follower_count,
+            LeaderboardMetric::Posts => agent.post_count,
+        }
+    }
+}
+
+/// Severity of a debug panel entry, least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum LogLevel {
+    #[default]
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO",
+            LogLevel::Warn => "WARN",
+            LogLevel::Error => "ERROR",
+        }
+    }
+
+    pub fn cycle_next(&self) -> Self {
+        match self {
+            LogLevel::Debug => LogLevel::Info,
+            LogLevel::Info => LogLevel::Warn,
+            LogLevel::Warn => LogLevel::Error,
+            LogLevel::Error => LogLevel::Debug,
+        }
+    }
+}
+
+pub struct DebugEntry {
+    pub level: LogLevel,
+    pub message: String,
+    pub timestamp: chrono::DateTime<chrono::Local>,
 }
 
 pub struct App {
@@ -25,24 +204,71 @@ pub struct App {
     pub selected_index: usize,
     pub sort_order: SortOrder,
     pub time_filter: TimeFilter,
+    // Show the loaded page in reverse of the API's order
+    pub sort_reversed: bool,
     pub current_post: Option<Post>,
     pub comments: Vec<Comment>,
     pub comment_scroll: usize,
     pub selected_comment_index: usize,
     pub collapsed_comments: HashSet<String>,
+    pub comment_view: CommentView,
+    // Hide comments scoring below this (0 = no filtering); their replies stay
+    pub min_comment_score: i64,
+    // Comments scoring below this start collapsed (None = disabled)
+    pub auto_collapse_score: Option<i64>,
+    // Cap on the wrap width of comment text, independent of terminal width
+    pub max_comment_width: Option<usize>,
+    pub post_collapsed: bool,
+    // PostDetail: j/k scroll the post body instead of moving through comments
+    pub post_content_focused: bool,
+    pub post_content_scroll: usize,
     pub seen_post_ids: HashSet<String>,
-    pub new_post_ids: HashSet<String>,
+    // The same IDs, oldest first, so the saved list can keep the newest
+    pub seen_post_order: Vec<String>,
+    // Posts that arrived since they were last seen, and when they arrived
+    pub new_post_ids: HashMap<String, std::time::Instant>,
+    // Posts stop counting as new after this many seconds (None = until opened)
+    pub new_post_highlight_secs: Option<u64>,
     pub last_refresh: Option<std::time::Instant>,
     pub is_loading: bool,
     pub is_background_loading: bool,
     pub error_message: Option<String>,
     pub show_technical_error: bool,
+    // What the current error came from: a rejected key, an unreachable API
+    // or anything else
+    pub error_kind: ErrorKind,
     pub should_quit: bool,
     pub show_help: bool,
+    // Lines the help overlay is scrolled down, on terminals too short for it
+    pub help_scroll: u16,
+    // Last feed page loaded, and the first one still in `posts` (earlier
+    // than `current_page` once further pages have been added below it)
     pub current_page: usize,
+    pub first_page: usize,
+    // Posts each loaded page added to `posts`, from `first_page` on
+    pub loaded_page_sizes: Vec<usize>,
+    // Pages added below since the feed was last replaced, trimmed ones included
+    pub added_pages: usize,
     pub has_more_posts: bool,
     pub spinner_frame: usize,
     pub refresh_interval_secs: u64,
+    // Posts fetched per feed page
+    pub posts_limit: i64,
+    // Feed posts kept before the oldest pages are dropped
+    pub max_feed_posts: usize,
+    // Auto-refresh slows down by this factor while idle (1 = never back off)
+    pub idle_refresh_multiplier: u64,
+    pub last_input: std::time::Instant,
+    // Temporary fast refresh, overriding the interval until this instant
+    pub fast_refresh_until: Option<std::time::Instant>,
+    // Hide feed posts scoring below this (0 = no filtering)
+    pub min_score: i64,
+    // Only show feed posts by this author (client-side)
+    pub author_filter: Option<String>,
+    // Only show feed posts whose title or body contains this (case-insensitive)
+    pub search_query: String,
+    // Keys edit `search_query` while true
+    pub search_active: bool,
     // New API data
     pub stats: Option<Stats>,
     pub leaderboard: Vec<LeaderboardAgent>,
@@ -52,31 +278,132 @@ pub struct App {
     pub leaderboard_selected: usize,
     pub top_pairings_selected: usize,
     pub recent_selected: usize,
+    // Opened posts and profiles, oldest first; shown newest first
+    pub history: Vec<HistoryEntry>,
+    pub history_selected: usize,
+    // Saved posts, newest first; kept in bookmarks.json between sessions
+    pub bookmarks: Vec<Post>,
+    pub bookmarks_selected: usize,
     pub submolts_selected: usize,
     pub submolts_scroll_row: usize,
+    pub pinned_submolts: Vec<String>,
+    // `'` was pressed on the Feed; the next digit opens that pinned submolt
+    pub pinned_jump_pending: bool,
+    // `g` was pressed; a second `g` jumps to the top of the list
+    pub pending_g: bool,
+    // Hide unclaimed agents on the Leaderboard and Recent Agents lists
+    pub hide_unclaimed: bool,
+    // Leaderboard ranks from the previous load, keyed by agent ID
+    pub previous_leaderboard_ranks: HashMap<String, u32>,
+    pub leaderboard_updated_at: Option<std::time::Instant>,
+    pub leaderboard_metric: LeaderboardMetric,
     // Setup screen
     pub api_key_input: String,
     pub setup_error: Option<String>,
     // Debug mode
     pub debug_mode: bool,
-    pub debug_log: Vec<String>,
+    pub debug_log: Vec<DebugEntry>,
+    // Request timings recorded by the API client, shown on the Stats screen
+    pub request_metrics: Arc<Mutex<RequestMetrics>>,
+    // Entries below this level are hidden from the debug panel
+    pub debug_min_level: LogLevel,
+    // Transient footer message (e.g. "Copied to clipboard")
+    pub status_message: Option<(String, std::time::Instant)>,
     // Navigation flag
     pub select_bottom_on_load: bool,
+    // Open the selected post once the next page arrives (stepping past a page edge)
+    pub open_selected_on_load: bool,
+    // Add the next page to the loaded posts instead of replacing them, when
+    // the filters leave too few of them visible
+    pub append_on_load: bool,
     // Settings
     pub settings_selected: usize,
     pub row_display: RowDisplay,
+    pub compact_numbers_threshold: u64,
     // Submolt detail modal
     pub show_submolt_detail: bool,
+    // Submolt feed preview sidebar
+    pub show_submolt_feed_preview: bool,
+    pub submolt_preview_name: Option<String>,
+    pub submolt_preview_posts: Vec<Post>,
     // Currently viewing submolt (None = all posts)
     pub current_submolt: Option<SubmoltFull>,
+    // Feed shows only posts from agents the API key's owner follows (F)
+    pub following_only: bool,
     // Agent profile
     pub agent_profile: Option<AgentProfile>,
     pub agent_posts: Vec<Post>,
     pub agent_posts_selected: usize,
+    pub agent_posts_sort: AgentPostsSort,
+    pub show_owner_details: bool,
+    // Announcement banner on the feed; cleared when dismissed with Esc
+    pub announcement: Option<String>,
+    // Expanded footer listing the current screen's shortcuts
+    pub show_cheatsheet: bool,
+    pub show_read_time: bool,
+    // Append "(domain)" to the meta line of link posts in the feed
+    pub show_link_domains: bool,
+    // Feed list on the left, the selected post's body on the right (wide terminals only)
+    pub split_view: bool,
+    // Wrap URLs in OSC 8 escapes so they can be clicked
+    pub hyperlinks: bool,
+    // Where links were found in the last frame, reprinted as OSC 8 after it's drawn
+    pub hyperlink_areas: Vec<LinkArea>,
+    // Draw agent avatars in the preview sidebar
+    pub avatars: bool,
+    // Decoded avatars by URL; None while downloading or if it failed
+    pub avatar_cache: HashMap<String, Option<Avatar>>,
+    // Clickable header tabs from the last frame: the nav tabs, and the feed's sort tabs
+    pub nav_tab_line: TabLine,
+    pub sort_tab_line: TabLine,
+    // No blank spacer lines between list items, for more rows per screen
+    pub dense_spacing: bool,
+    // Metadata shown on each feed row, in order
+    pub feed_columns: Vec<FeedColumn>,
+    // Header nav tabs, in order; number keys follow their position
+    pub nav_tabs: Vec<NavTab>,
+    pub color_mode: ColorMode,
+    pub background: Background,
+    pub theme: ThemeName,
+    // Verified star next to X handles, and its color (None = theme teal)
+    pub show_verified_badge: bool,
+    pub badge_color: Option<(u8, u8, u8)>,
+    // Report modal: the post being reported (ID, title) and the highlighted reason
+    pub report_reasons: Vec<String>,
+    pub report_target: Option<(String, String)>,
+    pub report_reason_selected: usize,
+    // Saved feed views: the name being typed for a new one, and the picker
+    pub presets: Vec<Preset>,
+    pub preset_name_input: Option<String>,
+    pub show_preset_picker: bool,
+    pub preset_selected: usize,
+    // Related posts modal for the open post
+    pub show_related: bool,
+    pub related_posts: Vec<Post>,
+    pub related_selected: usize,
+    // Page number being typed to jump to
+    pub page_input: Option<String>,
+    // Name prefix being typed to jump to a submolt on the Submolts grid
+    pub submolt_jump_input: Option<String>,
+    // Keys rebound in the config, translated before input is handled
+    pub keymap: Keymap,
+    // Name of the config profile whose API key is in use
+    pub active_profile: Option<String>,
+    // Hide the header on PostDetail / AgentProfile for more reading space
+    pub reading_mode: bool,
+    // PostDetail shows only the post text in a centered column
+    pub focus_mode: bool,
+    // Save the next drawn frame as an ANSI text screenshot
+    pub screenshot_requested: bool,
     pub show_agent_preview: bool,
     pub preview_agent_name: Option<String>,
+    // When the selection last moved with the agent preview open; the
+    // preview loads once it has rested for a moment
+    pub preview_requested_at: Option<std::time::Instant>,
     pub previous_screen: Option<Screen>,
     pub is_preview_loading: bool,
+    // When the current load started, for the stalled spinner watchdog
+    pub loading_since: Option<std::time::Instant>,
     pub show_about: bool,
     pub last_frame_area: Option<(u16, u16)>,
 }
@@ -89,24 +416,48 @@ impl App {
             selected_index: 0,
             sort_order: SortOrder::New,
             time_filter: TimeFilter::Day,
+            sort_reversed: false,
             current_post: None,
             comments: Vec::new(),
             comment_scroll: 0,
             selected_comment_index: 0,
             collapsed_comments: HashSet::new(),
+            comment_view: CommentView::default(),
+            min_comment_score: 0,
+            auto_collapse_score: None,
+            max_comment_width: None,
+            post_collapsed: false,
+            post_content_focused: false,
+            post_content_scroll: 0,
             seen_post_ids: HashSet::new(),
-            new_post_ids: HashSet::new(),
+            seen_post_order: Vec::new(),
+            new_post_ids: HashMap::new(),
+            new_post_highlight_secs: None,
             last_refresh: None,
             is_loading: false,
             is_background_loading: false,
             error_message: None,
             show_technical_error: false,
+            error_kind: ErrorKind::Other,
             should_quit: false,
             show_help: false,
+            help_scroll: 0,
             current_page: 0,
+            first_page: 0,
+            loaded_page_sizes: Vec::new(),
+            added_pages: 0,
             has_more_posts: false,
             spinner_frame: 0,
             refresh_interval_secs: 0,
+            posts_limit: 25,
+            max_feed_posts: DEFAULT_MAX_FEED_POSTS,
+            idle_refresh_multiplier: 1,
+            last_input: std::time::Instant::now(),
+            fast_refresh_until: None,
+            min_score: 0,
+            author_filter: None,
+            search_query: String::new(),
+            search_active: false,
             stats: None,
             leaderboard: Vec::new(),
             top_pairings: Vec::new(),
@@ -115,54 +466,291 @@ impl App {
             leaderboard_selected: 0,
             top_pairings_selected: 0,
             recent_selected: 0,
+            history: Vec::new(),
+            history_selected: 0,
+            bookmarks: Vec::new(),
+            bookmarks_selected: 0,
             submolts_selected: 0,
             submolts_scroll_row: 0,
+            pinned_submolts: Vec::new(),
+            pinned_jump_pending: false,
+            pending_g: false,
+            hide_unclaimed: false,
+            previous_leaderboard_ranks: HashMap::new(),
+            leaderboard_updated_at: None,
+            leaderboard_metric: LeaderboardMetric::default(),
             api_key_input: String::new(),
             setup_error: None,
             debug_mode: false,
             debug_log: Vec::new(),
+            request_metrics: Arc::default(),
+            debug_min_level: LogLevel::default(),
+            status_message: None,
             select_bottom_on_load: false,
+            open_selected_on_load: false,
+            append_on_load: false,
             settings_selected: 0,
             row_display: RowDisplay::default(),
+            compact_numbers_threshold: DEFAULT_COMPACT_NUMBERS_THRESHOLD,
             show_submolt_detail: false,
+            show_submolt_feed_preview: false,
+            submolt_preview_name: None,
+            submolt_preview_posts: Vec::new(),
             current_submolt: None,
+            following_only: false,
             agent_profile: None,
             agent_posts: Vec::new(),
             agent_posts_selected: 0,
+            agent_posts_sort: AgentPostsSort::default(),
+            show_owner_details: false,
+            announcement: None,
+            show_cheatsheet: false,
+            show_read_time: true,
+            show_link_domains: false,
+            split_view: false,
+            hyperlinks: true,
+            hyperlink_areas: Vec::new(),
+            avatars: false,
+            avatar_cache: HashMap::new(),
+            nav_tab_line: TabLine::default(),
+            sort_tab_line: TabLine::default(),
+            dense_spacing: false,
+            feed_columns: FeedColumn::ALL.to_vec(),
+            nav_tabs: NavTab::DEFAULT.to_vec(),
+            color_mode: ColorMode::default(),
+            background: Background::default(),
+            theme: ThemeName::default(),
+            show_verified_badge: true,
+            badge_color: None,
+            report_reasons: Vec::new(),
+            report_target: None,
+            report_reason_selected: 0,
+            presets: Vec::new(),
+            preset_name_input: None,
+            show_preset_picker: false,
+            preset_selected: 0,
+            show_related: false,
+            related_posts: Vec::new(),
+            related_selected: 0,
+            page_input: None,
+            submolt_jump_input: None,
+            keymap: Keymap::default(),
+            active_profile: None,
+            reading_mode: false,
+            focus_mode: false,
+            screenshot_requested: false,
             show_agent_preview: false,
             preview_agent_name: None,
+            preview_requested_at: None,
             previous_screen: None,
             is_preview_loading: false,
+            loading_since: None,
             show_about: false,
             last_frame_area: None,
         }
     }
 
+    /// A new app that already knows the posts seen in earlier sessions
+    pub fn with_seen_posts(ids: Vec<String>) -> Self {
+        let mut app = Self::new();
+        for id in ids {
+            app.mark_seen(id);
+        }
+        app
+    }
+
+    /// Remember a post as seen, so refreshes don't count it as new
+    pub fn mark_seen(&mut self, id: String) {
+        if self.seen_post_ids.insert(id.clone()) {
+            self.seen_post_order.push(id);
+        }
+    }
+
+    /// Copy retries the API client noted since the last tick into the debug log
+    pub fn log_request_retries(&mut self) {
+        let notes = match self.request_metrics.lock() {
+            Ok(mut metrics) => metrics.take_retry_notes(),
+            Err(_) => return,
+        };
+        for note in notes {
+            self.add_debug_with_level(LogLevel::Warn, note);
+        }
+    }
+
     pub fn add_debug(&mut self, msg: String) {
-        let timestamp = chrono::Local::now().format("%H:%M:%S%.3f");
-        self.debug_log.push(format!("[{}] {}", timestamp, msg));
+        self.add_debug_with_level(LogLevel::Debug, msg);
+    }
+
+    pub fn add_debug_with_level(&mut self, level: LogLevel, msg: String) {
+        self.debug_log.push(DebugEntry {
+            level,
+            message: msg,
+            timestamp: chrono::Local::now(),
+        });
         // Keep only last 100 entries
         if self.debug_log.len() > 100 {
             self.debug_log.remove(0);
         }
     }
 
+    /// Show a short message in the footer and record it in the debug log
+    pub fn set_status(&mut self, msg: String) {
+        self.add_debug_with_level(LogLevel::Info, msg.clone());
+        self.status_message = Some((msg, std::time::Instant::now()));
+    }
+
+    /// The current status message, if it hasn't expired yet
+    pub fn active_status(&self) -> Option<&str> {
+        self.status_message
+            .as_ref()
+            .filter(|(_, at)| at.elapsed().as_secs() < STATUS_MESSAGE_SECS)
+            .map(|(msg, _)| msg.as_str())
+    }
+
     pub fn toggle_debug(&mut self) {
         self.debug_mode = !self.debug_mode;
     }
 
+    pub fn cycle_debug_min_level(&mut self) {
+        self.debug_min_level = self.debug_min_level.cycle_next();
+    }
+
+    /// Track how long loading has been active and give up on a load whose
+    /// task was lost (panicked or never reported back)
+    pub fn check_loading_timeout(&mut self) {
+        if !self.is_loading && !self.is_preview_loading {
+            self.loading_since = None;
+            return;
+        }
+
+        let since = *self
+            .loading_since
+            .get_or_insert_with(std::time::Instant::now);
+        if since.elapsed().as_secs() < LOADING_TIMEOUT_SECS {
+            return;
+        }
+
+        self.is_loading = false;
+        self.is_background_loading = false;
+        self.is_preview_loading = false;
+        self.append_on_load = false;
+        self.loading_since = None;
+        self.error_message = Some("Load timed out".to_string());
+        self.add_debug_with_level(LogLevel::Error, "Load timed out".to_string());
+    }
+
+    /// Record user activity, which resets the idle refresh back-off
+    pub fn mark_input(&mut self) {
+        self.last_input = std::time::Instant::now();
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.last_input.elapsed().as_secs() >= IDLE_AFTER_SECS
+    }
+
+    pub fn is_fast_refresh(&self) -> bool {
+        self.fast_refresh_until
+            .is_some_and(|until| std::time::Instant::now() < until)
+    }
+
+    /// Start a short burst of fast refreshes, or end the current one early
+    pub fn toggle_fast_refresh(&mut self) {
+        if self.is_fast_refresh() {
+            self.fast_refresh_until = None;
+            self.set_status("Fast refresh off".to_string());
+        } else {
+            self.fast_refresh_until = Some(
+                std::time::Instant::now()
+                    + std::time::Duration::from_secs(FAST_REFRESH_DURATION_SECS),
+            );
+            self.set_status(format!(
+                "Fast refresh: every {}s for {}s",
+                FAST_REFRESH_INTERVAL_SECS, FAST_REFRESH_DURATION_SECS
+            ));
+        }
+    }
+
+    /// Drop an expired fast refresh boost, reverting to the configured interval
+    pub fn expire_fast_refresh(&mut self) {
+        if self.fast_refresh_until.is_some() && !self.is_fast_refresh() {
+            self.fast_refresh_until = None;
+            self.add_debug("Fast refresh ended".to_string());
+        }
+    }
+
+    /// Stop counting posts as new once they've been new for longer than the
+    /// configured highlight duration
+    pub fn expire_new_posts(&mut self) {
+        let Some(secs) = self.new_post_highlight_secs else {
+            return;
+        };
+        let expired: Vec<String> = self
+            .new_post_ids
+            .iter()
+            .filter(|(_, arrived)| arrived.elapsed().as_secs() >= secs)
+            .map(|(id, _)| id.clone())
+            .collect();
+        // Expired posts count as seen so a later refresh doesn't flag them again
+        for id in expired {
+            self.new_post_ids.remove(&id);
+            self.mark_seen(id);
+        }
+    }
+
+    /// The refresh interval in effect: short during a fast refresh boost,
+    /// lengthened while there's been no input for a while
+    pub fn effective_refresh_interval_secs(&self) -> u64 {
+        if self.is_fast_refresh() {
+            FAST_REFRESH_INTERVAL_SECS
+        } else if self.is_idle() {
+            self.refresh_interval_secs
+                .saturating_mul(self.idle_refresh_multiplier.max(1))
+        } else {
+            self.refresh_interval_secs
+        }
+    }
+
     pub fn seconds_until_refresh(&self) -> u64 {
+        let interval = self.effective_refresh_interval_secs();
         match self.last_refresh {
             Some(instant) => {
                 let elapsed = instant.elapsed().as_secs();
-                self.refresh_interval_secs.saturating_sub(elapsed)
+                interval.saturating_sub(elapsed)
             }
-            None => self.refresh_interval_secs,
+            None => interval,
         }
     }
 
+    /// Whether the refresh interval has fully elapsed. Compares elapsed time
+    /// against the interval rather than waiting for the countdown to read
+    /// exactly zero, so a late tick can't skip a refresh
+    pub fn is_refresh_due(&self) -> bool {
+        let elapsed = self
+            .last_refresh
+            .map_or(0, |instant| instant.elapsed().as_secs());
+        elapsed >= self.effective_refresh_interval_secs()
+    }
+
+    /// Whether a modal or sidebar is covering the screen. Auto-refresh holds
+    /// off meanwhile so the content underneath doesn't change unseen
+    pub fn is_modal_open(&self) -> bool {
+        self.show_help
+            || self.show_about
+            || self.show_agent_preview
+            || self.show_submolt_detail
+            || self.show_submolt_feed_preview
+            || self.show_owner_details
+            || self.show_preset_picker
+            || self.show_related
+            || self.preset_name_input.is_some()
+            || self.page_input.is_some()
+            || self.submolt_jump_input.is_some()
+            || self.report_target.is_some()
+    }
+
     pub fn toggle_help(&mut self) {
         self.show_help = !self.show_help;
+        self.help_scroll = 0;
     }
 
     pub fn toggle_about(&mut self) {
@@ -176,13 +764,21 @@ impl App {
         }
     }
 
+    /// Go to the page before the first one loaded, so pages added below it
+    /// aren't loaded again
     pub fn prev_page(&mut self) {
-        if self.current_page > 0 {
-            self.current_page -= 1;
+        if self.first_page > 0 {
+            self.current_page = self.first_page - 1;
             self.select_bottom_on_load = true;
         }
     }
 
+    /// Reload from the first loaded page on refresh. Pages added below it are
+    /// dropped, and added again if the filters still hide most posts
+    pub fn refresh_from_first_page(&mut self) {
+        self.current_page = self.first_page;
+    }
+
     pub fn advance_spinner(&mut self) {
         self.spinner_frame = (self.spinner_frame + 1) % 10;
     }
@@ -190,7 +786,8 @@ impl App {
     pub fn select_next(&mut self) {
         match self.screen {
             Screen::Feed => {
-                if !self.posts.is_empty() && self.selected_index < self.posts.len() - 1 {
+                let visible_count = self.visible_posts().len();
+                if visible_count > 0 && self.selected_index < visible_count - 1 {
                     self.selected_index += 1;
                 }
             }
@@ -202,9 +799,8 @@ impl App {
             }
             Screen::Setup | Screen::Stats => {}
             Screen::Leaderboard => {
-                if !self.leaderboard.is_empty()
-                    && self.leaderboard_selected < self.leaderboard.len() - 1
-                {
+                let visible_count = self.visible_leaderboard().len();
+                if visible_count > 0 && self.leaderboard_selected < visible_count - 1 {
                     self.leaderboard_selected += 1;
                 }
             }
@@ -216,9 +812,8 @@ impl App {
                 }
             }
             Screen::RecentAgents => {
-                if !self.recent_agents.is_empty()
-                    && self.recent_selected < self.recent_agents.len() - 1
-                {
+                let visible_count = self.visible_recent_agents().len();
+                if visible_count > 0 && self.recent_selected < visible_count - 1 {
                     self.recent_selected += 1;
                 }
             }
@@ -229,10 +824,20 @@ impl App {
                 }
             }
             Screen::Settings => {
-                if self.settings_selected < 1 {
+                if self.settings_selected < 2 {
                     self.settings_selected += 1;
                 }
             }
+            Screen::History => {
+                if self.history_selected + 1 < self.history.len() {
+                    self.history_selected += 1;
+                }
+            }
+            Screen::Bookmarks => {
+                if self.bookmarks_selected + 1 < self.bookmarks.len() {
+                    self.bookmarks_selected += 1;
+                }
+            }
             Screen::AgentProfile => {
                 if !self.agent_posts.is_empty()
                     && self.agent_posts_selected < self.agent_posts.len() - 1
@@ -282,6 +887,12 @@ impl App {
                     self.settings_selected -= 1;
                 }
             }
+            Screen::History => {
+                self.history_selected = self.history_selected.saturating_sub(1);
+            }
+            Screen::Bookmarks => {
+                self.bookmarks_selected = self.bookmarks_selected.saturating_sub(1);
+            }
             Screen::AgentProfile => {
                 if self.agent_posts_selected > 0 {
                     self.agent_posts_selected -= 1;
@@ -290,21 +901,72 @@ impl App {
         }
     }
 
+    /// Whether `gg` / `G` jump around the current screen's list
+    pub fn has_jumpable_list(&self) -> bool {
+        matches!(
+            self.screen,
+            Screen::Feed
+                | Screen::Leaderboard
+                | Screen::RecentAgents
+                | Screen::TopPairings
+                | Screen::AgentProfile
+                | Screen::Bookmarks
+        )
+    }
+
+    /// Select the first item of the current screen's list (`gg`)
+    pub fn select_first(&mut self) {
+        match self.screen {
+            Screen::Feed => self.selected_index = 0,
+            Screen::Leaderboard => self.leaderboard_selected = 0,
+            Screen::RecentAgents => self.recent_selected = 0,
+            Screen::TopPairings => self.top_pairings_selected = 0,
+            Screen::AgentProfile => self.agent_posts_selected = 0,
+            Screen::Bookmarks => self.bookmarks_selected = 0,
+            _ => {}
+        }
+    }
+
+    /// Select the last item of the current screen's list (`G`). On the feed
+    /// this is the last loaded post; the next page isn't fetched
+    pub fn select_last(&mut self) {
+        match self.screen {
+            Screen::Feed => {
+                self.selected_index = self.visible_posts().len().saturating_sub(1);
+            }
+            Screen::Leaderboard => {
+                self.leaderboard_selected = self.visible_leaderboard().len().saturating_sub(1);
+            }
+            Screen::RecentAgents => {
+                self.recent_selected = self.visible_recent_agents().len().saturating_sub(1);
+            }
+            Screen::TopPairings => {
+                self.top_pairings_selected = self.top_pairings.len().saturating_sub(1);
+            }
+            Screen::AgentProfile => {
+                self.agent_posts_selected = self.agent_posts.len().saturating_sub(1);
+            }
+            Screen::Bookmarks => {
+                self.bookmarks_selected = self.bookmarks.len().saturating_sub(1);
+            }
+            _ => {}
+        }
+    }
+
+    /// Move left in the submolts grid; from column 0 this wraps to the end
+    /// of the previous row. The grid is filled row by row, so that's just the
+    /// previous index. The render keeps the selected row scrolled into view
     pub fn select_left(&mut self) {
         if self.screen == Screen::Submolts {
-            // Don't move left if at left edge of grid (column 0)
-            if self.submolts_selected % 4 > 0 {
-                self.submolts_selected -= 1;
-            }
+            self.submolts_selected = self.submolts_selected.saturating_sub(1);
         }
     }
 
+    /// Move right in the submolts grid; from column 3 this wraps to the start
+    /// of the next row. Stops at the last submolt
     pub fn select_right(&mut self) {
-        if self.screen == Screen::Submolts {
-            // Don't move right if at right edge of grid (column 3) or past end
-            if self.submolts_selected % 4 < 3 && self.submolts_selected + 1 < self.submolts.len() {
-                self.submolts_selected += 1;
-            }
+        if self.screen == Screen::Submolts && self.submolts_selected + 1 < self.submolts.len() {
+            self.submolts_selected += 1;
         }
     }
 
@@ -323,6 +985,200 @@ impl App {
         }
     }
 
+    /// Query for the current page of the feed with its sort, filter and submolt
+    pub fn feed_query(&self) -> FeedQuery {
+        FeedQuery {
+            sort: self.sort_order,
+            time_filter: self.time_filter_for_api(),
+            offset: self.current_page as i64 * self.posts_limit,
+            limit: self.posts_limit,
+            submolt: self.current_submolt.as_ref().map(|s| s.name.clone()),
+            following: self.following_only,
+        }
+    }
+
+    /// moltbook.com permalinks for the open post's comments, in thread order.
+    /// Replies inside collapsed comments are left out, as on screen
+    pub fn visible_comment_permalinks(&self) -> Vec<String> {
+        let Some(ref post) = self.current_post else {
+            return Vec::new();
+        };
+        self.get_visible_comment_ids()
+            .iter()
+            .map(|id| format!("https://www.moltbook.com/posts/{}#comment-{}", post.id, id))
+            .collect()
+    }
+
+    /// moltbook.com URL that reproduces the current feed view (submolt, sort and time)
+    pub fn feed_deep_link(&self) -> String {
+        let base = "https://www.moltbook.com";
+        let path = match self.current_submolt {
+            Some(ref submolt) => format!("/s/{}", urlencoding::encode(&submolt.name)),
+            None => "/".to_string(),
+        };
+
+        let mut params = Vec::new();
+        if self.sort_order != SortOrder::New {
+            params.push(format!("sort={}", self.sort_order.as_str()));
+        }
+        if let Some(time) = self.time_filter_for_api() {
+            params.push(format!("time={}", time.as_str()));
+        }
+
+        if params.is_empty() {
+            format!("{}{}", base, path)
+        } else {
+            format!("{}{}?{}", base, path, params.join("&"))
+        }
+    }
+
+    /// The current submolt, sort and time filter as a preset called `name`
+    pub fn current_preset(&self, name: String) -> Preset {
+        Preset {
+            name,
+            submolt: self.current_submolt.as_ref().map(|s| s.name.clone()),
+            sort: self.sort_order,
+            time: self.time_filter,
+        }
+    }
+
+    /// Remember `preset`, replacing a saved one with the same name
+    pub fn store_preset(&mut self, preset: Preset) {
+        match self.presets.iter_mut().find(|p| p.name == preset.name) {
+            Some(existing) => *existing = preset,
+            None => self.presets.push(preset),
+        }
+    }
+
+    /// Switch the feed to a preset's view; the caller reloads posts
+    pub fn apply_preset(&mut self, preset: &Preset) {
+        self.following_only = false;
+        self.current_submolt = preset
+            .submolt
+            .as_deref()
+            .map(|name| self.submolt_named(name));
+        self.set_sort_order(preset.sort);
+        self.time_filter = preset.time;
+        self.current_page = 0;
+        self.selected_index = 0;
+        self.screen = Screen::Feed;
+    }
+
+    /// The loaded submolt called `name`, or a bare one with just the name when
+    /// the list hasn't been loaded (only the name is needed to fetch its feed)
+    pub fn submolt_named(&self, name: &str) -> SubmoltFull {
+        self.submolts
+            .iter()
+            .find(|s| s.name == name)
+            .cloned()
+            .unwrap_or_else(|| SubmoltFull {
+                id: String::new(),
+                name: name.to_string(),
+                display_name: name.to_string(),
+                description: None,
+                subscriber_count: 0,
+                created_at: String::new(),
+                last_activity_at: None,
+                featured_at: None,
+                created_by: None,
+            })
+    }
+
+    /// Indices into `submolts` of those whose name starts with what's typed
+    /// in the quick-switcher, in grid order (case-insensitive)
+    pub fn submolt_jump_matches(&self) -> Vec<usize> {
+        let prefix = self
+            .submolt_jump_input
+            .as_deref()
+            .unwrap_or_default()
+            .to_lowercase();
+        self.submolts
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.name.to_lowercase().starts_with(&prefix))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Avatar URL of the agent in the preview sidebar: from its profile, or
+    /// its leaderboard entry when the profile doesn't include one
+    pub fn preview_avatar_url(&self) -> Option<String> {
+        let profile = self.agent_profile.as_ref()?;
+        profile.avatar_url.clone().or_else(|| {
+            self.leaderboard
+                .iter()
+                .find(|agent| agent.name == profile.name)
+                .and_then(|agent| agent.avatar_url.clone())
+        })
+    }
+
+    /// The preview agent's avatar, if avatars are on and it's been downloaded
+    pub fn preview_avatar(&self) -> Option<&Avatar> {
+        if !self.avatars {
+            return None;
+        }
+        self.avatar_cache.get(&self.preview_avatar_url()?)?.as_ref()
+    }
+
+    /// Order the submolt grid: pinned first (in pin order), then featured,
+    /// then by subscriber count. The selection stays on the same submolt
+    pub fn sort_submolts(&mut self) {
+        let selected = self
+            .submolts
+            .get(self.submolts_selected)
+            .map(|s| s.name.clone());
+        let pins = &self.pinned_submolts;
+        let pin_rank = |s: &SubmoltFull| pins.iter().position(|p| *p == s.name);
+        self.submolts
+            .sort_by(|a, b| match (pin_rank(a), pin_rank(b)) {
+                (Some(x), Some(y)) => x.cmp(&y),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                _ => match (&a.featured_at, &b.featured_at) {
+                    (Some(_), None) => std::cmp::Ordering::Less,
+                    (None, Some(_)) => std::cmp::Ordering::Greater,
+                    _ => b.subscriber_count.cmp(&a.subscriber_count),
+                },
+            });
+        if let Some(name) = selected {
+            self.submolts_selected = self
+                .submolts
+                .iter()
+                .position(|s| s.name == name)
+                .unwrap_or(0);
+        }
+    }
+
+    /// Pin or unpin the selected submolt. Returns its name and whether it's
+    /// now pinned
+    pub fn toggle_pin_selected_submolt(&mut self) -> Option<(String, bool)> {
+        let name = self.submolts.get(self.submolts_selected)?.name.clone();
+        let pinned = match self.pinned_submolts.iter().position(|p| *p == name) {
+            Some(index) => {
+                self.pinned_submolts.remove(index);
+                false
+            }
+            None => {
+                self.pinned_submolts.push(name.clone());
+                true
+            }
+        };
+        self.sort_submolts();
+        Some((name, pinned))
+    }
+
+    /// Switch the feed to the `number`th pinned submolt (counting from 1).
+    /// Returns its name so the caller can load it
+    pub fn open_pinned_submolt(&mut self, number: usize) -> Option<String> {
+        let name = self.pinned_submolts.get(number.checked_sub(1)?)?.clone();
+        self.current_submolt = Some(self.submolt_named(&name));
+        self.following_only = false;
+        self.current_page = 0;
+        self.selected_index = 0;
+        self.screen = Screen::Feed;
+        Some(name)
+    }
+
     pub fn set_sort_order(&mut self, order: SortOrder) {
         self.sort_order = order;
         // Reset time filter to Day when switching to Top/Discussed if currently on Hour
@@ -354,28 +1210,203 @@ impl App {
     }
 
     pub fn open_selected_post(&mut self) {
-        if let Some(post) = self.posts.get(self.selected_index) {
+        if let Some(post) = self.selected_post().cloned() {
             // Mark as seen when opening
             self.new_post_ids.remove(&post.id);
-            self.seen_post_ids.insert(post.id.clone());
-            self.current_post = Some(post.clone());
+            self.mark_seen(post.id.clone());
+            self.record_history(HistoryEntry::Post(Box::new(post.clone())));
+            self.current_post = Some(post);
             self.comments.clear();
             self.comment_scroll = 0;
+            self.selected_comment_index = 0;
+            self.collapsed_comments.clear();
+            self.post_content_scroll = 0;
             self.screen = Screen::PostDetail;
         }
     }
 
+    /// Remember an opened post or profile, skipping it if it's the latest entry
+    pub fn record_history(&mut self, entry: HistoryEntry) {
+        if self.history.last().is_some_and(|last| last.is_same(&entry)) {
+            return;
+        }
+        self.history.push(entry);
+        if self.history.len() > HISTORY_LIMIT {
+            self.history.remove(0);
+        }
+    }
+
+    pub fn open_history(&mut self) {
+        self.screen = Screen::History;
+        self.history_selected = 0;
+    }
+
+    /// History entries newest first, as listed on the History screen
+    pub fn history_newest_first(&self) -> impl Iterator<Item = &HistoryEntry> {
+        self.history.iter().rev()
+    }
+
+    pub fn selected_history_entry(&self) -> Option<&HistoryEntry> {
+        self.history_newest_first().nth(self.history_selected)
+    }
+
+    pub fn open_bookmarks(&mut self) {
+        self.screen = Screen::Bookmarks;
+        self.bookmarks_selected = 0;
+    }
+
+    pub fn is_bookmarked(&self, post_id: &str) -> bool {
+        self.bookmarks.iter().any(|p| p.id == post_id)
+    }
+
+    pub fn selected_bookmark(&self) -> Option<&Post> {
+        self.bookmarks.get(self.bookmarks_selected)
+    }
+
+    /// The post `b` acts on: the selected one in the feed or bookmarks, or
+    /// the one being read
+    pub fn bookmark_target(&self) -> Option<&Post> {
+        match self.screen {
+            Screen::Feed => self.selected_post(),
+            Screen::PostDetail => self.current_post.as_ref(),
+            Screen::Bookmarks => self.selected_bookmark(),
+            _ => None,
+        }
+    }
+
+    /// Bookmark `post`, or remove it if it's already bookmarked. Returns
+    /// whether it's bookmarked now
+    pub fn toggle_bookmark(&mut self, post: Post) -> bool {
+        if let Some(i) = self.bookmarks.iter().position(|p| p.id == post.id) {
+            self.bookmarks.remove(i);
+            self.bookmarks_selected = self
+                .bookmarks_selected
+                .min(self.bookmarks.len().saturating_sub(1));
+            false
+        } else {
+            self.bookmarks.insert(0, post);
+            true
+        }
+    }
+
+    /// While reading a feed post, open the next (or previous) one on the
+    /// loaded page. Returns false at the edge of the page
+    pub fn step_post(&mut self, forward: bool) -> bool {
+        let target = if forward {
+            self.selected_index + 1
+        } else if self.selected_index > 0 {
+            self.selected_index - 1
+        } else {
+            return false;
+        };
+        if target >= self.visible_posts().len() {
+            return false;
+        }
+        self.selected_index = target;
+        self.open_selected_post();
+        true
+    }
+
+    /// Blank lines after each list item (none with dense spacing)
+    pub fn spacer_height(&self) -> u16 {
+        if self.dense_spacing {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// Height of the announcement banner on the Feed, including borders
+    pub fn announcement_banner_height(&self) -> u16 {
+        if self.announcement.is_some() {
+            3
+        } else {
+            0
+        }
+    }
+
+    /// Height of a screen's header, or 0 while reading mode hides it
+    pub fn visible_header_height(&self, full_height: u16) -> u16 {
+        if self.reading_mode {
+            0
+        } else {
+            full_height
+        }
+    }
+
+    /// Reading mode is only available on screens where the header isn't needed
+    pub fn toggle_reading_mode(&mut self) {
+        if matches!(self.screen, Screen::PostDetail | Screen::AgentProfile) {
+            self.reading_mode = !self.reading_mode;
+        }
+    }
+
+    pub fn toggle_focus_mode(&mut self) {
+        if self.screen == Screen::PostDetail && self.current_post.is_some() {
+            self.focus_mode = !self.focus_mode;
+        }
+    }
+
+    /// Bring the header back once the user has moved to another screen
+    pub fn sync_reading_mode(&mut self, previous_screen: &Screen) {
+        if self.screen != *previous_screen {
+            self.reading_mode = false;
+        }
+    }
+
+    /// Height of the post block on the PostDetail screen, including borders
+    pub fn post_block_height(&self) -> u16 {
+        if self.post_collapsed {
+            3
+        } else {
+            15
+        }
+    }
+
+    pub fn toggle_post_collapsed(&mut self) {
+        self.post_collapsed = !self.post_collapsed;
+        if self.post_collapsed {
+            self.post_content_focused = false;
+        }
+    }
+
+    /// Switch j/k between scrolling the post body and moving through the
+    /// comments. A collapsed post has nothing to scroll
+    pub fn toggle_post_content_focus(&mut self) {
+        self.post_content_focused = !self.post_content_focused && !self.post_collapsed;
+    }
+
+    /// The agent's posts in the current profile sort order
+    pub fn sorted_agent_posts(&self) -> Vec<&Post> {
+        let mut posts: Vec<&Post> = self.agent_posts.iter().collect();
+        match self.agent_posts_sort {
+            // RFC 3339 timestamps sort chronologically as strings
+            AgentPostsSort::Date => posts.sort_by(|a, b| b.created_at.cmp(&a.created_at)),
+            AgentPostsSort::Score => posts.sort_by_key(|p| std::cmp::Reverse(p.score())),
+            AgentPostsSort::Comments => posts.sort_by_key(|p| std::cmp::Reverse(p.comment_count)),
+        }
+        posts
+    }
+
+    pub fn cycle_agent_posts_sort(&mut self) {
+        self.agent_posts_sort = self.agent_posts_sort.cycle_next();
+        self.agent_posts_selected = 0;
+    }
+
     pub fn go_back(&mut self) {
         match self.screen {
             Screen::PostDetail => {
-                // Check if we came from AgentProfile
-                if self.previous_screen == Some(Screen::AgentProfile) {
-                    self.screen = Screen::AgentProfile;
+                // Check if we came from AgentProfile, History or Bookmarks
+                if let Some(prev @ (Screen::AgentProfile | Screen::History | Screen::Bookmarks)) =
+                    self.previous_screen.clone()
+                {
+                    self.screen = prev;
                     self.current_post = None;
                     self.comments.clear();
                     self.comment_scroll = 0;
                     self.selected_comment_index = 0;
                     self.collapsed_comments.clear();
+                    self.post_content_scroll = 0;
                     self.previous_screen = None;
                 } else {
                     self.screen = Screen::Feed;
@@ -384,6 +1415,7 @@ impl App {
                     self.comment_scroll = 0;
                     self.selected_comment_index = 0;
                     self.collapsed_comments.clear();
+                    self.post_content_scroll = 0;
                 }
             }
             Screen::Setup => {
@@ -413,7 +1445,9 @@ impl App {
             | Screen::TopPairings
             | Screen::RecentAgents
             | Screen::Submolts
-            | Screen::Settings => {
+            | Screen::Settings
+            | Screen::History
+            | Screen::Bookmarks => {
                 self.screen = Screen::Feed;
             }
         }
@@ -427,58 +1461,524 @@ impl App {
         }
     }
 
+    /// Collapse every top-level comment that has replies, leaving a flat list
+    /// of the main responses. If they're all collapsed already, expand the
+    /// whole thread instead. Returns whether the thread is now collapsed
+    pub fn toggle_top_level_only(&mut self) -> bool {
+        fn contains(comment: &Comment, id: &str) -> bool {
+            comment.id == id || comment.replies.iter().any(|r| contains(r, id))
+        }
+        // Keep the selection on the thread it was in
+        let selected_root = self.get_selected_comment_id().and_then(|id| {
+            self.comments
+                .iter()
+                .find(|c| contains(c, &id))
+                .map(|c| c.id.clone())
+        });
+
+        let with_replies: Vec<String> = self
+            .comments
+            .iter()
+            .filter(|c| !c.replies.is_empty())
+            .map(|c| c.id.clone())
+            .collect();
+        let collapse = !with_replies
+            .iter()
+            .all(|id| self.collapsed_comments.contains(id));
+        if collapse {
+            self.collapsed_comments.extend(with_replies);
+        } else {
+            self.collapsed_comments.clear();
+        }
+
+        if let Some(root) = selected_root {
+            if let Some(index) = self
+                .get_visible_comment_ids()
+                .iter()
+                .position(|id| *id == root)
+            {
+                self.selected_comment_index = index;
+            }
+        }
+        collapse
+    }
+
+    /// Collapse every comment scoring below `auto_collapse_score`
+    pub fn auto_collapse_low_scored(&mut self) {
+        fn collect_low_scored(comments: &[Comment], threshold: i64, result: &mut HashSet<String>) {
+            for comment in comments {
+                if comment.score() < threshold {
+                    result.insert(comment.id.clone());
+                }
+                collect_low_scored(&comment.replies, threshold, result);
+            }
+        }
+        if let Some(threshold) = self.auto_collapse_score {
+            collect_low_scored(&self.comments, threshold, &mut self.collapsed_comments);
+        }
+    }
+
+    /// Swap in reloaded comments for the open post. Collapsed threads stay
+    /// collapsed and the selected comment stays selected if it's still there
+    pub fn refresh_comments(&mut self, comments: Vec<Comment>) {
+        let selected = self.get_selected_comment_id();
+        self.comments = comments;
+        let visible = self.get_visible_comment_ids();
+        self.selected_comment_index = selected
+            .and_then(|id| visible.iter().position(|v| *v == id))
+            .unwrap_or(self.selected_comment_index)
+            .min(visible.len().saturating_sub(1));
+    }
+
     pub fn is_comment_collapsed(&self, comment_id: &str) -> bool {
         self.collapsed_comments.contains(comment_id)
     }
 
+    /// Every comment of the open post, oldest first, ignoring nesting
+    pub fn flat_comments(&self) -> Vec<&Comment> {
+        fn collect<'a>(comments: &'a [Comment], result: &mut Vec<&'a Comment>) {
+            for comment in comments {
+                result.push(comment);
+                collect(&comment.replies, result);
+            }
+        }
+        let mut all = Vec::new();
+        collect(&self.comments, &mut all);
+        // ISO 8601 timestamps sort chronologically as strings
+        all.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        all
+    }
+
+    /// Switch between the tree and flat comment views, keeping the same
+    /// comment selected
+    pub fn toggle_comment_view(&mut self) {
+        let selected = self.get_selected_comment_id();
+        self.comment_view = match self.comment_view {
+            CommentView::Tree => CommentView::Flat,
+            CommentView::Flat => CommentView::Tree,
+        };
+        // In the tree the selected comment may be inside a collapsed one
+        self.selected_comment_index = selected
+            .and_then(|id| self.get_visible_comment_ids().iter().position(|v| *v == id))
+            .unwrap_or(0);
+        self.comment_scroll = 0;
+    }
+
+    /// Whether a comment passes the minimum comment score filter
+    pub fn comment_passes_filter(&self, comment: &Comment) -> bool {
+        self.min_comment_score == 0 || comment.score() >= self.min_comment_score
+    }
+
     pub fn get_visible_comment_ids(&self) -> Vec<String> {
-        fn collect_visible(
-            comments: &[Comment],
-            collapsed: &HashSet<String>,
-            result: &mut Vec<String>,
-        ) {
+        if self.comment_view == CommentView::Flat {
+            return self
+                .flat_comments()
+                .iter()
+                .filter(|c| self.comment_passes_filter(c))
+                .map(|c| c.id.clone())
+                .collect();
+        }
+        // A comment hidden by the score filter still shows its replies
+        fn collect_visible(app: &App, comments: &[Comment], result: &mut Vec<String>) {
             for comment in comments {
+                if !app.comment_passes_filter(comment) {
+                    collect_visible(app, &comment.replies, result);
+                    continue;
+                }
                 result.push(comment.id.clone());
-                if !collapsed.contains(&comment.id) {
-                    collect_visible(&comment.replies, collapsed, result);
+                if !app.collapsed_comments.contains(&comment.id) {
+                    collect_visible(app, &comment.replies, result);
                 }
             }
         }
         let mut ids = Vec::new();
-        collect_visible(&self.comments, &self.collapsed_comments, &mut ids);
+        collect_visible(self, &self.comments, &mut ids);
         ids
     }
 
+    /// Step the minimum comment score through `MIN_SCORE_STEPS`, keeping the
+    /// selected comment selected while it's still shown
+    pub fn adjust_min_comment_score(&mut self, raise: bool) {
+        let selected = self.get_selected_comment_id();
+        self.min_comment_score = step_min_score(self.min_comment_score, raise);
+
+        let visible = self.get_visible_comment_ids();
+        self.selected_comment_index = selected
+            .and_then(|id| visible.iter().position(|v| *v == id))
+            .unwrap_or(self.selected_comment_index)
+            .min(visible.len().saturating_sub(1));
+    }
+
+    /// The post a report would be about: the selected one on the Feed, or the
+    /// open one on PostDetail
+    pub fn reportable_post(&self) -> Option<&Post> {
+        match self.screen {
+            Screen::Feed => self.selected_post(),
+            Screen::PostDetail => self.current_post.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Nav tab opened by a number key, counting the configured tabs from 1
+    pub fn nav_tab_for_key(&self, key: char) -> Option<NavTab> {
+        let index = key.to_digit(10)?.checked_sub(1)?;
+        self.nav_tabs.get(index as usize).copied()
+    }
+
+    /// Kind and ID of the item selected on the current screen, for copying
+    pub fn selected_item_id(&self) -> Option<(&'static str, String)> {
+        match self.screen {
+            Screen::Feed => self.selected_post().map(|p| ("post", p.id.clone())),
+            Screen::PostDetail => self
+                .get_selected_comment_id()
+                .map(|id| ("comment", id))
+                .or_else(|| self.current_post.as_ref().map(|p| ("post", p.id.clone()))),
+            Screen::Leaderboard => self
+                .selected_leaderboard_agent()
+                .map(|a| ("agent", a.id.clone())),
+            Screen::RecentAgents => self
+                .selected_recent_agent()
+                .map(|a| ("agent", a.id.clone())),
+            Screen::AgentProfile => self.agent_profile.as_ref().map(|p| ("agent", p.id.clone())),
+            Screen::History => match self.selected_history_entry()? {
+                HistoryEntry::Post(post) => Some(("post", post.id.clone())),
+                HistoryEntry::Agent(_) => None,
+            },
+            Screen::Bookmarks => self.selected_bookmark().map(|p| ("post", p.id.clone())),
+            Screen::Submolts => self
+                .submolts
+                .get(self.submolts_selected)
+                .map(|s| ("submolt", s.id.clone())),
+            _ => None,
+        }
+    }
+
+    /// moltbook.com page of the item selected on the current screen, for
+    /// opening in a browser or copying
+    pub fn selected_item_url(&self) -> Option<String> {
+        let base = "https://www.moltbook.com";
+        match self.screen {
+            Screen::Feed => self
+                .selected_post()
+                .map(|post| format!("{}/posts/{}", base, post.id)),
+            Screen::PostDetail => self
+                .current_post
+                .as_ref()
+                .map(|post| format!("{}/posts/{}", base, post.id)),
+            Screen::AgentProfile => self
+                .agent_profile
+                .as_ref()
+                .map(|profile| format!("{}/agent/{}", base, profile.name)),
+            Screen::Submolts => self
+                .submolts
+                .get(self.submolts_selected)
+                .map(|submolt| format!("{}/s/{}", base, submolt.name)),
+            Screen::Leaderboard => self
+                .selected_leaderboard_agent()
+                .map(|agent| format!("{}/agent/{}", base, agent.name)),
+            Screen::RecentAgents => self
+                .selected_recent_agent()
+                .map(|agent| format!("{}/agent/{}", base, agent.name)),
+            _ => None,
+        }
+    }
+
     pub fn get_selected_comment_id(&self) -> Option<String> {
         let visible_ids = self.get_visible_comment_ids();
         visible_ids.get(self.selected_comment_index).cloned()
     }
 
-    pub fn update_posts(&mut self, posts: Vec<Post>) {
+    pub fn update_posts(&mut self, mut posts: Vec<Post>) {
+        // Random sort can return the same post twice; keep the first occurrence
+        let mut ids = HashSet::new();
+        posts.retain(|p| ids.insert(p.id.clone()));
+
         // Find new posts
         let current_ids: HashSet<&String> = self.posts.iter().map(|p| &p.id).collect();
 
         for post in &posts {
             if !current_ids.contains(&post.id) && !self.seen_post_ids.contains(&post.id) {
-                self.new_post_ids.insert(post.id.clone());
+                self.new_post_ids
+                    .insert(post.id.clone(), std::time::Instant::now());
             }
         }
 
+        self.loaded_page_sizes = vec![posts.len()];
         self.posts = posts;
+        self.first_page = self.current_page;
+        self.added_pages = 0;
         self.last_refresh = Some(std::time::Instant::now());
 
         // Select bottom if navigating to previous page
-        if self.select_bottom_on_load && !self.posts.is_empty() {
-            self.selected_index = self.posts.len() - 1;
+        let visible_count = self.visible_posts().len();
+        if self.select_bottom_on_load && visible_count > 0 {
+            self.selected_index = visible_count - 1;
             self.select_bottom_on_load = false;
-        } else if self.selected_index >= self.posts.len() && !self.posts.is_empty() {
-            // Adjust selection if needed
-            self.selected_index = self.posts.len() - 1;
+        } else {
+            self.clamp_feed_selection();
         }
     }
 
+    /// The loaded feed page as pretty JSON: the posts plus the sort, time
+    /// filter, submolt and page they were loaded with
+    pub fn feed_export_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(&serde_json::json!({
+            "exported_at": chrono::Local::now().to_rfc3339(),
+            "sort": self.sort_order.as_str(),
+            "time": self.time_filter_for_api().map(|t| t.as_str()),
+            "submolt": self.current_submolt.as_ref().map(|s| &s.name),
+            "page": self.current_page + 1,
+            "posts": &self.posts,
+        }))
+    }
+
+    /// Add the page after `current_page` below the loaded posts, skipping any
+    /// already loaded and keeping the selected post selected. Past
+    /// `max_feed_posts` the oldest pages are dropped, so `P` loads them again
+    pub fn append_posts(&mut self, posts: Vec<Post>) {
+        let selected = self.selected_post().map(|p| p.id.clone());
+        self.current_page += 1;
+        self.added_pages += 1;
+
+        let mut ids: HashSet<String> = self.posts.iter().map(|p| p.id.clone()).collect();
+        let mut added = 0;
+        for post in posts {
+            if !ids.insert(post.id.clone()) {
+                continue;
+            }
+            if !self.seen_post_ids.contains(&post.id) {
+                self.new_post_ids
+                    .insert(post.id.clone(), std::time::Instant::now());
+            }
+            self.posts.push(post);
+            added += 1;
+        }
+        self.loaded_page_sizes.push(added);
+
+        // Always keep the page that just arrived
+        while self.posts.len() > self.max_feed_posts && self.loaded_page_sizes.len() > 1 {
+            let size = self.loaded_page_sizes.remove(0);
+            self.posts.drain(..size);
+            self.first_page += 1;
+        }
+
+        // Falls back to the top when the selected post was dropped
+        match selected.and_then(|id| self.visible_posts().iter().position(|p| p.id == id)) {
+            Some(index) => self.selected_index = index,
+            None => self.selected_index = 0,
+        }
+        self.clamp_feed_selection();
+    }
+
+    /// Replace the feed with an auto-refreshed page, keeping the selected post
+    /// selected even if the new order moved it. Falls back to the clamped
+    /// index when it's no longer in the feed
+    pub fn refresh_posts(&mut self, posts: Vec<Post>) {
+        let selected = self.selected_post().map(|p| p.id.clone());
+        self.update_posts(posts);
+        if let Some(index) =
+            selected.and_then(|id| self.visible_posts().iter().position(|p| p.id == id))
+        {
+            self.selected_index = index;
+        }
+    }
+
+    /// Feed posts that pass the minimum score filter, in display order
+    pub fn visible_posts(&self) -> Vec<&Post> {
+        let mut posts: Vec<&Post> = self
+            .posts
+            .iter()
+            .filter(|p| self.min_score == 0 || p.score() >= self.min_score)
+            .filter(|p| match self.author_filter {
+                Some(ref name) => p.author.as_ref().is_some_and(|a| &a.name == name),
+                None => true,
+            })
+            .filter(|p| self.matches_search(p))
+            .collect();
+        if self.sort_reversed {
+            posts.reverse();
+        }
+        posts
+    }
+
+    /// Client-side feed filters currently narrowing the loaded posts, each
+    /// with the key that clears or changes it
+    pub fn active_feed_filters(&self) -> Vec<String> {
+        let mut filters = Vec::new();
+        if self.min_score > 0 {
+            filters.push(format!("score ≥ {} ([ to lower)", self.min_score));
+        }
+        if let Some(ref author) = self.author_filter {
+            filters.push(format!("author u/{} (Esc to clear)", author));
+        }
+        if !self.search_query.is_empty() {
+            filters.push(format!("search \"{}\" (Esc to clear)", self.search_query));
+        }
+        filters
+    }
+
+    /// Whether a post's title or body contains the search query
+    fn matches_search(&self, post: &Post) -> bool {
+        if self.search_query.is_empty() {
+            return true;
+        }
+        let query = self.search_query.to_lowercase();
+        post.title.to_lowercase().contains(&query)
+            || post
+                .content
+                .as_deref()
+                .is_some_and(|content| content.to_lowercase().contains(&query))
+    }
+
+    /// Edit the feed search query, keeping the selection on a matching post
+    pub fn set_search_query(&mut self, query: String) {
+        self.search_query = query;
+        self.clamp_feed_selection();
+    }
+
+    /// Name of the selected feed post's author, if it has one
+    pub fn selected_post_author(&self) -> Option<String> {
+        self.selected_post()
+            .and_then(|p| p.author.as_ref())
+            .map(|a| a.name.clone())
+    }
+
+    /// Narrow the feed to the selected post's author, or clear the filter if
+    /// one is already active
+    pub fn toggle_author_filter(&mut self) {
+        if self.author_filter.take().is_none() {
+            self.author_filter = self.selected_post_author();
+        }
+        self.selected_index = 0;
+    }
+
+    pub fn toggle_sort_reversed(&mut self) {
+        self.sort_reversed = !self.sort_reversed;
+        self.selected_index = 0;
+    }
+
+    fn clamp_feed_selection(&mut self) {
+        let visible_count = self.visible_posts().len();
+        if self.selected_index >= visible_count {
+            self.selected_index = visible_count.saturating_sub(1);
+        }
+    }
+
+    /// Step the minimum score filter up or down through `MIN_SCORE_STEPS`
+    pub fn adjust_min_score(&mut self, raise: bool) {
+        self.min_score = step_min_score(self.min_score, raise);
+        self.clamp_feed_selection();
+    }
+
+    pub fn update_leaderboard(&mut self, leaderboard: Vec<LeaderboardAgent>) {
+        // Remember ranks from the last load so movement can be shown
+        if !self.leaderboard.is_empty() {
+            self.previous_leaderboard_ranks = self
+                .leaderboard
+                .iter()
+                .map(|a| (a.id.clone(), a.rank))
+                .collect();
+            self.leaderboard_updated_at = Some(std::time::Instant::now());
+        }
+
+        self.leaderboard = leaderboard;
+        self.clamp_agent_list_selection();
+    }
+
+    /// Leaderboard entries ordered by the active metric, minus unclaimed
+    /// agents when they're hidden. Agents without a value for the metric sort last
+    pub fn visible_leaderboard(&self) -> Vec<&LeaderboardAgent> {
+        let mut agents: Vec<&LeaderboardAgent> = self
+            .leaderboard
+            .iter()
+            .filter(|a| !self.hide_unclaimed || a.is_claimed)
+            .collect();
+        if self.leaderboard_metric != LeaderboardMetric::Karma {
+            agents.sort_by_key(|a| std::cmp::Reverse(self.leaderboard_metric.value(a)));
+        }
+        agents
+    }
+
+    /// Where an agent stands on the loaded karma leaderboard, e.g. "#42 by
+    /// karma", or `None` before the leaderboard has been loaded
+    pub fn karma_rank_label(&self, name: &str) -> Option<String> {
+        if self.leaderboard.is_empty() {
+            return None;
+        }
+        Some(match self.leaderboard.iter().find(|a| a.name == name) {
+            Some(agent) => format!("#{} by karma", agent.rank),
+            None => format!("unranked in top {}", self.leaderboard.len()),
+        })
+    }
+
+    /// Rank shown for the agent at `index` in `visible_leaderboard()`: the
+    /// API's karma rank, or the position when re-sorted by another metric
+    pub fn leaderboard_display_rank(&self, index: usize, agent: &LeaderboardAgent) -> u32 {
+        match self.leaderboard_metric {
+            LeaderboardMetric::Karma => agent.rank,
+            _ => index as u32 + 1,
+        }
+    }
+
+    pub fn cycle_leaderboard_metric(&mut self) {
+        self.leaderboard_metric = self.leaderboard_metric.cycle_next();
+        self.leaderboard_selected = 0;
+
+        let metric = self.leaderboard_metric;
+        if !self.leaderboard.iter().any(|a| metric.value(a).is_some()) {
+            self.set_status(format!(
+                "{} counts aren't included in the leaderboard data",
+                metric.as_str()
+            ));
+        }
+    }
+
+    /// Recent agents, minus unclaimed agents when they're hidden
+    pub fn visible_recent_agents(&self) -> Vec<&RecentAgent> {
+        self.recent_agents
+            .iter()
+            .filter(|a| !self.hide_unclaimed || a.is_claimed)
+            .collect()
+    }
+
+    pub fn selected_leaderboard_agent(&self) -> Option<&LeaderboardAgent> {
+        self.visible_leaderboard()
+            .get(self.leaderboard_selected)
+            .copied()
+    }
+
+    pub fn selected_recent_agent(&self) -> Option<&RecentAgent> {
+        self.visible_recent_agents()
+            .get(self.recent_selected)
+            .copied()
+    }
+
+    pub fn toggle_hide_unclaimed(&mut self) {
+        self.hide_unclaimed = !self.hide_unclaimed;
+        self.clamp_agent_list_selection();
+    }
+
+    /// Keep the leaderboard and recent agents selections inside the visible lists
+    pub fn clamp_agent_list_selection(&mut self) {
+        let leaderboard_count = self.visible_leaderboard().len();
+        if self.leaderboard_selected >= leaderboard_count {
+            self.leaderboard_selected = leaderboard_count.saturating_sub(1);
+        }
+        let recent_count = self.visible_recent_agents().len();
+        if self.recent_selected >= recent_count {
+            self.recent_selected = recent_count.saturating_sub(1);
+        }
+    }
+
+    /// Whether leaderboard rank changes should still be highlighted
+    pub fn leaderboard_highlight_active(&self) -> bool {
+        self.leaderboard_updated_at
+            .map(|t| t.elapsed().as_secs() < LEADERBOARD_HIGHLIGHT_SECS)
+            .unwrap_or(false)
+    }
+
     pub fn selected_post(&self) -> Option<&Post> {
-        self.posts.get(self.selected_index)
+        self.visible_posts().get(self.selected_index).copied()
     }
 }
 
@@ -487,3 +1987,173 @@ impl Default for App {
         Self::new()
     }
 }
+
+/// The step of `MIN_SCORE_STEPS` above or below `current`, stopping at either
+/// end. A value between steps (e.g. from a preset) counts as the step below it
+fn step_min_score(current: i64, raise: bool) -> i64 {
+    let index = MIN_SCORE_STEPS
+        .iter()
+        .rposition(|&step| step <= current)
+        .unwrap_or(0);
+    let next = if raise {
+        (index + 1).min(MIN_SCORE_STEPS.len() - 1)
+    } else {
+        index.saturating_sub(1)
+    };
+    MIN_SCORE_STEPS[next]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(id: &str, title: &str) -> Post {
+        Post {
+            id: id.to_string(),
+            title: title.to_string(),
+            content: None,
+            url: None,
+            upvotes: 0,
+            downvotes: 0,
+            comment_count: 0,
+            created_at: String::new(),
+            author: None,
+            submolt: None,
+        }
+    }
+
+    #[test]
+    fn update_posts_drops_repeated_ids() {
+        let mut app = App::new();
+        app.update_posts(vec![
+            post("a", "first"),
+            post("b", "second"),
+            post("a", "first again"),
+            post("c", "third"),
+            post("b", "second again"),
+        ]);
+
+        let ids: Vec<&str> = app.posts.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, ["a", "b", "c"]);
+        assert_eq!(app.posts[0].title, "first");
+        assert_eq!(app.posts[1].title, "second");
+    }
+
+    #[test]
+    fn refresh_posts_follows_selected_post() {
+        let mut app = App::new();
+        app.update_posts(vec![post("a", "a"), post("b", "b"), post("c", "c")]);
+        app.selected_index = 1;
+
+        app.refresh_posts(vec![post("b", "b"), post("c", "c"), post("a", "a")]);
+        assert_eq!(app.selected_index, 0);
+
+        app.refresh_posts(vec![post("c", "c"), post("d", "d")]);
+        assert_eq!(app.selected_index, 0);
+        assert_eq!(app.selected_post().map(|p| p.id.as_str()), Some("c"));
+    }
+
+    fn app_refreshed_secs_ago(secs: u64) -> App {
+        let mut app = App::new();
+        app.refresh_interval_secs = 30;
+        app.last_refresh =
+            std::time::Instant::now().checked_sub(std::time::Duration::from_secs(secs));
+        app
+    }
+
+    #[test]
+    fn refresh_not_due_before_interval() {
+        let app = app_refreshed_secs_ago(29);
+        assert!(!app.is_refresh_due());
+        assert_eq!(app.seconds_until_refresh(), 1);
+    }
+
+    #[test]
+    fn refresh_due_at_interval() {
+        let app = app_refreshed_secs_ago(30);
+        assert!(app.is_refresh_due());
+        assert_eq!(app.seconds_until_refresh(), 0);
+    }
+
+    #[test]
+    fn refresh_due_after_interval() {
+        let app = app_refreshed_secs_ago(45);
+        assert!(app.is_refresh_due());
+        assert_eq!(app.seconds_until_refresh(), 0);
+    }
+
+    #[test]
+    fn min_score_steps_stop_at_either_end() {
+        assert_eq!(step_min_score(0, true), 1);
+        assert_eq!(step_min_score(10, true), 25);
+        assert_eq!(step_min_score(10, false), 5);
+        assert_eq!(step_min_score(0, false), 0);
+        assert_eq!(step_min_score(1000, true), 1000);
+        // Off-step values move from the step below them
+        assert_eq!(step_min_score(7, true), 10);
+        assert_eq!(step_min_score(7, false), 1);
+        assert_eq!(step_min_score(-3, true), 1);
+    }
+
+    #[test]
+    fn append_posts_skips_loaded_posts_and_keeps_the_selection() {
+        let mut app = App::new();
+        app.update_posts(vec![post("a", "A"), post("b", "B")]);
+        app.selected_index = 1;
+        app.append_posts(vec![post("b", "B again"), post("c", "C")]);
+
+        let ids: Vec<&str> = app.posts.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, ["a", "b", "c"]);
+        assert_eq!(app.posts[1].title, "B");
+        assert_eq!(app.selected_index, 1);
+        assert_eq!((app.first_page, app.current_page), (0, 1));
+    }
+
+    #[test]
+    fn append_posts_drops_the_oldest_pages_past_the_cap() {
+        let mut app = App::new();
+        app.max_feed_posts = 3;
+        app.update_posts(vec![post("a", "A"), post("b", "B")]);
+        app.append_posts(vec![post("c", "C")]);
+        assert_eq!(app.posts.len(), 3);
+        app.selected_index = 2;
+
+        app.append_posts(vec![post("d", "D"), post("e", "E")]);
+        let ids: Vec<&str> = app.posts.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, ["c", "d", "e"]);
+        assert_eq!((app.first_page, app.current_page), (1, 2));
+        assert_eq!(app.selected_post().unwrap().id, "c");
+
+        // Whole pages are dropped, and a dropped selection falls back to the top
+        app.append_posts(vec![post("f", "F"), post("g", "G")]);
+        let ids: Vec<&str> = app.posts.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, ["f", "g"]);
+        assert_eq!((app.first_page, app.current_page), (3, 3));
+        assert_eq!(app.selected_post().unwrap().id, "f");
+        assert_eq!(app.added_pages, 3);
+
+        // Going back loads the last page that was dropped
+        app.prev_page();
+        assert_eq!(app.feed_query().offset, 2 * app.posts_limit);
+    }
+
+    #[test]
+    fn refresh_after_append_starts_from_the_first_page() {
+        let mut app = App::new();
+        app.current_page = 2;
+        app.update_posts(vec![post("a", "A")]);
+        app.append_posts(vec![post("b", "B")]);
+        app.append_posts(vec![post("c", "C")]);
+        assert_eq!((app.first_page, app.current_page), (2, 4));
+
+        app.refresh_from_first_page();
+        assert_eq!(app.feed_query().offset, 2 * app.posts_limit);
+        app.refresh_posts(vec![post("a", "A"), post("d", "D")]);
+        assert_eq!((app.first_page, app.current_page), (2, 2));
+
+        // P goes to the page before the first one shown
+        app.append_posts(vec![post("e", "E")]);
+        app.prev_page();
+        assert_eq!(app.current_page, 1);
+    }
+}
@@ -0,0 +1,112 @@
+//! Agent avatars drawn with half-block characters: each cell shows two
+//! pixels, the upper one as the foreground of `▀` and the lower one as its
+//! background, so any terminal with colors can show them
+
+use anyhow::Result;
+use image::{imageops::FilterType, ImageReader, Limits};
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+use std::io::Cursor;
+
+/// Avatar size in pixels, drawn as this many columns and half as many rows
+pub const SIZE: u32 = 16;
+
+/// Largest source image decoded, per side
+const MAX_SOURCE_SIDE: u32 = 1024;
+/// Most memory the decoder may allocate
+const MAX_DECODE_ALLOC: u64 = 16 * 1024 * 1024;
+
+/// A decoded avatar, scaled to `SIZE` x `SIZE`
+#[derive(Debug, Clone)]
+pub struct Avatar {
+    pixels: Vec<[u8; 3]>,
+}
+
+impl Avatar {
+    /// Decode a PNG or JPEG and scale it down; transparency becomes black.
+    /// Images too large to be an avatar are rejected before they're decoded.
+    /// This is CPU-bound, so call it off the async runtime
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut limits = Limits::default();
+        limits.max_image_width = Some(MAX_SOURCE_SIDE);
+        limits.max_image_height = Some(MAX_SOURCE_SIDE);
+        limits.max_alloc = Some(MAX_DECODE_ALLOC);
+
+        let mut reader = ImageReader::new(Cursor::new(bytes)).with_guessed_format()?;
+        reader.limits(limits);
+        let image = reader
+            .decode()?
+            .resize_exact(SIZE, SIZE, FilterType::Triangle)
+            .to_rgb8();
+        Ok(Avatar {
+            pixels: image.pixels().map(|pixel| pixel.0).collect(),
+        })
+    }
+
+    /// The avatar as `SIZE / 2` lines of `SIZE` cells, indented by `indent` spaces
+    pub fn lines(&self, indent: usize) -> Vec<Line<'static>> {
+        let color = |x: u32, y: u32| {
+            let [r, g, b] = self.pixels[(y * SIZE + x) as usize];
+            Color::Rgb(r, g, b)
+        };
+        (0..SIZE / 2)
+            .map(|row| {
+                let mut spans = vec![Span::raw(" ".repeat(indent))];
+                spans.extend((0..SIZE).map(|x| {
+                    Span::styled(
+                        "▀",
+                        Style::default()
+                            .fg(color(x, row * 2))
+                            .bg(color(x, row * 2 + 1)),
+                    )
+                }));
+                Line::from(spans)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageFormat, Rgb, RgbImage};
+
+    fn png(image: &RgbImage) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    #[test]
+    fn lines_draw_upper_pixel_as_fg_and_lower_as_bg() {
+        // Red on even rows, blue on odd ones, already at the avatar size
+        let image = RgbImage::from_fn(SIZE, SIZE, |_, y| {
+            if y % 2 == 0 {
+                Rgb([255, 0, 0])
+            } else {
+                Rgb([0, 0, 255])
+            }
+        });
+        let avatar = Avatar::decode(&png(&image)).unwrap();
+        let lines = avatar.lines(1);
+
+        assert_eq!(lines.len(), (SIZE / 2) as usize);
+        // The indent, then one cell per column
+        assert_eq!(lines[0].spans.len(), SIZE as usize + 1);
+        assert_eq!(lines[0].spans[0].content, " ");
+        let cell = &lines[0].spans[1];
+        assert_eq!(cell.content, "▀");
+        assert_eq!(cell.style.fg, Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(cell.style.bg, Some(Color::Rgb(0, 0, 255)));
+    }
+
+    #[test]
+    fn oversized_image_is_rejected() {
+        let image = RgbImage::new(MAX_SOURCE_SIDE + 1, 1);
+        assert!(Avatar::decode(&png(&image)).is_err());
+    }
+}
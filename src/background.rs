@@ -0,0 +1,136 @@
+use crate::config::Background;
+
+use std::time::Duration;
+
+/// How long to wait for the terminal to answer the background color query
+const QUERY_TIMEOUT: Duration = Duration::from_millis(150);
+
+/// Guess whether the terminal has a light or dark background: ask the
+/// terminal (OSC 11), then fall back to `COLORFGBG`, then assume dark.
+/// Call with raw mode on so the terminal's reply isn't echoed
+pub fn detect() -> Background {
+    query_background()
+        .map(|(r, g, b)| {
+            let luma = 0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32;
+            if luma > 128.0 {
+                Background::Light
+            } else {
+                Background::Dark
+            }
+        })
+        .or_else(from_colorfgbg)
+        .unwrap_or_default()
+}
+
+/// Background from `COLORFGBG` ("fg;bg" palette indexes), set by some terminals
+fn from_colorfgbg() -> Option<Background> {
+    let value = std::env::var("COLORFGBG").ok()?;
+    let bg: u8 = value.rsplit(';').next()?.parse().ok()?;
+    // White (7) and the bright colors except bright black (8) are light
+    Some(if bg == 7 || bg > 8 {
+        Background::Light
+    } else {
+        Background::Dark
+    })
+}
+
+/// Send OSC 11 to the terminal and read back the background as RGB. Terminals
+/// that don't support the query stay silent, so the reply is polled for on a
+/// non-blocking handle until `QUERY_TIMEOUT`
+#[cfg(unix)]
+fn query_background() -> Option<(u8, u8, u8)> {
+    use std::io::{ErrorKind, Read, Write};
+    use std::os::unix::fs::OpenOptionsExt;
+    use std::time::Instant;
+
+    let mut tty = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .custom_flags(libc::O_NONBLOCK)
+        .open("/dev/tty")
+        .ok()?;
+    tty.write_all(b"\x1b]11;?\x1b\\").ok()?;
+    tty.flush().ok()?;
+
+    let started = Instant::now();
+    let mut reply = Vec::new();
+    let mut buf = [0u8; 64];
+    while started.elapsed() < QUERY_TIMEOUT {
+        match tty.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => {
+                reply.extend_from_slice(&buf[..n]);
+                // Replies end with BEL or ST (ESC \)
+                if reply.ends_with(b"\x07") || reply.ends_with(b"\x1b\\") {
+                    break;
+                }
+            }
+            Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            Err(_) => return None,
+        }
+    }
+    parse_osc11_reply(&String::from_utf8_lossy(&reply))
+}
+
+#[cfg(not(unix))]
+fn query_background() -> Option<(u8, u8, u8)> {
+    None
+}
+
+/// Parse a reply like `ESC ]11;rgb:ffff/ffff/ffff BEL`. Channels have one to
+/// four hex digits each and are scaled to 0-255
+fn parse_osc11_reply(reply: &str) -> Option<(u8, u8, u8)> {
+    let rgb = reply.split("rgb:").nth(1)?;
+    let rgb = rgb.trim_end_matches(['\x07', '\x1b', '\\']);
+    let mut channels = rgb.split('/').map(|hex| {
+        let digits = hex.len().clamp(1, 4) as u32;
+        let value = u32::from_str_radix(hex.get(..digits as usize)?, 16).ok()?;
+        let max = 16u32.pow(digits) - 1;
+        Some((value * 255 / max) as u8)
+    });
+    Some((channels.next()??, channels.next()??, channels.next()??))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_four_digit_channels_ending_in_bel() {
+        assert_eq!(
+            parse_osc11_reply("\x1b]11;rgb:ffff/8080/0000\x07"),
+            Some((255, 128, 0))
+        );
+    }
+
+    #[test]
+    fn parses_two_digit_channels_ending_in_st() {
+        assert_eq!(
+            parse_osc11_reply("\x1b]11;rgb:1e/1e/2e\x1b\\"),
+            Some((30, 30, 46))
+        );
+    }
+
+    #[test]
+    fn scales_one_and_three_digit_channels() {
+        assert_eq!(
+            parse_osc11_reply("\x1b]11;rgb:f/0/8\x07"),
+            Some((255, 0, 136))
+        );
+        assert_eq!(
+            parse_osc11_reply("\x1b]11;rgb:fff/000/800\x07"),
+            Some((255, 0, 127))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_replies() {
+        assert_eq!(parse_osc11_reply(""), None);
+        assert_eq!(parse_osc11_reply("\x1b]11;?\x07"), None);
+        assert_eq!(parse_osc11_reply("\x1b]11;rgb:ffff/ffff\x07"), None);
+        assert_eq!(parse_osc11_reply("\x1b]11;rgb:gg/00/00\x07"), None);
+        assert_eq!(parse_osc11_reply("\x1b]11;rgb://\x07"), None);
+    }
+}
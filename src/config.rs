@@ -1,11 +1,16 @@
+use crate::api::{Post, RetryPolicy, SortOrder, TimeFilter};
+use crate::keymap::Keymap;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
+use toml_edit::DocumentMut;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum RowDisplay {
+    /// One line per feed post: title and a short meta summary
+    Dense,
     Compact,
     #[default]
     Normal,
@@ -15,6 +20,7 @@ pub enum RowDisplay {
 impl RowDisplay {
     pub fn as_str(&self) -> &'static str {
         match self {
+            RowDisplay::Dense => "Dense",
             RowDisplay::Compact => "Compact",
             RowDisplay::Normal => "Normal",
             RowDisplay::Comfortable => "Comfortable",
@@ -23,43 +29,596 @@ impl RowDisplay {
 
     pub fn cycle_next(&self) -> Self {
         match self {
+            RowDisplay::Dense => RowDisplay::Compact,
             RowDisplay::Compact => RowDisplay::Normal,
             RowDisplay::Normal => RowDisplay::Comfortable,
-            RowDisplay::Comfortable => RowDisplay::Compact,
+            RowDisplay::Comfortable => RowDisplay::Dense,
         }
     }
 
     pub fn cycle_prev(&self) -> Self {
         match self {
-            RowDisplay::Compact => RowDisplay::Comfortable,
+            RowDisplay::Dense => RowDisplay::Comfortable,
+            RowDisplay::Compact => RowDisplay::Dense,
             RowDisplay::Normal => RowDisplay::Compact,
             RowDisplay::Comfortable => RowDisplay::Normal,
         }
     }
 }
 
+/// How many colors the terminal can show; brand RGB colors are mapped down to fit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+    #[default]
+    TrueColor,
+    Indexed256,
+    Basic16,
+}
+
+impl ColorMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ColorMode::TrueColor => "Truecolor",
+            ColorMode::Indexed256 => "256 colors",
+            ColorMode::Basic16 => "16 colors",
+        }
+    }
+
+    pub fn cycle_next(&self) -> Self {
+        match self {
+            ColorMode::TrueColor => ColorMode::Indexed256,
+            ColorMode::Indexed256 => ColorMode::Basic16,
+            ColorMode::Basic16 => ColorMode::TrueColor,
+        }
+    }
+
+    /// Guess the terminal's color support from `COLORTERM` and `TERM`
+    pub fn detect() -> Self {
+        let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            return ColorMode::TrueColor;
+        }
+        if std::env::var("TERM").is_ok_and(|term| term.contains("256color")) {
+            ColorMode::Indexed256
+        } else {
+            ColorMode::Basic16
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "truecolor" => Some(ColorMode::TrueColor),
+            "256" => Some(ColorMode::Indexed256),
+            "16" => Some(ColorMode::Basic16),
+            _ => None,
+        }
+    }
+}
+
+/// Whether the terminal background is dark or light; light backgrounds get
+/// dark text and light panels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Background {
+    #[default]
+    Dark,
+    Light,
+}
+
+impl Background {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Background::Dark),
+            "light" => Some(Background::Light),
+            _ => None,
+        }
+    }
+}
+
+/// Built-in color theme, picked with `theme` in the config file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThemeName {
+    #[default]
+    Default,
+    Light,
+    Mono,
+}
+
+impl ThemeName {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(ThemeName::Default),
+            "light" => Some(ThemeName::Light),
+            "mono" => Some(ThemeName::Mono),
+            _ => None,
+        }
+    }
+}
+
+const DEFAULT_API_URL: &str = "https://www.moltbook.com/api/v1";
+
+/// Check that `url` is an http(s) URL to use as the API base, dropping any
+/// trailing slash so endpoint paths can be appended
+pub fn parse_api_url(url: &str) -> std::result::Result<String, String> {
+    let parsed =
+        reqwest::Url::parse(url.trim()).map_err(|e| format!("'{}' is not a URL ({})", url, e))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(format!(
+            "'{}' must start with http:// or https://",
+            url.trim()
+        ));
+    }
+    Ok(url.trim().trim_end_matches('/').to_string())
+}
+
+/// Reasons offered when reporting a post, unless `report_reasons` is set
+const DEFAULT_REPORT_REASONS: &[&str] =
+    &["Spam", "Harassment", "Misinformation", "Off-topic", "Other"];
+
+/// Loading spinner frame interval, and the fastest allowed
+const DEFAULT_SPINNER_INTERVAL_MS: u64 = 80;
+const MIN_SPINNER_INTERVAL_MS: u64 = 20;
+
+/// Posts fetched per feed page, and the range `posts_limit` is clamped to
+const DEFAULT_POSTS_LIMIT: i64 = 25;
+const MIN_POSTS_LIMIT: i64 = 10;
+const MAX_POSTS_LIMIT: i64 = 100;
+
+/// Retries after a failed request (connection errors, timeouts, 5xx), the
+/// most allowed, and the first retry's delay (doubling after that)
+const DEFAULT_RETRIES: u32 = 2;
+const MAX_RETRIES: u32 = 10;
+const DEFAULT_RETRY_BASE_MS: u64 = 500;
+const MIN_RETRY_BASE_MS: u64 = 50;
+
+/// Most seen post IDs kept in `seen.json`; the oldest are dropped first
+const SEEN_POSTS_LIMIT: usize = 5000;
+
+/// Counts at or above this are shown as 1.2K / 3.4M by default
+pub const DEFAULT_COMPACT_NUMBERS_THRESHOLD: u64 = 1_000;
+
+/// Most feed posts kept as pages are added below them, and the lowest
+/// `max_feed_posts` may be set to
+pub const DEFAULT_MAX_FEED_POSTS: usize = 200;
+const MIN_MAX_FEED_POSTS: usize = 50;
+
+/// A piece of metadata shown on a feed row's second line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedColumn {
+    Submolt,
+    Author,
+    Date,
+    Score,
+    Comments,
+    ReadTime,
+}
+
+impl FeedColumn {
+    /// Every column, in the default order
+    pub const ALL: [FeedColumn; 6] = [
+        FeedColumn::Submolt,
+        FeedColumn::Author,
+        FeedColumn::Date,
+        FeedColumn::Score,
+        FeedColumn::Comments,
+        FeedColumn::ReadTime,
+    ];
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "submolt" => Some(FeedColumn::Submolt),
+            "author" => Some(FeedColumn::Author),
+            "date" => Some(FeedColumn::Date),
+            "score" => Some(FeedColumn::Score),
+            "comments" => Some(FeedColumn::Comments),
+            "read_time" => Some(FeedColumn::ReadTime),
+            _ => None,
+        }
+    }
+}
+
+/// A screen in the header's numbered nav tabs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NavTab {
+    Feed,
+    Leaderboard,
+    TopPairings,
+    Agents,
+    Submolts,
+    Stats,
+    Settings,
+    About,
+    History,
+    Bookmarks,
+}
+
+impl NavTab {
+    /// Tabs shown when `nav_tabs` isn't set, in order. History and Bookmarks
+    /// are opt-in so the default row still fits a 120-column terminal
+    pub const DEFAULT: [NavTab; 8] = [
+        NavTab::Feed,
+        NavTab::Leaderboard,
+        NavTab::TopPairings,
+        NavTab::Agents,
+        NavTab::Submolts,
+        NavTab::Stats,
+        NavTab::Settings,
+        NavTab::About,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            NavTab::Feed => "Feed",
+            NavTab::Leaderboard => "Leaderboard",
+            NavTab::TopPairings => "Top Pairings",
+            NavTab::Agents => "Agents",
+            NavTab::Submolts => "Submolts",
+            NavTab::Stats => "Stats",
+            NavTab::Settings => "Settings",
+            NavTab::About => "About",
+            NavTab::History => "History",
+            NavTab::Bookmarks => "Bookmarks",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "feed" => Some(NavTab::Feed),
+            "leaderboard" => Some(NavTab::Leaderboard),
+            "top_pairings" => Some(NavTab::TopPairings),
+            "agents" => Some(NavTab::Agents),
+            "submolts" => Some(NavTab::Submolts),
+            "stats" => Some(NavTab::Stats),
+            "settings" => Some(NavTab::Settings),
+            "about" => Some(NavTab::About),
+            "history" => Some(NavTab::History),
+            "bookmarks" => Some(NavTab::Bookmarks),
+            _ => None,
+        }
+    }
+}
+
+/// A named `[profiles.<name>]` table with its own API key
+#[derive(Debug, Clone)]
+pub struct Profile {
+    pub name: String,
+    pub api_key: Option<String>,
+}
+
+/// A saved feed view from the `[presets]` table, stored as
+/// `"name" = "submolt=rust sort=top time=week"`
+#[derive(Debug, Clone)]
+pub struct Preset {
+    pub name: String,
+    pub submolt: Option<String>,
+    pub sort: SortOrder,
+    pub time: TimeFilter,
+}
+
+impl Preset {
+    fn parse(name: &str, value: &str) -> Option<Self> {
+        let mut preset = Preset {
+            name: name.to_string(),
+            submolt: None,
+            sort: SortOrder::New,
+            time: TimeFilter::Day,
+        };
+        for field in value.split_whitespace() {
+            match field.split_once('=')? {
+                ("submolt", submolt) => preset.submolt = Some(submolt.to_string()),
+                ("sort", sort) => preset.sort = SortOrder::from_name(sort)?,
+                ("time", time) => preset.time = TimeFilter::from_name(time)?,
+                _ => return None,
+            }
+        }
+        Some(preset)
+    }
+
+    /// Short summary of the view, like "m/rust • Top • Week"
+    pub fn describe(&self) -> String {
+        let submolt = match self.submolt {
+            Some(ref name) => format!("m/{}", name),
+            None => "All posts".to_string(),
+        };
+        if self.sort == SortOrder::New {
+            format!("{} • {}", submolt, self.sort)
+        } else {
+            format!("{} • {} • {}", submolt, self.sort, self.time)
+        }
+    }
+
+    fn to_value(&self) -> String {
+        let mut fields = Vec::new();
+        if let Some(ref submolt) = self.submolt {
+            fields.push(format!("submolt={}", submolt));
+        }
+        fields.push(format!("sort={}", self.sort.as_str()));
+        fields.push(format!("time={}", self.time.as_str()));
+        fields.join(" ")
+    }
+}
+
+/// The config file's top-level table. Settings are read one key at a time,
+/// so a value of the wrong type is skipped with a warning instead of
+/// discarding the rest of the file
+struct ConfigFile {
+    table: toml::Table,
+    warnings: Vec<String>,
+}
+
+impl ConfigFile {
+    fn parse(contents: &str) -> Result<Self, toml::de::Error> {
+        Ok(ConfigFile {
+            table: contents.parse()?,
+            warnings: Vec::new(),
+        })
+    }
+
+    /// The value of `key`, or None if it's missing or has the wrong type
+    fn get<T: serde::de::DeserializeOwned>(&mut self, key: &str) -> Option<T> {
+        let value = self.table.get(key)?;
+        match value.clone().try_into() {
+            Ok(value) => Some(value),
+            Err(_) => {
+                self.warnings.push(format!(
+                    "Ignoring {} in config, it has the wrong type: {}",
+                    key, value
+                ));
+                None
+            }
+        }
+    }
+
+    /// A table such as `[presets]`, kept as a table to preserve file order
+    fn table(&mut self, key: &str) -> toml::Table {
+        self.get(key).unwrap_or_default()
+    }
+}
+
 #[derive(Debug)]
 pub struct Config {
     pub api_key: Option<String>,
     pub api_url: String,
     pub row_display: RowDisplay,
     pub refresh_interval_secs: u64,
+    // Posts per feed page
+    pub posts_limit: i64,
+    // Feed posts kept before the oldest pages are dropped
+    pub max_feed_posts: usize,
+    // Retries after a failed request, and the first retry's delay
+    pub retries: u32,
+    pub retry_base_ms: u64,
+    pub proxy: Option<String>,
+    pub compact_numbers_threshold: u64,
+    pub auto_collapse_score: Option<i64>,
+    pub max_comment_width: Option<usize>,
+    pub idle_refresh_multiplier: u64,
+    // Seconds before a post stops counting as new (None = until opened)
+    pub new_post_highlight_secs: Option<u64>,
+    // Milliseconds between loading spinner frames
+    pub spinner_interval_ms: u64,
+    // Keep the spinner still (and skip redraws for it)
+    pub reduce_motion: bool,
+    pub show_read_time: bool,
+    // Show where link posts point in the feed meta line
+    pub show_link_domains: bool,
+    // Make URLs clickable with OSC 8 escapes (some terminals print them raw)
+    pub hyperlinks: bool,
+    // Draw agent avatars in the preview sidebar (downloads an image per agent)
+    pub avatars: bool,
+    // Drop the blank spacer lines between list items
+    pub dense_spacing: bool,
+    pub profiles: Vec<Profile>,
+    pub presets: Vec<Preset>,
+    pub active_profile: Option<String>,
+    pub feed_columns: Vec<FeedColumn>,
+    pub nav_tabs: Vec<NavTab>,
+    // Submolt names listed first on the Submolts screen, in pin order
+    pub pinned_submolts: Vec<String>,
+    // Rebound keys from `[keybindings]`
+    pub keymap: Keymap,
+    // Fixed color mode (None = detect from the environment)
+    pub color_mode: Option<ColorMode>,
+    // Fixed background (None = ask the terminal)
+    pub background: Option<Background>,
+    pub theme: ThemeName,
+    pub report_reasons: Vec<String>,
+    pub show_verified_badge: bool,
+    // RGB color of the verified star (None = theme teal)
+    pub badge_color: Option<(u8, u8, u8)>,
+    // Problems found while reading the config file, shown in the debug panel
+    pub warnings: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            api_key: None,
+            api_url: DEFAULT_API_URL.to_string(),
+            row_display: RowDisplay::default(),
+            refresh_interval_secs: 10,
+            posts_limit: DEFAULT_POSTS_LIMIT,
+            max_feed_posts: DEFAULT_MAX_FEED_POSTS,
+            retries: DEFAULT_RETRIES,
+            retry_base_ms: DEFAULT_RETRY_BASE_MS,
+            proxy: None,
+            compact_numbers_threshold: DEFAULT_COMPACT_NUMBERS_THRESHOLD,
+            auto_collapse_score: None,
+            max_comment_width: None,
+            idle_refresh_multiplier: 3,
+            new_post_highlight_secs: None,
+            spinner_interval_ms: DEFAULT_SPINNER_INTERVAL_MS,
+            reduce_motion: false,
+            show_read_time: true,
+            show_link_domains: false,
+            hyperlinks: true,
+            avatars: false,
+            dense_spacing: false,
+            profiles: Vec::new(),
+            presets: Vec::new(),
+            active_profile: None,
+            feed_columns: FeedColumn::ALL.to_vec(),
+            nav_tabs: NavTab::DEFAULT.to_vec(),
+            pinned_submolts: Vec::new(),
+            keymap: Keymap::default(),
+            color_mode: None,
+            background: None,
+            theme: ThemeName::default(),
+            report_reasons: DEFAULT_REPORT_REASONS
+                .iter()
+                .map(|r| r.to_string())
+                .collect(),
+            show_verified_badge: true,
+            badge_color: None,
+            warnings: Vec::new(),
+        }
+    }
 }
 
 impl Config {
     pub fn load() -> Result<Self> {
-        let api_key = Self::load_api_key();
-        let api_url = "https://www.moltbook.com/api/v1".to_string();
+        // Load settings from config file, then resolve the API key
+        let mut config = Self::load_settings();
+        config.api_key = config.active_profile_key().or_else(Self::load_api_key);
+        // The environment overrides the file's endpoint
+        if let Ok(url) = std::env::var("MOLTBOOK_API_URL") {
+            match parse_api_url(&url) {
+                Ok(url) => config.api_url = url,
+                Err(e) => config
+                    .warnings
+                    .push(format!("Ignoring MOLTBOOK_API_URL: {}", e)),
+            }
+        }
+
+        Ok(config)
+    }
 
-        // Load settings from config file
-        let (row_display, refresh_interval_secs) = Self::load_settings();
+    /// Where the API key in use came from, or `None` without one
+    pub fn api_key_source(&self) -> Option<String> {
+        self.api_key.as_ref()?;
+        if self.active_profile_key().is_some() {
+            let name = self.active_profile.as_deref().unwrap_or_default();
+            return Some(format!("profile \"{}\"", name));
+        }
+        if std::env::var("MOLTBOOK_API_KEY").is_ok_and(|key| !key.is_empty()) {
+            Some("MOLTBOOK_API_KEY".to_string())
+        } else {
+            Some("config file".to_string())
+        }
+    }
 
-        Ok(Self {
-            api_key,
-            api_url,
-            row_display,
-            refresh_interval_secs,
-        })
+    /// API key of the active profile, if one is selected and has a key.
+    /// An explicitly chosen profile wins over `MOLTBOOK_API_KEY`
+    fn active_profile_key(&self) -> Option<String> {
+        let name = self.active_profile.as_deref()?;
+        self.profiles
+            .iter()
+            .find(|p| p.name == name)
+            .and_then(|p| p.api_key.clone())
+            .filter(|key| !key.is_empty())
+    }
+
+    /// The profile after the active one, wrapping around (`None` if there are no profiles)
+    pub fn next_profile(&self) -> Option<&str> {
+        let position = self
+            .active_profile
+            .as_deref()
+            .and_then(|name| self.profiles.iter().position(|p| p.name == name));
+        let next = match position {
+            Some(i) => (i + 1) % self.profiles.len(),
+            None => 0,
+        };
+        self.profiles.get(next).map(|p| p.name.as_str())
+    }
+
+    /// Make `name` the active profile, persisting it and returning the reloaded config
+    pub fn switch_profile(name: &str) -> Result<Self> {
+        Self::update_config_file(&[("active_profile", name.into())])?;
+        Self::load()
+    }
+
+    /// Parse a list of feed column names, returning the known columns and a
+    /// warning for each unknown one
+    fn parse_feed_columns(names: &[String]) -> (Vec<FeedColumn>, Vec<String>) {
+        let mut columns = Vec::new();
+        let mut warnings = Vec::new();
+
+        for name in names {
+            match FeedColumn::from_name(name) {
+                Some(column) if !columns.contains(&column) => columns.push(column),
+                Some(_) => {}
+                None => warnings.push(format!("Unknown feed column in config: {:?}", name)),
+            }
+        }
+
+        (columns, warnings)
+    }
+
+    /// Parse a `#RRGGBB` color
+    fn parse_hex_color(value: &str) -> Option<(u8, u8, u8)> {
+        let hex = value.strip_prefix('#')?;
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        Some((channel(0)?, channel(2)?, channel(4)?))
+    }
+
+    /// Parse a list of nav tab names, returning the known tabs and a warning
+    /// for each unknown one. An empty result falls back to the default tabs so
+    /// the screens stay reachable
+    fn parse_nav_tabs(names: &[String]) -> (Vec<NavTab>, Vec<String>) {
+        let mut tabs = Vec::new();
+        let mut warnings = Vec::new();
+
+        for name in names {
+            match NavTab::from_name(name) {
+                Some(tab) if !tabs.contains(&tab) => tabs.push(tab),
+                Some(_) => {}
+                None => warnings.push(format!("Unknown nav tab in config: {:?}", name)),
+            }
+        }
+
+        if tabs.is_empty() {
+            warnings.push("No known nav tabs in config, showing the default tabs".to_string());
+            tabs = NavTab::DEFAULT.to_vec();
+        }
+
+        (tabs, warnings)
+    }
+
+    /// Read `[profiles.<name>]` tables, in file order
+    fn parse_profiles(table: &toml::Table) -> Vec<Profile> {
+        table
+            .iter()
+            .map(|(name, profile)| Profile {
+                name: name.clone(),
+                api_key: profile
+                    .get("api_key")
+                    .and_then(toml::Value::as_str)
+                    .map(String::from),
+            })
+            .collect()
+    }
+
+    /// Read the `[presets]` table, in file order, with a warning for each
+    /// entry that can't be read
+    fn parse_presets(table: &toml::Table) -> (Vec<Preset>, Vec<String>) {
+        let mut presets = Vec::new();
+        let mut warnings = Vec::new();
+
+        for (name, value) in table {
+            match value.as_str().and_then(|value| Preset::parse(name, value)) {
+                Some(preset) => presets.push(preset),
+                None => warnings.push(format!("Invalid preset in config: {:?}", name)),
+            }
+        }
+
+        (presets, warnings)
+    }
+
+    fn read_config_file() -> Option<String> {
+        let config_path = Self::config_file_path()?;
+        fs::read_to_string(config_path).ok()
     }
 
     fn load_api_key() -> Option<String> {
@@ -71,101 +630,278 @@ impl Config {
         }
 
         // Then, check config file
-        if let Some(config_path) = Self::config_file_path() {
-            if config_path.exists() {
-                if let Ok(contents) = fs::read_to_string(&config_path) {
-                    for line in contents.lines() {
-                        let line = line.trim();
-                        if line.starts_with("api_key") {
-                            if let Some(value) = line.split('=').nth(1) {
-                                let key = value.trim().trim_matches('"').trim_matches('\'');
-                                if !key.is_empty() {
-                                    return Some(key.to_string());
-                                }
-                            }
-                        }
-                    }
-                }
+        Self::api_key_from(&Self::read_config_file()?)
+    }
+
+    /// The API key set in the config file's `contents`, if any
+    fn api_key_from(contents: &str) -> Option<String> {
+        let mut file = ConfigFile::parse(contents).ok()?;
+        file.get::<String>("api_key").filter(|key| !key.is_empty())
+    }
+
+    /// Read settings from the config file, falling back to defaults.
+    /// The API key is resolved separately by `load_api_key`.
+    fn load_settings() -> Self {
+        Self::settings_from(Self::read_config_file().as_deref())
+    }
+
+    /// Settings from the config file's `contents` (None if there's no file)
+    fn settings_from(contents: Option<&str>) -> Self {
+        let mut config = Self::default();
+
+        let mut file = match contents.map(ConfigFile::parse) {
+            None => return config,
+            Some(Ok(file)) => file,
+            Some(Err(e)) => {
+                config.warnings.push(format!(
+                    "Config file isn't valid TOML, using defaults: {}",
+                    e
+                ));
+                return config;
             }
-        }
+        };
 
-        None
-    }
-
-    fn load_settings() -> (RowDisplay, u64) {
-        let mut row_display = RowDisplay::default();
-        let mut refresh_interval_secs = 10u64;
-
-        if let Some(config_path) = Self::config_file_path() {
-            if config_path.exists() {
-                if let Ok(contents) = fs::read_to_string(&config_path) {
-                    for line in contents.lines() {
-                        let line = line.trim();
-                        if line.starts_with("row_display") {
-                            if let Some(value) = line.split('=').nth(1) {
-                                let value = value.trim().trim_matches('"').trim_matches('\'');
-                                row_display = match value {
-                                    "compact" => RowDisplay::Compact,
-                                    "comfortable" => RowDisplay::Comfortable,
-                                    _ => RowDisplay::Normal,
-                                };
-                            }
-                        } else if line.starts_with("refresh_interval_secs") {
-                            if let Some(value) = line.split('=').nth(1) {
-                                if let Ok(secs) = value.trim().parse::<u64>() {
-                                    refresh_interval_secs = secs;
-                                }
-                            }
-                        }
-                    }
-                }
+        config.profiles = Self::parse_profiles(&file.table("profiles"));
+        let (keymap, warnings) = Keymap::parse(&file.table("keybindings"));
+        config.keymap = keymap;
+        config.warnings.extend(warnings);
+        let (presets, warnings) = Self::parse_presets(&file.table("presets"));
+        config.presets = presets;
+        config.warnings.extend(warnings);
+
+        if let Some(row_display) = file.get::<String>("row_display") {
+            config.row_display = match row_display.as_str() {
+                "dense" => RowDisplay::Dense,
+                "compact" => RowDisplay::Compact,
+                "comfortable" => RowDisplay::Comfortable,
+                _ => RowDisplay::Normal,
+            };
+        }
+        if let Some(secs) = file.get("refresh_interval_secs") {
+            config.refresh_interval_secs = secs;
+        }
+        if let Some(limit) = file.get::<i64>("posts_limit") {
+            config.posts_limit = limit.clamp(MIN_POSTS_LIMIT, MAX_POSTS_LIMIT);
+        }
+        if let Some(max) = file.get::<usize>("max_feed_posts") {
+            config.max_feed_posts = max.max(MIN_MAX_FEED_POSTS);
+        }
+        if let Some(retries) = file.get::<u32>("retries") {
+            config.retries = retries.min(MAX_RETRIES);
+        }
+        if let Some(ms) = file.get::<u64>("retry_base_ms") {
+            config.retry_base_ms = ms.max(MIN_RETRY_BASE_MS);
+        }
+        if let Some(threshold) = file.get("compact_numbers_threshold") {
+            config.compact_numbers_threshold = threshold;
+        }
+        if let Some(multiplier) = file.get::<u64>("idle_refresh_multiplier") {
+            config.idle_refresh_multiplier = multiplier.max(1);
+        }
+        config.new_post_highlight_secs = file
+            .get::<u64>("new_post_highlight_secs")
+            .filter(|s| *s > 0);
+        if let Some(ms) = file.get::<u64>("spinner_interval_ms") {
+            config.spinner_interval_ms = ms.max(MIN_SPINNER_INTERVAL_MS);
+        }
+        if let Some(reduce) = file.get("reduce_motion") {
+            config.reduce_motion = reduce;
+        }
+        config.max_comment_width = file.get::<usize>("max_comment_width").filter(|w| *w > 0);
+        if let Some(show) = file.get("show_read_time") {
+            config.show_read_time = show;
+        }
+        if let Some(show) = file.get("show_link_domains") {
+            config.show_link_domains = show;
+        }
+        if let Some(hyperlinks) = file.get("hyperlinks") {
+            config.hyperlinks = hyperlinks;
+        }
+        if let Some(avatars) = file.get("avatars") {
+            config.avatars = avatars;
+        }
+        if let Some(dense) = file.get("dense_spacing") {
+            config.dense_spacing = dense;
+        }
+        config.auto_collapse_score = file.get("auto_collapse_score");
+        if let Some(names) = file.get::<Vec<String>>("feed_columns") {
+            let (columns, warnings) = Self::parse_feed_columns(&names);
+            config.feed_columns = columns;
+            config.warnings.extend(warnings);
+        }
+        if let Some(value) = file.get::<String>("color_mode") {
+            match ColorMode::from_name(&value) {
+                Some(mode) => config.color_mode = Some(mode),
+                None if value == "auto" => config.color_mode = None,
+                None => config
+                    .warnings
+                    .push(format!("Unknown color_mode in config: {:?}", value)),
+            }
+        }
+        if let Some(show) = file.get("show_verified_badge") {
+            config.show_verified_badge = show;
+        }
+        if let Some(value) = file.get::<String>("badge_color") {
+            match Self::parse_hex_color(&value) {
+                Some(rgb) => config.badge_color = Some(rgb),
+                None => config
+                    .warnings
+                    .push(format!("Invalid badge_color in config: {:?}", value)),
+            }
+        }
+        if let Some(value) = file.get::<String>("background") {
+            match Background::from_name(&value) {
+                Some(background) => config.background = Some(background),
+                None if value == "auto" => config.background = None,
+                None => config
+                    .warnings
+                    .push(format!("Unknown background in config: {:?}", value)),
             }
         }
+        if let Some(value) = file.get::<String>("theme") {
+            match ThemeName::from_name(&value) {
+                Some(theme) => config.theme = theme,
+                None => config
+                    .warnings
+                    .push(format!("Unknown theme in config: {:?}", value)),
+            }
+        }
+        if let Some(names) = file.get::<Vec<String>>("nav_tabs") {
+            let (tabs, warnings) = Self::parse_nav_tabs(&names);
+            config.nav_tabs = tabs;
+            config.warnings.extend(warnings);
+        }
+        if let Some(names) = file.get::<Vec<String>>("pinned_submolts") {
+            config.pinned_submolts = names.into_iter().filter(|n| !n.is_empty()).collect();
+        }
+        if let Some(reasons) = file.get::<Vec<String>>("report_reasons") {
+            let reasons: Vec<String> = reasons.into_iter().filter(|r| !r.is_empty()).collect();
+            if !reasons.is_empty() {
+                config.report_reasons = reasons;
+            }
+        }
+        config.active_profile = file
+            .get::<String>("active_profile")
+            .filter(|name| !name.is_empty());
+        config.proxy = file
+            .get::<String>("proxy")
+            .filter(|proxy| !proxy.is_empty());
+        if let Some(url) = file.get::<String>("api_url") {
+            match parse_api_url(&url) {
+                Ok(url) => config.api_url = url,
+                Err(e) => config
+                    .warnings
+                    .push(format!("Ignoring api_url in config: {}", e)),
+            }
+        }
+        config.warnings.extend(file.warnings);
 
-        (row_display, refresh_interval_secs)
+        config
     }
 
-    fn config_file_path() -> Option<PathBuf> {
-        dirs::home_dir().map(|home| home.join(".moltbook-tui").join("config.toml"))
+    /// Directory holding the config file and anything else the app writes
+    pub fn data_dir() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(".moltbook-tui"))
     }
 
-    pub fn save(api_key: &str) -> Result<Self> {
-        // Load existing settings to preserve them
-        let (row_display, refresh_interval_secs) = Self::load_settings();
+    pub fn config_file_path() -> Option<PathBuf> {
+        Self::data_dir().map(|dir| dir.join("config.toml"))
+    }
 
-        let config_path = Self::config_file_path()
+    /// Write a file into the data directory, returning its full path
+    pub fn write_data_file(file_name: &str, contents: &str) -> Result<PathBuf> {
+        let dir = Self::data_dir()
             .ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?;
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create data directory: {:?}", dir))?;
 
-        // Create directory if it doesn't exist
-        if let Some(parent) = config_path.parent() {
-            fs::create_dir_all(parent)
-                .with_context(|| format!("Failed to create config directory: {:?}", parent))?;
+        let path = dir.join(file_name);
+        fs::write(&path, contents).with_context(|| format!("Failed to write file: {:?}", path))?;
+        Ok(path)
+    }
+
+    /// The config file parsed for editing, empty if there isn't one. A file
+    /// that doesn't parse is an error, so it's never overwritten
+    fn read_config_document() -> Result<DocumentMut> {
+        Self::parse_config_document(&Self::read_config_file().unwrap_or_default())
+    }
+
+    fn parse_config_document(contents: &str) -> Result<DocumentMut> {
+        contents
+            .parse()
+            .context("Config file isn't valid TOML; fix it before saving settings")
+    }
+
+    /// Set top-level keys in `doc`. Everything else, comments included, is
+    /// left as written, and a replaced value keeps its trailing comment
+    fn set_config_keys(doc: &mut DocumentMut, updates: &[(&str, toml_edit::Value)]) {
+        for (key, value) in updates {
+            match doc.get_mut(key).and_then(|item| item.as_value_mut()) {
+                Some(existing) => {
+                    let decor = existing.decor().clone();
+                    *existing = value.clone();
+                    *existing.decor_mut() = decor;
+                }
+                None => doc[key] = toml_edit::Item::Value(value.clone()),
+            }
         }
+    }
 
-        // Write config file with all settings
-        let row_display_str = match row_display {
-            RowDisplay::Compact => "compact",
-            RowDisplay::Normal => "normal",
-            RowDisplay::Comfortable => "comfortable",
-        };
-        let content = format!(
-            "api_key = \"{}\"\nrow_display = \"{}\"\nrefresh_interval_secs = {}",
-            api_key, row_display_str, refresh_interval_secs
-        );
-        fs::write(&config_path, &content)
-            .with_context(|| format!("Failed to write config file: {:?}", config_path))?;
+    /// Set top-level keys in the config file, keeping every other line
+    fn update_config_file(updates: &[(&str, toml_edit::Value)]) -> Result<()> {
+        let mut doc = Self::read_config_document()?;
+        Self::set_config_keys(&mut doc, updates);
+        Self::write_config_document(&doc)
+    }
 
-        let api_url = "https://www.moltbook.com/api/v1".to_string();
+    /// How the API client retries failed requests
+    pub fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy {
+            retries: self.retries,
+            base_delay: std::time::Duration::from_millis(self.retry_base_ms),
+        }
+    }
 
-        Ok(Self {
-            api_key: Some(api_key.to_string()),
-            api_url,
-            row_display,
-            refresh_interval_secs,
-        })
+    /// Post IDs seen in earlier sessions, oldest first (empty if none were saved)
+    pub fn load_seen_post_ids() -> Vec<String> {
+        Self::data_dir()
+            .and_then(|dir| fs::read_to_string(dir.join("seen.json")).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
     }
 
-    pub fn save_settings(row_display: RowDisplay, refresh_interval_secs: u64) -> Result<()> {
+    /// Save the newest `SEEN_POSTS_LIMIT` seen post IDs for the next session
+    pub fn save_seen_post_ids(ids: &[String]) -> Result<PathBuf> {
+        let newest = &ids[ids.len().saturating_sub(SEEN_POSTS_LIMIT)..];
+        Self::write_data_file("seen.json", &serde_json::to_string(newest)?)
+    }
+
+    /// Posts bookmarked in earlier sessions, newest first (empty if none
+    /// were saved)
+    pub fn load_bookmarks() -> Vec<Post> {
+        Self::data_dir()
+            .and_then(|dir| fs::read_to_string(dir.join("bookmarks.json")).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save_bookmarks(posts: &[Post]) -> Result<PathBuf> {
+        Self::write_data_file("bookmarks.json", &serde_json::to_string_pretty(posts)?)
+    }
+
+    /// Add `preset` to the `[presets]` table, replacing one with the same name
+    pub fn save_preset(preset: &Preset) -> Result<()> {
+        let mut doc = Self::read_config_document()?;
+        let presets = doc
+            .entry("presets")
+            .or_insert(toml_edit::table())
+            .as_table_like_mut()
+            .context("`presets` in the config file isn't a table")?;
+        presets.insert(&preset.name, toml_edit::value(preset.to_value()));
+        Self::write_config_document(&doc)
+    }
+
+    fn write_config_document(doc: &DocumentMut) -> Result<()> {
         let config_path = Self::config_file_path()
             .ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?;
 
@@ -175,31 +911,130 @@ impl Config {
                 .with_context(|| format!("Failed to create config directory: {:?}", parent))?;
         }
 
-        // Load existing api_key if present
-        let api_key = Self::load_api_key();
+        fs::write(&config_path, doc.to_string())
+            .with_context(|| format!("Failed to write config file: {:?}", config_path))?;
+
+        Ok(())
+    }
+
+    pub fn save(api_key: &str) -> Result<Self> {
+        Self::update_config_file(&[("api_key", api_key.into())])?;
+
+        // Reload settings so the returned config reflects the file
+        let mut config = Self::load_settings();
+        config.api_key = Some(api_key.to_string());
+
+        Ok(config)
+    }
+
+    pub fn save_pinned_submolts(names: &[String]) -> Result<()> {
+        let names: toml_edit::Array = names.iter().collect();
+        Self::update_config_file(&[("pinned_submolts", names.into())])
+    }
 
-        // Write config file with all settings
+    pub fn save_settings(row_display: RowDisplay, refresh_interval_secs: u64) -> Result<()> {
         let row_display_str = match row_display {
+            RowDisplay::Dense => "dense",
             RowDisplay::Compact => "compact",
             RowDisplay::Normal => "normal",
             RowDisplay::Comfortable => "comfortable",
         };
 
-        let content = if let Some(key) = api_key {
-            format!(
-                "api_key = \"{}\"\nrow_display = \"{}\"\nrefresh_interval_secs = {}",
-                key, row_display_str, refresh_interval_secs
-            )
-        } else {
-            format!(
-                "row_display = \"{}\"\nrefresh_interval_secs = {}",
-                row_display_str, refresh_interval_secs
-            )
-        };
+        Self::update_config_file(&[
+            ("row_display", row_display_str.into()),
+            (
+                "refresh_interval_secs",
+                (refresh_interval_secs as i64).into(),
+            ),
+        ])
+    }
+}
 
-        fs::write(&config_path, &content)
-            .with_context(|| format!("Failed to write config file: {:?}", config_path))?;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        Ok(())
+    #[test]
+    fn mistyped_value_is_skipped_without_losing_other_settings() {
+        let contents = r#"
+api_key = "moltbook_sk_test"
+reduce_motion = "true"
+refresh_interval_secs = "30"
+show_read_time = false
+"#;
+        let config = Config::settings_from(Some(contents));
+        assert!(!config.reduce_motion);
+        assert_eq!(
+            config.refresh_interval_secs,
+            Config::default().refresh_interval_secs
+        );
+        assert!(!config.show_read_time);
+        assert_eq!(config.warnings.len(), 2);
+        assert!(config.warnings.iter().any(|w| w.contains("reduce_motion")));
+        assert_eq!(
+            Config::api_key_from(contents).as_deref(),
+            Some("moltbook_sk_test")
+        );
+    }
+
+    #[test]
+    fn saving_settings_keeps_api_key_and_comments() {
+        let contents = "# moltbook-tui\napi_key = \"moltbook_sk_test\"\nrow_display = \"normal\" # set by hand\n\n[profiles.work]\napi_key = \"moltbook_sk_work\"\n";
+        let mut doc = Config::parse_config_document(contents).unwrap();
+        Config::set_config_keys(
+            &mut doc,
+            &[
+                ("row_display", "compact".into()),
+                ("refresh_interval_secs", 30i64.into()),
+            ],
+        );
+        let saved = doc.to_string();
+
+        assert!(saved.contains("# moltbook-tui"));
+        assert!(saved.contains("row_display = \"compact\" # set by hand"));
+        assert_eq!(
+            Config::api_key_from(&saved).as_deref(),
+            Some("moltbook_sk_test")
+        );
+        let config = Config::settings_from(Some(&saved));
+        assert_eq!(config.row_display, RowDisplay::Compact);
+        assert_eq!(config.refresh_interval_secs, 30);
+        assert_eq!(config.profiles.len(), 1);
+    }
+
+    #[test]
+    fn default_retries_make_three_attempts() {
+        // `retries` counts the attempts after the first one
+        let policy = Config::default().retry_policy();
+        assert_eq!(policy.retries + 1, 3);
+        assert_eq!(policy.base_delay, std::time::Duration::from_millis(500));
+    }
+
+    #[test]
+    fn unknown_nav_tabs_fall_back_to_the_defaults() {
+        let (tabs, warnings) = Config::parse_nav_tabs(&["nope".to_string()]);
+        assert_eq!(tabs, NavTab::DEFAULT);
+        assert_eq!(
+            warnings,
+            [
+                "Unknown nav tab in config: \"nope\"",
+                "No known nav tabs in config, showing the default tabs",
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_file_gives_defaults() {
+        let config = Config::settings_from(None);
+        let defaults = Config::default();
+        assert_eq!(config.row_display, defaults.row_display);
+        assert_eq!(config.refresh_interval_secs, defaults.refresh_interval_secs);
+        assert_eq!(config.api_url, defaults.api_url);
+        assert!(config.warnings.is_empty());
+
+        // Saving into a missing file starts a new one
+        let mut doc = Config::parse_config_document("").unwrap();
+        Config::set_config_keys(&mut doc, &[("api_key", "moltbook_sk_test".into())]);
+        assert_eq!(doc.to_string(), "api_key = \"moltbook_sk_test\"\n");
     }
 }
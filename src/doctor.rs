@@ -0,0 +1,128 @@
+use crate::api::ApiClient;
+use crate::config::{ColorMode, Config};
+
+use std::io::IsTerminal;
+use std::time::Instant;
+
+/// Outcome of one `--doctor` check
+enum Status {
+    Ok,
+    Warn,
+    Fail,
+}
+
+fn report(status: Status, check: &str, detail: impl AsRef<str>) {
+    let label = match status {
+        Status::Ok => "[ OK ]",
+        Status::Warn => "[WARN]",
+        Status::Fail => "[FAIL]",
+    };
+    println!("{} {:<12} {}", label, check, detail.as_ref());
+}
+
+/// Check the config file, API key, API connectivity and terminal, printing
+/// one line per check. Returns false if a check the app can't work without
+/// failed
+pub async fn run(config: &Config, api_client: &ApiClient) -> bool {
+    let mut ok = true;
+    println!("moltbook {}\n", env!("CARGO_PKG_VERSION"));
+
+    // Config file: missing is fine (defaults apply), unreadable is not
+    match Config::config_file_path() {
+        Some(path) if !path.exists() => report(
+            Status::Ok,
+            "Config",
+            format!("{} not found, using defaults", path.display()),
+        ),
+        Some(path) => match std::fs::read_to_string(&path) {
+            Ok(_) => report(Status::Ok, "Config", path.display().to_string()),
+            Err(e) => {
+                ok = false;
+                report(
+                    Status::Fail,
+                    "Config",
+                    format!("{} can't be read: {}", path.display(), e),
+                );
+            }
+        },
+        None => report(Status::Warn, "Config", "no home directory, using defaults"),
+    }
+    for warning in &config.warnings {
+        report(Status::Warn, "Config", warning);
+    }
+
+    match config.api_key_source() {
+        Some(source) => report(Status::Ok, "API key", format!("set ({})", source)),
+        None => report(
+            Status::Warn,
+            "API key",
+            "not set; browsing works, reporting posts doesn't",
+        ),
+    }
+
+    report(Status::Ok, "Proxy", api_client.proxy_status());
+
+    // One lightweight request shows whether the API is reachable at all
+    let started = Instant::now();
+    match api_client.get_stats().await {
+        Ok(stats) => report(
+            Status::Ok,
+            "API",
+            format!(
+                "{} reachable in {} ms ({} agents)",
+                config.api_url,
+                started.elapsed().as_millis(),
+                stats.agents
+            ),
+        ),
+        Err(e) => {
+            ok = false;
+            report(
+                Status::Fail,
+                "API",
+                format!("{} unreachable: {}", config.api_url, e),
+            );
+        }
+    }
+
+    if std::io::stdout().is_terminal() {
+        report(Status::Ok, "Terminal", "stdout is a terminal");
+    } else {
+        report(Status::Warn, "Terminal", "stdout is not a terminal");
+    }
+    let term = std::env::var("TERM").unwrap_or_default();
+    let color_mode = config.color_mode.unwrap_or_else(ColorMode::detect);
+    let colors = format!(
+        "{} (TERM={}, COLORTERM={})",
+        color_mode.as_str(),
+        term,
+        std::env::var("COLORTERM").unwrap_or_default()
+    );
+    if color_mode == ColorMode::TrueColor {
+        report(Status::Ok, "Colors", colors);
+    } else {
+        report(Status::Warn, "Colors", colors);
+    }
+    // Mouse reporting can't be queried; dumb terminals are known not to have it
+    if term == "dumb" {
+        report(
+            Status::Warn,
+            "Mouse",
+            "TERM=dumb, mouse input unlikely to work",
+        );
+    } else {
+        report(
+            Status::Ok,
+            "Mouse",
+            "enabled at startup (keys work without it)",
+        );
+    }
+
+    println!();
+    if ok {
+        println!("All critical checks passed");
+    } else {
+        println!("Some critical checks failed");
+    }
+    ok
+}
@@ -0,0 +1,128 @@
+//! OSC 8 hyperlinks for URLs on screen. Ratatui has no notion of links, so
+//! after each frame the linked cells are printed again wrapped in the escape
+//! sequence; the text and colors stay exactly as drawn
+
+use crate::screenshot::sgr;
+use ratatui::buffer::Buffer;
+
+/// A run of cells on one row that links to `url`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkArea {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub url: String,
+}
+
+/// Every place `text` is drawn within a single row of `buffer`. Text that
+/// wrapped onto the next row isn't found
+pub fn find(buffer: &Buffer, text: &str, url: &str) -> Vec<LinkArea> {
+    let chars: Vec<char> = text.chars().collect();
+    let area = buffer.area;
+    let width = chars.len() as u16;
+    let mut found = Vec::new();
+    if chars.is_empty() || width > area.width {
+        return found;
+    }
+
+    for y in area.top()..area.bottom() {
+        let mut x = area.left();
+        while x + width <= area.right() {
+            let matches = chars.iter().enumerate().all(|(i, c)| {
+                let mut symbol = buffer[(x + i as u16, y)].symbol().chars();
+                symbol.next() == Some(*c) && symbol.next().is_none()
+            });
+            if matches {
+                found.push(LinkArea {
+                    x,
+                    y,
+                    width,
+                    url: url.to_string(),
+                });
+                x += width;
+            } else {
+                x += 1;
+            }
+        }
+    }
+    found
+}
+
+/// Escape sequences that reprint each area's cells from `buffer` as a link.
+/// URLs with control characters are left unlinked, since an ESC or BEL in one
+/// would end the sequence early and the rest would reach the terminal raw
+pub fn to_osc8(buffer: &Buffer, areas: &[LinkArea]) -> String {
+    let mut out = String::new();
+    for link in areas {
+        if link.url.chars().any(char::is_control) {
+            continue;
+        }
+        // Cursor positions are 1-based
+        out.push_str(&format!("\x1b[{};{}H", link.y + 1, link.x + 1));
+        out.push_str(&format!("\x1b]8;;{}\x1b\\", link.url));
+        for x in link.x..link.x + link.width {
+            let cell = &buffer[(x, link.y)];
+            out.push_str(&sgr(cell.fg, cell.bg, cell.modifier));
+            out.push_str(cell.symbol());
+        }
+        out.push_str("\x1b]8;;\x1b\\\x1b[0m");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_returns_each_match_on_a_row() {
+        let buffer = Buffer::with_lines(["see a.io and a.io", "a.io"]);
+        let areas = find(&buffer, "a.io", "https://a.io");
+        let positions: Vec<(u16, u16)> = areas.iter().map(|a| (a.x, a.y)).collect();
+        assert_eq!(positions, [(4, 0), (13, 0), (0, 1)]);
+        assert!(areas
+            .iter()
+            .all(|a| a.width == 4 && a.url == "https://a.io"));
+    }
+
+    #[test]
+    fn find_skips_wrapped_and_missing_text() {
+        let buffer = Buffer::with_lines(["link: https://exam", "ple.com"]);
+        assert!(find(&buffer, "https://example.com", "https://example.com").is_empty());
+        assert!(find(&buffer, "nowhere", "https://nowhere.io").is_empty());
+        assert!(find(&buffer, "", "https://a.io").is_empty());
+    }
+
+    #[test]
+    fn to_osc8_wraps_the_cells_in_a_link() {
+        let buffer = Buffer::with_lines(["xx ab"]);
+        let area = LinkArea {
+            x: 3,
+            y: 0,
+            width: 2,
+            url: "https://a.io".to_string(),
+        };
+        assert_eq!(
+            to_osc8(&buffer, &[area]),
+            "\x1b[1;4H\x1b]8;;https://a.io\x1b\\\x1b[0ma\x1b[0mb\x1b]8;;\x1b\\\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn to_osc8_skips_urls_with_control_characters() {
+        let buffer = Buffer::with_lines(["ab"]);
+        for url in [
+            "https://a.io/\x1b]0;pwned\x07",
+            "https://a.io/\n",
+            "https://a.io/\x07",
+        ] {
+            let area = LinkArea {
+                x: 0,
+                y: 0,
+                width: 2,
+                url: url.to_string(),
+            };
+            assert_eq!(to_osc8(&buffer, &[area]), "");
+        }
+    }
+}
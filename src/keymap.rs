@@ -0,0 +1,439 @@
+use crate::app::Screen;
+
+use crossterm::event::KeyCode;
+use std::collections::{HashMap, HashSet};
+
+/// Actions that can be rebound in the `[keybindings]` config table, with the
+/// key each is bound to by default
+const ACTIONS: &[(&str, KeyCode)] = &[
+    ("quit", KeyCode::Char('q')),
+    ("help", KeyCode::Char('?')),
+    ("back", KeyCode::Esc),
+    ("open", KeyCode::Enter),
+    ("next", KeyCode::Char('j')),
+    ("prev", KeyCode::Char('k')),
+    ("left", KeyCode::Char('h')),
+    ("right", KeyCode::Char('l')),
+    ("refresh", KeyCode::Char('r')),
+    ("open_browser", KeyCode::Char('o')),
+    ("copy_link", KeyCode::Char('y')),
+    ("bookmark", KeyCode::Char('b')),
+    ("search", KeyCode::Char('/')),
+    ("next_page", KeyCode::Char('N')),
+    ("prev_page", KeyCode::Char('P')),
+];
+
+/// Keys rebound in `[keybindings]`. Input is translated back to the default
+/// keys before it's handled, so the handlers only ever see one set of keys
+#[derive(Debug, Clone, Default)]
+pub struct Keymap {
+    // Configured key -> default key of the action it's bound to
+    remapped: HashMap<KeyCode, KeyCode>,
+    // Default keys of rebound actions, which no longer do anything
+    unbound: HashSet<KeyCode>,
+}
+
+impl Keymap {
+    /// Build a keymap from the `[keybindings]` table (`action = "key"`),
+    /// returning a warning for each entry that can't be used
+    pub fn parse(table: &toml::Table) -> (Self, Vec<String>) {
+        let mut keymap = Keymap::default();
+        let mut warnings = Vec::new();
+        let mut rebound = Vec::new();
+        for (action, value) in table {
+            let Some(&(_, default)) = ACTIONS.iter().find(|(name, _)| name == action) else {
+                warnings.push(format!("Unknown action in [keybindings]: {:?}", action));
+                continue;
+            };
+            let Some(key) = value.as_str().and_then(parse_key) else {
+                warnings.push(format!(
+                    "Invalid key for {} in [keybindings]: {}",
+                    action, value
+                ));
+                continue;
+            };
+            if key == default {
+                continue;
+            }
+            if keymap.remapped.insert(key, default).is_some() {
+                warnings.push(format!("Key {} is bound to more than one action", value));
+            }
+            keymap.unbound.insert(default);
+            rebound.push((action, value, key));
+        }
+        // A new key takes over whatever it did before, unless that was an
+        // action that has moved elsewhere too (as when two keys are swapped)
+        for (action, value, key) in rebound {
+            if keymap.unbound.contains(&key) {
+                continue;
+            }
+            if let Some(what) = default_use(key) {
+                warnings.push(format!(
+                    "Key {} for {} in [keybindings] replaces {:?}",
+                    value, action, what
+                ));
+            }
+        }
+        (keymap, warnings)
+    }
+
+    /// The default key `key` stands for, or None if it's a default key whose
+    /// action was moved elsewhere
+    pub fn resolve(&self, key: KeyCode) -> Option<KeyCode> {
+        match self.remapped.get(&key) {
+            Some(&default) => Some(default),
+            None if self.unbound.contains(&key) => None,
+            None => Some(key),
+        }
+    }
+
+    /// The key bound to the action whose default is `default`
+    fn bound_key(&self, default: KeyCode) -> KeyCode {
+        self.remapped
+            .iter()
+            .find(|(_, &action_default)| action_default == default)
+            .map_or(default, |(&key, _)| key)
+    }
+
+    /// A key group such as "j/k" or "N/P" as it reads with this keymap, with
+    /// each rebound default key replaced by the key it moved to
+    pub fn label(&self, keys: &str) -> String {
+        if self.remapped.is_empty() {
+            return keys.to_string();
+        }
+        // A lone separator such as "/" is a key of its own
+        if let Some(key) = parse_key(keys) {
+            return key_name(self.bound_key(key));
+        }
+        let mut label = String::new();
+        for piece in keys.split_inclusive(['/', ' ']) {
+            let token = piece.trim_end_matches(['/', ' ']);
+            match parse_key(token) {
+                Some(key) => label.push_str(&key_name(self.bound_key(key))),
+                None => label.push_str(token),
+            }
+            label.push_str(&piece[token.len()..]);
+        }
+        label
+    }
+
+    /// A footer hint such as "j/k: Nav • Esc: Back" with the key group of
+    /// each part relabelled for this keymap
+    pub fn hint(&self, hint: &str) -> String {
+        hint.split(" • ")
+            .map(|part| match part.split_once(": ") {
+                Some((keys, what)) => format!("{}: {}", self.label(keys), what),
+                None => part.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" • ")
+    }
+}
+
+/// A key as written in the config: one character, or a name like `Enter`
+fn parse_key(name: &str) -> Option<KeyCode> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c));
+    }
+    match name.to_ascii_lowercase().as_str() {
+        "enter" => Some(KeyCode::Enter),
+        "esc" => Some(KeyCode::Esc),
+        "tab" => Some(KeyCode::Tab),
+        "backspace" => Some(KeyCode::Backspace),
+        "space" => Some(KeyCode::Char(' ')),
+        "up" => Some(KeyCode::Up),
+        "down" => Some(KeyCode::Down),
+        "left" => Some(KeyCode::Left),
+        "right" => Some(KeyCode::Right),
+        "pageup" => Some(KeyCode::PageUp),
+        "pagedown" => Some(KeyCode::PageDown),
+        _ => None,
+    }
+}
+
+/// How a key is written in hints and the cheatsheet
+fn key_name(key: KeyCode) -> String {
+    match key {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::PageUp => "PgUp".to_string(),
+        KeyCode::PageDown => "PgDn".to_string(),
+        other => format!("{:?}", other),
+    }
+}
+
+/// A key (or key group) and what it does, as shown in the cheatsheet
+pub type Shortcut = (&'static str, &'static str);
+
+/// What `key` does on some screen, going by the cheatsheet tables
+fn default_use(key: KeyCode) -> Option<&'static str> {
+    [
+        GLOBAL,
+        FEED,
+        POST_DETAIL,
+        LEADERBOARD,
+        RECENT_AGENTS,
+        TOP_PAIRINGS,
+        SUBMOLTS,
+        AGENT_PROFILE,
+        HISTORY,
+        BOOKMARKS,
+        STATS,
+        SETTINGS,
+    ]
+    .into_iter()
+    .flatten()
+    .find(|(keys, _)| shortcut_keys(keys).contains(&key))
+    .map(|(_, what)| *what)
+}
+
+/// Keys named in a cheatsheet entry such as "j/k", "1-8" or "f ←/→"
+fn shortcut_keys(keys: &str) -> Vec<KeyCode> {
+    keys.split(['/', ' '])
+        .flat_map(|token| match token.as_bytes() {
+            &[from @ b'0'..=b'9', b'-', to @ b'0'..=b'9'] => {
+                (from..=to).map(|d| KeyCode::Char(d as char)).collect()
+            }
+            _ => parse_key(token).into_iter().collect::<Vec<_>>(),
+        })
+        .collect()
+}
+
+/// Keys that work on every screen
+const GLOBAL: &[Shortcut] = &[
+    ("1-8", "Switch screen"),
+    ("Esc", "Go back"),
+    ("?", "Help"),
+    ("@", "Switch profile"),
+    ("S", "Screenshot"),
+    ("D", "Color depth"),
+    ("V", "History"),
+    ("W", "Bookmarks"),
+    ("H", "Hide cheatsheet"),
+    ("q", "Quit"),
+];
+
+const FEED: &[Shortcut] = &[
+    ("j/k", "Move"),
+    ("gg/G", "First/last"),
+    ("Enter", "Open post"),
+    ("Tab", "Preview author"),
+    ("v", "Split view"),
+    ("o", "Open in browser"),
+    ("y", "Copy link"),
+    ("n/t/d/R", "New/Top/Discussed/Random"),
+    ("s", "Shuffle"),
+    ("F", "Following feed"),
+    ("f ←/→", "Time filter"),
+    ("/", "Search page"),
+    ("A", "Posts by author"),
+    ("b", "Bookmark"),
+    ("i", "Reverse page"),
+    ("U", "Link domains"),
+    ("[/]", "Min score"),
+    ("N/P", "Next/prev page"),
+    ("#", "Go to page"),
+    ("r", "Refresh"),
+    ("a +/-", "Auto-refresh"),
+    ("B", "Fast refresh"),
+    ("L", "Copy feed link"),
+    ("w", "Export posts"),
+    ("M/m", "Save/open preset"),
+    ("'1-9", "Pinned submolt"),
+    ("I", "Copy ID"),
+    ("!", "Report post"),
+];
+
+const POST_DETAIL: &[Shortcut] = &[
+    ("j/k", "Move"),
+    ("Enter", "Collapse comment"),
+    ("T", "Top-level only"),
+    ("v", "Tree/flat view"),
+    ("[/]", "Min comment score"),
+    ("Tab", "Scroll post/comments"),
+    ("PgDn/PgUp", "Page post body"),
+    ("p", "Collapse post"),
+    ("J/K", "Next/prev post"),
+    ("X", "Related posts"),
+    ("b", "Bookmark"),
+    ("C", "Copy thread"),
+    ("L", "Copy comment links"),
+    ("z", "Hide header"),
+    ("Z", "Focus mode"),
+    ("o", "Open in browser"),
+    ("y", "Copy link"),
+    ("r", "Refresh"),
+    ("I", "Copy ID"),
+    ("!", "Report post"),
+];
+
+const LEADERBOARD: &[Shortcut] = &[
+    ("j/k", "Move"),
+    ("gg/G", "First/last"),
+    ("Enter", "Open profile"),
+    ("Tab", "Preview agent"),
+    ("o", "Open in browser"),
+    ("y", "Copy link"),
+    ("m", "Rank by metric"),
+    ("u", "Hide unclaimed"),
+    ("r", "Refresh"),
+    ("I", "Copy ID"),
+];
+
+const RECENT_AGENTS: &[Shortcut] = &[
+    ("j/k", "Move"),
+    ("gg/G", "First/last"),
+    ("Enter", "Open profile"),
+    ("Tab", "Preview agent"),
+    ("o", "Open in browser"),
+    ("y", "Copy link"),
+    ("u", "Hide unclaimed"),
+    ("r", "Refresh"),
+    ("I", "Copy ID"),
+];
+
+const TOP_PAIRINGS: &[Shortcut] = &[
+    ("j/k", "Move"),
+    ("gg/G", "First/last"),
+    ("Enter", "Open profile"),
+    ("Tab", "Preview agent"),
+    ("r", "Refresh"),
+];
+
+const SUBMOLTS: &[Shortcut] = &[
+    ("h/j/k/l", "Move"),
+    ("g", "Jump by name"),
+    ("Enter", "Open feed"),
+    ("Space", "Details"),
+    ("p", "Pin / unpin"),
+    ("Tab", "Preview posts"),
+    ("o", "Open in browser"),
+    ("y", "Copy link"),
+    ("r", "Refresh"),
+    ("I", "Copy ID"),
+];
+
+const AGENT_PROFILE: &[Shortcut] = &[
+    ("j/k", "Move"),
+    ("gg/G", "First/last"),
+    ("Enter", "Open post"),
+    ("s", "Sort posts"),
+    ("O", "Owner details"),
+    ("z", "Hide header"),
+    ("o", "Open in browser"),
+    ("y", "Copy link"),
+    ("I", "Copy ID"),
+];
+
+const HISTORY: &[Shortcut] = &[("j/k", "Move"), ("Enter", "Open again"), ("I", "Copy ID")];
+
+const BOOKMARKS: &[Shortcut] = &[
+    ("j/k", "Move"),
+    ("gg/G", "First/last"),
+    ("Enter", "Open post"),
+    ("b", "Remove bookmark"),
+    ("I", "Copy ID"),
+];
+
+const STATS: &[Shortcut] = &[("r", "Refresh")];
+
+const SETTINGS: &[Shortcut] = &[("j/k", "Move"), ("←/→", "Change")];
+
+/// Shortcuts valid on `screen`, screen-specific keys first, with keys as
+/// bound in `keymap`
+pub fn shortcuts_for(screen: &Screen, keymap: &Keymap) -> Vec<(String, &'static str)> {
+    let specific: &[Shortcut] = match screen {
+        Screen::Feed => FEED,
+        Screen::PostDetail => POST_DETAIL,
+        Screen::Leaderboard => LEADERBOARD,
+        Screen::RecentAgents => RECENT_AGENTS,
+        Screen::TopPairings => TOP_PAIRINGS,
+        Screen::Submolts => SUBMOLTS,
+        Screen::AgentProfile => AGENT_PROFILE,
+        Screen::History => HISTORY,
+        Screen::Bookmarks => BOOKMARKS,
+        Screen::Stats => STATS,
+        Screen::Settings => SETTINGS,
+        Screen::Setup => return Vec::new(),
+    };
+    specific
+        .iter()
+        .chain(GLOBAL)
+        .map(|(keys, what)| (keymap.label(keys), *what))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(config: &str) -> (Keymap, Vec<String>) {
+        Keymap::parse(&config.parse().unwrap())
+    }
+
+    #[test]
+    fn swapped_keys_trade_actions() {
+        let (keymap, warnings) = parse("next = \"k\"\nprev = \"j\"");
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!(keymap.resolve(KeyCode::Char('k')), Some(KeyCode::Char('j')));
+        assert_eq!(keymap.resolve(KeyCode::Char('j')), Some(KeyCode::Char('k')));
+    }
+
+    #[test]
+    fn moved_action_frees_its_default_key() {
+        let (keymap, warnings) = parse("open_browser = \"x\"");
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!(keymap.resolve(KeyCode::Char('x')), Some(KeyCode::Char('o')));
+        assert_eq!(keymap.resolve(KeyCode::Char('o')), None);
+        assert_eq!(keymap.resolve(KeyCode::Char('q')), Some(KeyCode::Char('q')));
+    }
+
+    #[test]
+    fn duplicate_binding_warns() {
+        let (_, warnings) = parse("open_browser = \"x\"\ncopy_link = \"x\"");
+        assert!(warnings.iter().any(|w| w.contains("more than one action")));
+    }
+
+    #[test]
+    fn taking_another_key_warns() {
+        let (keymap, warnings) = parse("bookmark = \"J\"");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("Next/prev post"), "{:?}", warnings);
+        assert_eq!(keymap.resolve(KeyCode::Char('J')), Some(KeyCode::Char('b')));
+    }
+
+    #[test]
+    fn labels_show_the_bound_keys() {
+        let (keymap, _) = parse("next = \"e\"\nquit = \"Q\"\nopen = \"Space\"");
+        assert_eq!(keymap.label("j/k"), "e/k");
+        assert_eq!(keymap.label("gg/G"), "gg/G");
+        assert_eq!(keymap.label("j / ↓"), "e / ↓");
+        assert_eq!(keymap.label("/"), "/");
+        assert_eq!(
+            keymap.hint(" j/k: Nav • Enter: Open • q: Quit • Page 2"),
+            " e/k: Nav • Space: Open • Q: Quit • Page 2"
+        );
+        let shortcuts = shortcuts_for(&Screen::Feed, &keymap);
+        assert!(shortcuts.contains(&("Q".to_string(), "Quit")));
+        assert!(shortcuts.contains(&("Space".to_string(), "Open post")));
+    }
+
+    #[test]
+    fn unknown_action_and_bad_key_are_skipped() {
+        let (keymap, warnings) = parse("teleport = \"t\"\nquit = \"ctrl+q\"\nhelp = 1");
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings[0].contains("Unknown action"));
+        assert!(warnings[1].contains("Invalid key for quit"));
+        assert!(warnings[2].contains("Invalid key for help"));
+        assert_eq!(keymap.resolve(KeyCode::Char('q')), Some(KeyCode::Char('q')));
+    }
+}
@@ -1,21 +1,27 @@
 mod api;
 mod app;
+mod avatar;
+mod background;
 mod config;
+mod doctor;
+mod hyperlink;
+mod keymap;
 mod mouse;
+mod screenshot;
 mod ui;
 
 use anyhow::Result;
-use api::TimeFilter;
-use app::{App, Screen};
+use app::{App, FeedQuery, HistoryEntry, LogLevel, Screen};
 use clap::{builder::Styles, Parser};
+use config::NavTab;
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::prelude::*;
-use std::io;
-use std::sync::Arc;
+use std::io::{self, Write};
+use std::sync::{Arc, LazyLock, Mutex};
 use std::time::Duration;
 use tokio::sync::{mpsc, watch};
 
@@ -52,16 +58,43 @@ fn styles() -> Styles {
     # Launch TUI\n  \
     moltbook\n\n  \
     # Launch with auto-refresh disabled\n  \
-    moltbook --no-refresh\n\n\
+    moltbook --no-refresh\n\n  \
+    # Save the feed as ANSI text and exit\n  \
+    moltbook --screenshot feed.ans\n\n  \
+    # Check config, API access and terminal support\n  \
+    moltbook --doctor\n\n  \
+    # Use a local API mirror\n  \
+    moltbook --api-url http://localhost:3000/api/v1\n\n  \
+    # Start in one submolt's feed\n  \
+    moltbook --submolt general\n\n\
     For more information, visit: https://github.com/terminaltrove/moltbook-tui")]
 struct Cli {
     /// Disable auto-refresh on startup
     #[arg(long)]
     no_refresh: bool,
+
+    /// Render the feed once, save it to PATH and exit (plain text if PATH ends in .txt, ANSI otherwise)
+    #[arg(long, value_name = "PATH")]
+    screenshot: Option<std::path::PathBuf>,
+
+    /// Check the config file, API key, API connectivity and terminal support, then exit
+    #[arg(long)]
+    doctor: bool,
+
+    /// API base URL, overriding MOLTBOOK_API_URL and api_url in the config file
+    #[arg(long, value_name = "URL", value_parser = config::parse_api_url)]
+    api_url: Option<String>,
+
+    /// Start in this submolt's feed instead of the main feed
+    #[arg(long, value_name = "NAME")]
+    submolt: Option<String>,
 }
 
 const REFRESH_INTERVAL_SECS: u64 = 30;
 
+/// How long the selection must rest before the agent preview is fetched
+const AGENT_PREVIEW_DEBOUNCE: Duration = Duration::from_millis(250);
+
 fn open_url(url: &str) {
     #[cfg(target_os = "macos")]
     {
@@ -79,7 +112,23 @@ fn open_url(url: &str) {
             .spawn();
     }
 }
-const POSTS_LIMIT: i64 = 25;
+
+/// Clipboard handle kept for the whole session; on X11/Wayland the copied
+/// contents are only served while the handle is alive
+static CLIPBOARD: LazyLock<Mutex<Option<arboard::Clipboard>>> = LazyLock::new(|| Mutex::new(None));
+
+fn copy_to_clipboard(text: &str) -> Result<()> {
+    let mut guard = CLIPBOARD
+        .lock()
+        .map_err(|_| anyhow::anyhow!("Clipboard lock poisoned"))?;
+    if guard.is_none() {
+        *guard = Some(arboard::Clipboard::new()?);
+    }
+    if let Some(clipboard) = guard.as_mut() {
+        clipboard.set_text(text.to_string())?;
+    }
+    Ok(())
+}
 
 #[derive(Debug)]
 enum AppEvent {
@@ -93,10 +142,17 @@ enum AppEvent {
     RecentAgentsLoaded(Vec<api::RecentAgent>),
     SubmoltsLoaded(Vec<api::SubmoltFull>),
     AgentProfileLoaded(api::AgentProfileResponse),
-    AgentPreviewLoaded(api::AgentProfileResponse), // Preview update (keeps sidebar open)
+    SubmoltPreviewLoaded(String, Vec<api::Post>), // (submolt name, latest posts)
+    AgentPreviewLoaded(String, api::AgentProfileResponse), // (agent name, profile) for the sidebar
+    AgentPreviewDue(String),                      // agent name, once the debounce delay has passed
+    AvatarLoaded(String, Option<avatar::Avatar>), // (image URL, decoded avatar)
+    AnnouncementLoaded(String),
+    RelatedPostsLoaded(Vec<api::Post>),
     ConfigSaved(Result<config::Config, String>),
-    Error(String),
-    Debug(String),
+    ProfileSwitched(Result<config::Config, String>),
+    PostReported(String), // title of the reported post
+    Error(api::ErrorKind, String),
+    Debug(LogLevel, String),
     Tick,
     SpinnerTick,
 }
@@ -106,15 +162,77 @@ async fn main() -> Result<()> {
     let cli = Cli::parse();
 
     // Load config - always succeeds, api_key may be None
-    let config = config::Config::load().unwrap_or_else(|_| config::Config {
-        api_key: None,
-        api_url: "https://www.moltbook.com/api/v1".to_string(),
-        row_display: config::RowDisplay::default(),
-        refresh_interval_secs: 10,
-    });
+    let mut config = config::Config::load().unwrap_or_default();
+    if let Some(ref url) = cli.api_url {
+        config.api_url = url.clone();
+    }
+
+    // Create app - go directly to feed (no setup needed for read-only)
+    let mut app = App::with_seen_posts(config::Config::load_seen_post_ids());
+    app.bookmarks = config::Config::load_bookmarks();
+    app.refresh_interval_secs = if cli.no_refresh {
+        0
+    } else {
+        config.refresh_interval_secs
+    };
+    app.row_display = config.row_display;
+    app.compact_numbers_threshold = config.compact_numbers_threshold;
+    app.auto_collapse_score = config.auto_collapse_score;
+    app.max_comment_width = config.max_comment_width;
+    app.posts_limit = config.posts_limit;
+    app.max_feed_posts = config.max_feed_posts;
+    app.idle_refresh_multiplier = config.idle_refresh_multiplier;
+    app.new_post_highlight_secs = config.new_post_highlight_secs;
+    app.show_read_time = config.show_read_time;
+    app.show_link_domains = config.show_link_domains;
+    app.hyperlinks = config.hyperlinks;
+    app.avatars = config.avatars;
+    app.dense_spacing = config.dense_spacing;
+    app.active_profile = config.active_profile.clone();
+    app.feed_columns = config.feed_columns.clone();
+    app.nav_tabs = config.nav_tabs.clone();
+    app.presets = config.presets.clone();
+    app.color_mode = config.color_mode.unwrap_or_else(config::ColorMode::detect);
+    app.background = config.background.unwrap_or_default();
+    app.theme = config.theme;
+    app.show_verified_badge = config.show_verified_badge;
+    app.badge_color = config.badge_color;
+    app.report_reasons = config.report_reasons.clone();
+    app.pinned_submolts = config.pinned_submolts.clone();
+    app.keymap = config.keymap.clone();
+    if let Some(name) = cli.submolt.as_deref() {
+        let name = name.trim_start_matches("m/");
+        app.current_submolt = Some(app.submolt_named(name));
+    }
+    for warning in &config.warnings {
+        app.add_debug_with_level(LogLevel::Warn, warning.clone());
+    }
+
+    // Create API client (auth is optional for read-only endpoints)
+    let api_client = Arc::new(api::ApiClient::new(
+        config.api_url.clone(),
+        config.api_key.clone(),
+        config.proxy.clone(),
+        app.request_metrics.clone(),
+        config.retry_policy(),
+    ));
+    app.add_debug(format!("Proxy: {}", api_client.proxy_status()));
+
+    if cli.doctor {
+        let passed = doctor::run(&config, &api_client).await;
+        std::process::exit(if passed { 0 } else { 1 });
+    }
+
+    if let Some(path) = cli.screenshot {
+        return capture_screenshot(&path, app, api_client).await;
+    }
 
     // Setup terminal
     enable_raw_mode()?;
+    if config.background.is_none() {
+        app.background = background::detect();
+        app.add_debug(format!("Terminal background: {:?}", app.background));
+    }
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
@@ -131,21 +249,6 @@ async fn main() -> Result<()> {
         original_hook(panic_info);
     }));
 
-    // Create app - go directly to feed (no setup needed for read-only)
-    let mut app = App::new();
-    app.refresh_interval_secs = if cli.no_refresh {
-        0
-    } else {
-        config.refresh_interval_secs
-    };
-    app.row_display = config.row_display;
-
-    // Create API client (auth is optional for read-only endpoints)
-    let api_client = Arc::new(api::ApiClient::new(
-        config.api_url.clone(),
-        config.api_key.clone(),
-    ));
-
     // Create event channel
     let (tx, mut rx) = mpsc::channel::<AppEvent>(100);
 
@@ -169,10 +272,8 @@ async fn main() -> Result<()> {
             tokio::select! {
                 result = event_result => {
                     match result {
-                        Ok(Some(Event::Key(key))) => {
-                            if key.kind == KeyEventKind::Press {
-                                let _ = input_tx.send(AppEvent::Input(key.code)).await;
-                            }
+                        Ok(Some(Event::Key(key))) if key.kind == KeyEventKind::Press => {
+                            let _ = input_tx.send(AppEvent::Input(key.code)).await;
                         }
                         Ok(Some(Event::Mouse(mouse_event))) => {
                             if let crossterm::event::MouseEventKind::Down(crossterm::event::MouseButton::Left) = mouse_event.kind {
@@ -208,34 +309,54 @@ async fn main() -> Result<()> {
         }
     });
 
-    // Spawn spinner animation tick handler
+    // Spawn spinner animation tick handler; with reduce_motion the spinner
+    // stays on its first frame and there are no extra redraws
     let spinner_tx = tx.clone();
-    let spinner_handle = tokio::spawn(async move {
-        let mut interval = tokio::time::interval(Duration::from_millis(80));
-        loop {
-            interval.tick().await;
-            let _ = spinner_tx.send(AppEvent::SpinnerTick).await;
-        }
+    let spinner_interval = Duration::from_millis(config.spinner_interval_ms);
+    let spinner_handle = (!config.reduce_motion).then(|| {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(spinner_interval);
+            loop {
+                interval.tick().await;
+                let _ = spinner_tx.send(AppEvent::SpinnerTick).await;
+            }
+        })
     });
 
     // Initial load (works without auth for read-only endpoints)
     app.is_loading = true;
-    load_posts(
-        api_client.clone(),
-        app.sort_order,
-        app.time_filter_for_api(),
-        0,
-        None,
-        tx.clone(),
-    );
+    load_posts(api_client.clone(), app.feed_query(), tx.clone());
     load_stats(api_client.clone(), tx.clone());
+    load_announcement(api_client.clone(), tx.clone());
 
     // Mutable API client for setup flow
     let mut api_client = api_client;
 
     // Main loop
     loop {
-        terminal.draw(|f| ui::render(f, &mut app))?;
+        let completed = terminal.draw(|f| ui::render(f, &mut app))?;
+
+        // Make URLs on screen clickable in terminals that support OSC 8
+        if !app.hyperlink_areas.is_empty() {
+            let links = hyperlink::to_osc8(completed.buffer, &app.hyperlink_areas);
+            let mut stdout = io::stdout();
+            stdout.write_all(links.as_bytes())?;
+            stdout.flush()?;
+        }
+
+        // Capture the frame just drawn, exactly as the user saw it
+        if app.screenshot_requested {
+            app.screenshot_requested = false;
+            let file_name = format!(
+                "screenshot-{}.ans",
+                chrono::Local::now().format("%Y%m%d-%H%M%S")
+            );
+            let contents = screenshot::to_ansi(completed.buffer);
+            match config::Config::write_data_file(&file_name, &contents) {
+                Ok(path) => app.set_status(format!("Screenshot saved to {}", path.display())),
+                Err(e) => app.set_status(format!("Screenshot failed: {}", e)),
+            }
+        }
 
         if app.should_quit {
             break;
@@ -244,30 +365,65 @@ async fn main() -> Result<()> {
         if let Some(event) = rx.recv().await {
             match event {
                 AppEvent::Input(key) => {
+                    app.mark_input();
+                    let screen = app.screen.clone();
                     handle_input(&mut app, key, api_client.clone(), tx.clone());
+                    app.sync_reading_mode(&screen);
                 }
                 AppEvent::MouseClick(x, y) => {
+                    app.mark_input();
+                    let screen = app.screen.clone();
                     mouse::handle_mouse_click(&mut app, x, y, api_client.clone(), tx.clone());
+                    app.sync_reading_mode(&screen);
                 }
                 AppEvent::PostsLoaded(posts, has_more) => {
                     app.is_loading = false;
-                    app.is_background_loading = false;
                     app.error_message = None;
                     app.has_more_posts = has_more;
-                    app.update_posts(posts);
+                    if std::mem::take(&mut app.append_on_load) {
+                        app.is_background_loading = false;
+                        app.append_posts(posts);
+                    } else if std::mem::take(&mut app.is_background_loading) {
+                        app.refresh_posts(posts);
+                    } else {
+                        app.update_posts(posts);
+                    }
+                    load_more_if_filtered_out(&mut app, api_client.clone(), tx.clone());
+                    // Continue stepping through posts from the new page
+                    if app.open_selected_on_load {
+                        app.open_selected_on_load = false;
+                        if let Some(post_id) = app.selected_post().map(|p| p.id.clone()) {
+                            app.open_selected_post();
+                            app.is_loading = true;
+                            load_post_with_comments(api_client.clone(), post_id, tx.clone());
+                        }
+                    }
                 }
                 AppEvent::CommentsLoaded(comments) => {
                     app.is_loading = false;
                     app.error_message = None;
-                    app.comments = comments;
+                    if std::mem::take(&mut app.is_background_loading) {
+                        app.refresh_comments(comments);
+                    } else {
+                        app.comments = comments;
+                        app.auto_collapse_low_scored();
+                    }
                 }
                 AppEvent::StatsLoaded(stats) => {
                     app.stats = Some(stats);
                 }
+                AppEvent::RelatedPostsLoaded(posts) => {
+                    app.is_loading = false;
+                    app.related_posts = posts;
+                    app.related_selected = 0;
+                }
+                AppEvent::AnnouncementLoaded(message) => {
+                    app.announcement = Some(message);
+                }
                 AppEvent::LeaderboardLoaded(leaderboard) => {
                     app.is_loading = false;
                     app.error_message = None;
-                    app.leaderboard = leaderboard;
+                    app.update_leaderboard(leaderboard);
                 }
                 AppEvent::TopPairingsLoaded(top_pairings) => {
                     app.is_loading = false;
@@ -278,23 +434,18 @@ async fn main() -> Result<()> {
                     app.is_loading = false;
                     app.error_message = None;
                     app.recent_agents = agents;
+                    app.clamp_agent_list_selection();
                 }
-                AppEvent::SubmoltsLoaded(mut submolts) => {
+                AppEvent::SubmoltsLoaded(submolts) => {
                     app.is_loading = false;
                     app.error_message = None;
-                    // Sort submolts: featured first, then by subscriber count
-                    submolts.sort_by(|a, b| {
-                        match (&a.featured_at, &b.featured_at) {
-                            (Some(_), None) => std::cmp::Ordering::Less,
-                            (None, Some(_)) => std::cmp::Ordering::Greater,
-                            _ => b.subscriber_count.cmp(&a.subscriber_count),
-                        }
-                    });
                     app.submolts = submolts;
+                    app.sort_submolts();
                 }
                 AppEvent::AgentProfileLoaded(response) => {
                     app.is_loading = false;
                     app.error_message = None;
+                    app.record_history(HistoryEntry::Agent(response.agent.name.clone()));
                     app.agent_profile = Some(response.agent);
                     app.agent_posts = response.recent_posts;
                     app.agent_posts_selected = 0;
@@ -304,33 +455,60 @@ async fn main() -> Result<()> {
                         app.preview_agent_name = None;
                     }
                 }
-                AppEvent::AgentPreviewLoaded(response) => {
-                    // Preview update - keep sidebar open, just update the profile data
-                    app.is_loading = false;
-                    app.is_preview_loading = false;
-                    app.error_message = None;
-                    app.agent_profile = Some(response.agent);
-                    app.agent_posts = response.recent_posts;
-                    app.agent_posts_selected = 0;
+                AppEvent::AgentPreviewLoaded(name, response) => {
+                    // Preview update - keep sidebar open, just update the profile data.
+                    // A late response for an agent scrolled past is ignored
+                    if app.preview_agent_name.as_deref() == Some(name.as_str()) {
+                        app.is_loading = false;
+                        app.is_preview_loading = false;
+                        app.error_message = None;
+                        app.agent_profile = Some(response.agent);
+                        app.agent_posts = response.recent_posts;
+                        app.agent_posts_selected = 0;
+                        load_preview_avatar(&mut app, api_client.clone(), tx.clone());
+                    }
+                }
+                AppEvent::AvatarLoaded(url, avatar) => {
+                    app.avatar_cache.insert(url, avatar);
+                }
+                AppEvent::AgentPreviewDue(name) => {
+                    // Only the last move counts: an earlier one's timer finds
+                    // a newer timestamp (or another agent) and does nothing
+                    let settled = app
+                        .preview_requested_at
+                        .is_some_and(|at| at.elapsed() >= AGENT_PREVIEW_DEBOUNCE);
+                    if settled && app.preview_agent_name.as_deref() == Some(name.as_str()) {
+                        app.preview_requested_at = None;
+                        load_agent_preview(api_client.clone(), name, tx.clone());
+                    }
+                }
+                AppEvent::SubmoltPreviewLoaded(name, posts) => {
+                    // Ignore results for a submolt that is no longer being previewed
+                    if app.submolt_preview_name.as_deref() == Some(name.as_str()) {
+                        app.is_loading = false;
+                        app.is_preview_loading = false;
+                        app.submolt_preview_posts = posts;
+                    }
                 }
                 AppEvent::ConfigSaved(result) => {
                     app.is_loading = false;
                     match result {
                         Ok(cfg) => {
                             // Update API client with new config
-                            api_client = Arc::new(api::ApiClient::new(cfg.api_url, cfg.api_key));
+                            let retry_policy = cfg.retry_policy();
+                            api_client = Arc::new(api::ApiClient::new(
+                                cli.api_url.clone().unwrap_or(cfg.api_url),
+                                cfg.api_key,
+                                cfg.proxy,
+                                app.request_metrics.clone(),
+                                retry_policy,
+                            ));
+                            app.add_debug(format!("Proxy: {}", api_client.proxy_status()));
                             // Switch to feed and load data
                             app.screen = Screen::Feed;
                             app.setup_error = None;
                             app.is_loading = true;
-                            load_posts(
-                                api_client.clone(),
-                                app.sort_order,
-                                app.time_filter_for_api(),
-                                0,
-                                app.current_submolt.as_ref().map(|s| s.name.clone()),
-                                tx.clone(),
-                            );
+                            load_posts(api_client.clone(), app.feed_query(), tx.clone());
                             load_stats(api_client.clone(), tx.clone());
                         }
                         Err(e) => {
@@ -338,30 +516,61 @@ async fn main() -> Result<()> {
                         }
                     }
                 }
-                AppEvent::Error(msg) => {
+                AppEvent::ProfileSwitched(result) => match result {
+                    Ok(cfg) => {
+                        let retry_policy = cfg.retry_policy();
+                        api_client = Arc::new(api::ApiClient::new(
+                            cli.api_url.clone().unwrap_or(cfg.api_url),
+                            cfg.api_key,
+                            cfg.proxy,
+                            app.request_metrics.clone(),
+                            retry_policy,
+                        ));
+                        app.active_profile = cfg.active_profile;
+                        app.error_kind = api::ErrorKind::Other;
+                        app.error_message = None;
+                        if let Some(ref name) = app.active_profile {
+                            app.set_status(format!("Switched to profile {}", name));
+                        }
+                        app.current_page = 0;
+                        app.selected_index = 0;
+                        app.is_loading = true;
+                        load_posts(api_client.clone(), app.feed_query(), tx.clone());
+                        load_stats(api_client.clone(), tx.clone());
+                    }
+                    Err(e) => app.set_status(e),
+                },
+                AppEvent::PostReported(title) => {
+                    app.set_status(format!("Reported \"{}\"", title));
+                }
+                AppEvent::Error(kind, msg) => {
                     app.is_loading = false;
+                    app.is_background_loading = false;
+                    app.is_preview_loading = false;
+                    app.append_on_load = false;
                     app.error_message = Some(msg.clone());
-                    app.add_debug(format!("ERROR: {}", msg));
+                    app.error_kind = kind;
+                    app.add_debug_with_level(LogLevel::Error, msg);
                 }
-                AppEvent::Debug(msg) => {
-                    app.add_debug(msg);
+                AppEvent::Debug(level, msg) => {
+                    app.add_debug_with_level(level, msg);
                 }
                 AppEvent::Tick => {
-                    // Only refresh if enabled and enough time has passed
-                    let should_refresh =
-                        app.refresh_interval_secs > 0 && app.seconds_until_refresh() == 0;
+                    app.log_request_retries();
+                    app.check_loading_timeout();
+                    app.expire_fast_refresh();
+                    app.expire_new_posts();
+
+                    // Only refresh if enabled (or boosted) and enough time has passed;
+                    // the countdown uses the longer idle interval when there's no input
+                    let should_refresh = (app.refresh_interval_secs > 0 || app.is_fast_refresh())
+                        && app.is_refresh_due()
+                        && !app.is_modal_open();
                     if app.screen == Screen::Feed && !app.is_loading && should_refresh {
+                        app.refresh_from_first_page();
                         app.is_loading = true;
                         app.is_background_loading = true;
-                        let offset = app.current_page as i64 * POSTS_LIMIT;
-                        load_posts(
-                            api_client.clone(),
-                            app.sort_order,
-                            app.time_filter_for_api(),
-                            offset,
-                            app.current_submolt.as_ref().map(|s| s.name.clone()),
-                            tx.clone(),
-                        );
+                        load_posts(api_client.clone(), app.feed_query(), tx.clone());
                     }
                 }
                 AppEvent::SpinnerTick => {
@@ -380,7 +589,12 @@ async fn main() -> Result<()> {
     tokio::time::sleep(Duration::from_millis(100)).await;
     input_handle.abort();
     tick_handle.abort();
-    spinner_handle.abort();
+    if let Some(handle) = spinner_handle {
+        handle.abort();
+    }
+
+    // Remember which posts were seen, so they aren't counted as new next time
+    let seen_saved = config::Config::save_seen_post_ids(&app.seen_post_order);
 
     // Restore terminal
     disable_raw_mode()?;
@@ -391,6 +605,65 @@ async fn main() -> Result<()> {
     )?;
     terminal.show_cursor()?;
 
+    if let Err(e) = seen_saved {
+        eprintln!("Couldn't save seen posts: {}", e);
+    }
+
+    Ok(())
+}
+
+/// Load the feed and stats, render a single frame off-screen and write it to
+/// `path`. Used by `--screenshot` for scripted captures
+async fn capture_screenshot(
+    path: &std::path::Path,
+    mut app: App,
+    api_client: Arc<api::ApiClient>,
+) -> Result<()> {
+    const CAPTURE_TIMEOUT_SECS: u64 = 15;
+
+    let (tx, mut rx) = mpsc::channel::<AppEvent>(100);
+    app.is_loading = true;
+    load_posts(api_client.clone(), app.feed_query(), tx.clone());
+    load_stats(api_client, tx);
+
+    // Wait for the posts (or an error) and the header stats, whichever comes last
+    let timeout = tokio::time::sleep(Duration::from_secs(CAPTURE_TIMEOUT_SECS));
+    tokio::pin!(timeout);
+    while app.is_loading || app.stats.is_none() {
+        tokio::select! {
+            event = rx.recv() => match event {
+                Some(AppEvent::PostsLoaded(posts, has_more)) => {
+                    app.is_loading = false;
+                    app.has_more_posts = has_more;
+                    app.update_posts(posts);
+                }
+                Some(AppEvent::StatsLoaded(stats)) => app.stats = Some(stats),
+                Some(AppEvent::Error(_, msg)) => {
+                    app.is_loading = false;
+                    app.error_message = Some(msg);
+                    break;
+                }
+                Some(_) => {}
+                None => break,
+            },
+            _ = &mut timeout => break,
+        }
+    }
+    app.is_loading = false;
+
+    let (width, height) = crossterm::terminal::size().unwrap_or((120, 40));
+    let mut terminal = Terminal::new(ratatui::backend::TestBackend::new(width, height))?;
+    let completed = terminal.draw(|f| ui::render(f, &mut app))?;
+
+    let is_plain_text = path.extension().is_some_and(|ext| ext == "txt");
+    let contents = if is_plain_text {
+        screenshot::to_plain_text(completed.buffer)
+    } else {
+        screenshot::to_ansi(completed.buffer)
+    };
+    std::fs::write(path, contents)?;
+    println!("Saved screenshot to {}", path.display());
+
     Ok(())
 }
 
@@ -439,24 +712,274 @@ fn handle_input(
         return;
     }
 
-    // Help menu takes priority - close it on any key
+    // Use api_client for data loading
+    let api_client = _api_client;
+
+    // Naming a preset for the current feed view
+    if let Some(mut name) = app.preset_name_input.take() {
+        match key {
+            // Quotes and '=' would break the config line
+            KeyCode::Char(c) if c != '"' && c != '=' => {
+                name.push(c);
+                app.preset_name_input = Some(name);
+            }
+            KeyCode::Backspace => {
+                name.pop();
+                app.preset_name_input = Some(name);
+            }
+            KeyCode::Enter if !name.trim().is_empty() => {
+                let preset = app.current_preset(name.trim().to_string());
+                match config::Config::save_preset(&preset) {
+                    Ok(()) => {
+                        app.set_status(format!("Saved preset \"{}\"", preset.name));
+                        app.store_preset(preset);
+                    }
+                    Err(e) => app.set_status(format!("Failed to save preset: {}", e)),
+                }
+            }
+            KeyCode::Esc => {}
+            _ => app.preset_name_input = Some(name),
+        }
+        return;
+    }
+
+    // Feed search: typing filters the loaded posts as you go
+    if app.search_active {
+        match key {
+            KeyCode::Char(c) => {
+                let mut query = app.search_query.clone();
+                query.push(c);
+                app.set_search_query(query);
+            }
+            KeyCode::Backspace => {
+                let mut query = app.search_query.clone();
+                query.pop();
+                app.set_search_query(query);
+            }
+            KeyCode::Enter => app.search_active = false,
+            KeyCode::Esc => {
+                app.search_active = false;
+                app.set_search_query(String::new());
+            }
+            _ => {}
+        }
+        return;
+    }
+
+    // Page number prompt
+    if let Some(mut input) = app.page_input.take() {
+        match key {
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                input.push(c);
+                app.page_input = Some(input);
+            }
+            KeyCode::Backspace => {
+                input.pop();
+                app.page_input = Some(input);
+            }
+            KeyCode::Enter => match input.parse::<usize>() {
+                // The page count isn't known, so any page can be tried
+                Ok(page) if page >= 1 => {
+                    app.add_debug(format!("-> Page {}", page));
+                    app.current_page = page - 1;
+                    app.selected_index = 0;
+                    app.is_loading = true;
+                    load_posts(api_client, app.feed_query(), tx);
+                }
+                _ => {
+                    app.set_status("Enter a page number from 1".to_string());
+                    app.page_input = Some(input);
+                }
+            },
+            KeyCode::Esc => {}
+            _ => app.page_input = Some(input),
+        }
+        return;
+    }
+
+    // Submolt quick-switcher: type a name prefix, Enter selects the first match
+    if let Some(mut input) = app.submolt_jump_input.take() {
+        match key {
+            KeyCode::Char(c) => {
+                input.push(c);
+                app.submolt_jump_input = Some(input);
+            }
+            KeyCode::Backspace => {
+                input.pop();
+                app.submolt_jump_input = Some(input);
+            }
+            KeyCode::Enter => {
+                app.submolt_jump_input = Some(input);
+                match app.submolt_jump_matches().first() {
+                    Some(&index) => {
+                        app.submolts_selected = index;
+                        app.submolt_jump_input = None;
+                    }
+                    None => app.set_status("No submolt starts with that".to_string()),
+                }
+            }
+            KeyCode::Esc => {}
+            _ => app.submolt_jump_input = Some(input),
+        }
+        return;
+    }
+
+    // Rebound keys stand in for their default keys from here on (text entry
+    // above sees the raw keys), and a default key whose action was rebound
+    // does nothing
+    let Some(key) = app.keymap.resolve(key) else {
+        return;
+    };
+
+    // Help menu takes priority: j/k scroll it when it doesn't fit, ? or Esc
+    // close it, and other keys are ignored
     if app.show_help {
-        if key == KeyCode::Char('?') || key == KeyCode::Esc {
-            app.toggle_help();
+        match key {
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.help_scroll = (app.help_scroll + 1).min(ui::overlays::help_max_scroll(app));
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.help_scroll = app.help_scroll.saturating_sub(1);
+            }
+            KeyCode::Char('?') | KeyCode::Esc => app.toggle_help(),
+            _ => {}
         }
         return;
     }
 
+    // Focus mode: Z or Esc leave it, and any other key (navigation included)
+    // leaves it and then does what it normally would
+    if app.focus_mode {
+        app.toggle_focus_mode();
+        if matches!(key, KeyCode::Char('Z') | KeyCode::Esc) {
+            return;
+        }
+    }
+
     // About modal takes priority
     if app.show_about {
-        if key == KeyCode::Char('8') || key == KeyCode::Esc {
+        let about_key =
+            matches!(key, KeyCode::Char(c) if app.nav_tab_for_key(c) == Some(NavTab::About));
+        if about_key || key == KeyCode::Esc {
             app.toggle_about();
         }
         return;
     }
 
-    // Use api_client for data loading
-    let api_client = _api_client;
+    // Report reason picker
+    if let Some((post_id, title)) = app.report_target.clone() {
+        match key {
+            KeyCode::Char('j') | KeyCode::Down
+                if app.report_reason_selected + 1 < app.report_reasons.len() =>
+            {
+                app.report_reason_selected += 1;
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.report_reason_selected = app.report_reason_selected.saturating_sub(1);
+            }
+            KeyCode::Enter => {
+                app.report_target = None;
+                if let Some(reason) = app.report_reasons.get(app.report_reason_selected) {
+                    report_post(
+                        api_client.clone(),
+                        post_id,
+                        title,
+                        reason.clone(),
+                        tx.clone(),
+                    );
+                }
+            }
+            KeyCode::Esc => {
+                app.report_target = None;
+            }
+            _ => {}
+        }
+        return;
+    }
+
+    // Related posts modal
+    if app.show_related {
+        match key {
+            KeyCode::Char('j') | KeyCode::Down
+                if app.related_selected + 1 < app.related_posts.len() =>
+            {
+                app.related_selected += 1;
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.related_selected = app.related_selected.saturating_sub(1);
+            }
+            KeyCode::Enter => {
+                if let Some(post) = app.related_posts.get(app.related_selected).cloned() {
+                    app.show_related = false;
+                    app.add_debug(format!("-> Related post {}", post.id));
+                    let post_id = post.id.clone();
+                    app.record_history(HistoryEntry::Post(Box::new(post.clone())));
+                    app.current_post = Some(post);
+                    app.comments.clear();
+                    app.comment_scroll = 0;
+                    app.selected_comment_index = 0;
+                    app.collapsed_comments.clear();
+                    app.post_content_scroll = 0;
+                    app.is_loading = true;
+                    load_post_with_comments(api_client, post_id, tx);
+                }
+            }
+            KeyCode::Char('X') | KeyCode::Esc => {
+                app.show_related = false;
+            }
+            _ => {}
+        }
+        return;
+    }
+
+    // A pending `g` only counts if the very next key is another `g`
+    let g_pending = std::mem::take(&mut app.pending_g);
+
+    // `'` then a digit opens that pinned submolt; any other key cancels
+    if app.pinned_jump_pending {
+        app.pinned_jump_pending = false;
+        if let KeyCode::Char(c) = key {
+            let number = c.to_digit(10).unwrap_or(0) as usize;
+            match app.open_pinned_submolt(number) {
+                Some(name) => {
+                    app.add_debug(format!("-> Pinned m/{}", name));
+                    app.is_loading = true;
+                    load_posts(api_client, app.feed_query(), tx);
+                }
+                None if c.is_ascii_digit() => {
+                    app.set_status(format!("No pinned submolt {}", c));
+                }
+                None => {}
+            }
+        }
+        return;
+    }
+
+    // Preset picker
+    if app.show_preset_picker {
+        match key {
+            KeyCode::Char('j') | KeyCode::Down if app.preset_selected + 1 < app.presets.len() => {
+                app.preset_selected += 1;
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.preset_selected = app.preset_selected.saturating_sub(1);
+            }
+            KeyCode::Enter => {
+                app.show_preset_picker = false;
+                if let Some(preset) = app.presets.get(app.preset_selected).cloned() {
+                    app.add_debug(format!("-> Preset {}", preset.name));
+                    app.apply_preset(&preset);
+                    app.is_loading = true;
+                    load_posts(api_client, app.feed_query(), tx);
+                }
+            }
+            KeyCode::Char('m') | KeyCode::Esc => {
+                app.show_preset_picker = false;
+            }
+            _ => {}
+        }
+        return;
+    }
 
     // Submolt detail modal - handle its keys
     if app.show_submolt_detail {
@@ -464,29 +987,69 @@ fn handle_input(
             KeyCode::Char(' ') | KeyCode::Esc => {
                 app.show_submolt_detail = false;
             }
+            KeyCode::Char('p') => toggle_pinned_submolt(app),
             _ => {}
         }
         return;
     }
 
+    // Submolt feed preview sidebar - navigate the grid while peeking at each feed
+    if app.show_submolt_feed_preview {
+        match key {
+            KeyCode::Tab | KeyCode::Esc => {
+                app.show_submolt_feed_preview = false;
+                app.submolt_preview_name = None;
+                app.is_loading = false;
+                app.is_preview_loading = false;
+                return;
+            }
+            KeyCode::Enter => {
+                // Close the preview and open the full feed below
+                app.show_submolt_feed_preview = false;
+                app.submolt_preview_name = None;
+            }
+            KeyCode::Char('j') | KeyCode::Down => app.select_next(),
+            KeyCode::Char('k') | KeyCode::Up => app.select_previous(),
+            KeyCode::Char('h') | KeyCode::Left => app.select_left(),
+            KeyCode::Char('l') | KeyCode::Right => app.select_right(),
+            _ => return,
+        }
+        if app.show_submolt_feed_preview {
+            update_submolt_preview_for_current_selection(app, api_client, tx);
+            return;
+        }
+    }
+
     // Agent preview sidebar - handle its keys but allow navigation
     if app.show_agent_preview {
         match key {
             KeyCode::Tab | KeyCode::Esc => {
                 app.show_agent_preview = false;
                 app.preview_agent_name = None;
+                // A pending preview response will be ignored, so stop waiting on it
+                if app.is_preview_loading {
+                    app.is_loading = false;
+                    app.is_preview_loading = false;
+                }
                 return;
             }
             KeyCode::Enter => {
                 // Open full agent profile
                 app.show_agent_preview = false;
-                app.preview_agent_name = None;
+                let name = app.preview_agent_name.take();
                 app.previous_screen = Some(app.screen.clone());
                 app.screen = Screen::AgentProfile;
-                // Profile data is already loaded in app.agent_profile
+                // Profile data is usually loaded in app.agent_profile already; if the
+                // preview is still on its way it will be ignored, so load it in full
+                if app.is_preview_loading {
+                    app.is_preview_loading = false;
+                    if let Some(name) = name {
+                        load_agent_profile(api_client, name, tx);
+                    }
+                }
                 return;
             }
-            KeyCode::Char('j') | KeyCode::Down | KeyCode::Char('k') | KeyCode::Up => {
+            KeyCode::Char('j' | 'k' | 'g' | 'G') | KeyCode::Down | KeyCode::Up => {
                 // Allow navigation - fall through to normal handling
             }
             _ => {
@@ -501,21 +1064,21 @@ fn handle_input(
             KeyCode::Char('e') => {
                 app.show_technical_error = !app.show_technical_error;
             }
-            KeyCode::Char('r') => {
-                if app.screen == Screen::Feed {
-                    app.error_message = None;
-                    app.show_technical_error = false;
-                    app.is_loading = true;
-                    let offset = app.current_page as i64 * POSTS_LIMIT;
-                    load_posts(
-                        api_client.clone(),
-                        app.sort_order,
-                        app.time_filter_for_api(),
-                        offset,
-                        app.current_submolt.as_ref().map(|s| s.name.clone()),
-                        tx.clone(),
-                    );
-                }
+            KeyCode::Char('r') if app.screen == Screen::Feed => {
+                app.error_message = None;
+                app.show_technical_error = false;
+                app.is_loading = true;
+                load_posts(api_client.clone(), app.feed_query(), tx.clone());
+            }
+            // Rejected API key: go re-enter it
+            KeyCode::Char('s') if app.error_kind == api::ErrorKind::Auth => {
+                app.error_message = None;
+                app.show_technical_error = false;
+                app.error_kind = api::ErrorKind::Other;
+                app.is_loading = false;
+                app.api_key_input.clear();
+                app.setup_error = Some("Your API key was rejected".to_string());
+                app.screen = Screen::Setup;
             }
             KeyCode::Esc => {
                 app.error_message = None;
@@ -533,8 +1096,97 @@ fn handle_input(
         KeyCode::Char('?') => {
             app.toggle_help();
         }
+        KeyCode::Char('S') => {
+            app.screenshot_requested = true;
+        }
+        KeyCode::Char('z') => {
+            app.toggle_reading_mode();
+        }
+        KeyCode::Char('Z') => {
+            app.toggle_focus_mode();
+        }
+        // Posts and profiles opened this session
+        KeyCode::Char('V') if app.screen != Screen::Setup => {
+            app.open_history();
+        }
+        KeyCode::Char('W') if app.screen != Screen::Setup => {
+            app.open_bookmarks();
+        }
+        // Bookmark the selected or open post, or remove the bookmark; saved
+        // right away so a crash doesn't lose it
+        KeyCode::Char('b') => {
+            if let Some(post) = app.bookmark_target().cloned() {
+                let added = app.toggle_bookmark(post);
+                match config::Config::save_bookmarks(&app.bookmarks) {
+                    Ok(_) if added => app.set_status("Bookmarked".to_string()),
+                    Ok(_) => app.set_status("Bookmark removed".to_string()),
+                    Err(e) => app.set_status(format!("Couldn't save bookmarks: {}", e)),
+                }
+            }
+        }
+        KeyCode::Char('H') => {
+            app.show_cheatsheet = !app.show_cheatsheet;
+        }
+        // Cycle through the [profiles.*] tables in the config file
+        KeyCode::Char('@') => {
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let result = config::Config::load()
+                    .map_err(|e| e.to_string())
+                    .and_then(|cfg| {
+                        let next = cfg
+                            .next_profile()
+                            .ok_or_else(|| "No profiles in config file".to_string())?
+                            .to_string();
+                        config::Config::switch_profile(&next).map_err(|e| e.to_string())
+                    });
+                let _ = tx.send(AppEvent::ProfileSwitched(result)).await;
+            });
+        }
+        KeyCode::Char('j') | KeyCode::Down
+            if app.screen == Screen::PostDetail && app.post_content_focused =>
+        {
+            app.post_content_scroll =
+                (app.post_content_scroll + 1).min(ui::screens::post_content_max_scroll(app));
+        }
+        KeyCode::Char('k') | KeyCode::Up
+            if app.screen == Screen::PostDetail && app.post_content_focused =>
+        {
+            app.post_content_scroll = app.post_content_scroll.saturating_sub(1);
+        }
+        // PageDown / PageUp scroll the post body a page at a time, whichever
+        // pane j/k is on; one line of the previous page stays visible
+        KeyCode::PageDown | KeyCode::PageUp
+            if app.screen == Screen::PostDetail && !app.post_collapsed =>
+        {
+            let page = (app.post_block_height().saturating_sub(3) as usize).max(1);
+            app.post_content_scroll = if key == KeyCode::PageDown {
+                (app.post_content_scroll + page).min(ui::screens::post_content_max_scroll(app))
+            } else {
+                app.post_content_scroll.saturating_sub(page)
+            };
+        }
+        KeyCode::Char('g') if app.screen == Screen::Submolts && !app.submolts.is_empty() => {
+            app.submolt_jump_input = Some(String::new());
+        }
+        // `gg` (second press) / `G`: jump to the top / bottom of the list
+        KeyCode::Char('g') if g_pending && app.has_jumpable_list() => {
+            app.select_first();
+            if app.show_agent_preview {
+                update_agent_preview_for_current_selection(app, tx.clone());
+            }
+        }
+        KeyCode::Char('g') if app.has_jumpable_list() => {
+            app.pending_g = true;
+        }
+        KeyCode::Char('G') if app.has_jumpable_list() => {
+            app.select_last();
+            if app.show_agent_preview {
+                update_agent_preview_for_current_selection(app, tx.clone());
+            }
+        }
         KeyCode::Char('j') | KeyCode::Down => {
-            let was_at_last = app.selected_index == app.posts.len().saturating_sub(1);
+            let was_at_last = app.selected_index == app.visible_posts().len().saturating_sub(1);
             app.select_next();
             // If we were at last post and there's more, load next page
             if app.screen == Screen::Feed
@@ -545,19 +1197,11 @@ fn handle_input(
             {
                 app.next_page();
                 app.is_loading = true;
-                let offset = app.current_page as i64 * POSTS_LIMIT;
-                load_posts(
-                    api_client.clone(),
-                    app.sort_order,
-                    app.time_filter_for_api(),
-                    offset,
-                    app.current_submolt.as_ref().map(|s| s.name.clone()),
-                    tx.clone(),
-                );
+                load_posts(api_client.clone(), app.feed_query(), tx.clone());
             }
             // Update agent preview if sidebar is open
             if app.show_agent_preview {
-                update_agent_preview_for_current_selection(app, api_client.clone(), tx.clone());
+                update_agent_preview_for_current_selection(app, tx.clone());
             }
         }
         KeyCode::Char('k') | KeyCode::Up => {
@@ -566,86 +1210,103 @@ fn handle_input(
             // If we were at first post and there's a previous page, load it
             if app.screen == Screen::Feed
                 && was_at_first
-                && app.current_page > 0
+                && app.first_page > 0
                 && !app.is_loading
                 && !app.posts.is_empty()
             {
                 app.prev_page();
                 app.is_loading = true;
-                let offset = app.current_page as i64 * POSTS_LIMIT;
-                load_posts(
-                    api_client.clone(),
-                    app.sort_order,
-                    app.time_filter_for_api(),
-                    offset,
-                    app.current_submolt.as_ref().map(|s| s.name.clone()),
-                    tx.clone(),
-                );
+                load_posts(api_client.clone(), app.feed_query(), tx.clone());
             }
             // Update agent preview if sidebar is open
             if app.show_agent_preview {
-                update_agent_preview_for_current_selection(app, api_client.clone(), tx.clone());
+                update_agent_preview_for_current_selection(app, tx.clone());
             }
         }
-        KeyCode::Char('h') => {
-            if app.screen == Screen::Submolts {
-                app.select_left();
-            }
+        KeyCode::Char('h') if app.screen == Screen::Submolts => {
+            app.select_left();
         }
-        KeyCode::Char('l') => {
-            if app.screen == Screen::Submolts {
-                app.select_right();
-            }
+        KeyCode::Char('l') if app.screen == Screen::Submolts => {
+            app.select_right();
         }
-        KeyCode::Char(' ') => {
-            if app.screen == Screen::Submolts && !app.submolts.is_empty() {
-                app.show_submolt_detail = true;
+        KeyCode::Char('p') if app.screen == Screen::Submolts => toggle_pinned_submolt(app),
+        KeyCode::Char('\'') if app.screen == Screen::Feed => {
+            if app.pinned_submolts.is_empty() {
+                app.set_status("No pinned submolts (pin one with p on Submolts)".to_string());
+            } else {
+                app.pinned_jump_pending = true;
             }
         }
+        KeyCode::Char(' ') if app.screen == Screen::Submolts && !app.submolts.is_empty() => {
+            app.show_submolt_detail = true;
+        }
         KeyCode::Tab => {
             // Toggle agent preview modal on agent-related screens
             match app.screen {
-                Screen::Leaderboard => {
-                    if !app.leaderboard.is_empty() {
-                        let agent = &app.leaderboard[app.leaderboard_selected];
-                        app.preview_agent_name = Some(agent.name.clone());
+                // Preview the selected post's author; posts without one have nothing to show
+                Screen::Feed => {
+                    if let Some(name) = app.selected_post_author() {
+                        app.preview_agent_name = Some(name.clone());
                         app.agent_profile = None;
                         app.show_agent_preview = true;
                         // Load agent profile for preview
                         app.is_loading = true;
                         app.is_preview_loading = true;
-                        load_agent_preview(api_client.clone(), agent.name.clone(), tx.clone());
+                        load_agent_preview(api_client.clone(), name, tx.clone());
                     }
                 }
-                Screen::TopPairings => {
-                    if !app.top_pairings.is_empty() {
-                        let human = &app.top_pairings[app.top_pairings_selected];
-                        app.preview_agent_name = Some(human.bot_name.clone());
+                Screen::Leaderboard => {
+                    if let Some(name) = app.selected_leaderboard_agent().map(|a| a.name.clone()) {
+                        app.preview_agent_name = Some(name.clone());
                         app.agent_profile = None;
                         app.show_agent_preview = true;
                         // Load agent profile for preview
                         app.is_loading = true;
                         app.is_preview_loading = true;
-                        load_agent_preview(api_client.clone(), human.bot_name.clone(), tx.clone());
+                        load_agent_preview(api_client.clone(), name, tx.clone());
                     }
                 }
+                Screen::TopPairings if !app.top_pairings.is_empty() => {
+                    let human = &app.top_pairings[app.top_pairings_selected];
+                    app.preview_agent_name = Some(human.bot_name.clone());
+                    app.agent_profile = None;
+                    app.show_agent_preview = true;
+                    // Load agent profile for preview
+                    app.is_loading = true;
+                    app.is_preview_loading = true;
+                    load_agent_preview(api_client.clone(), human.bot_name.clone(), tx.clone());
+                }
                 Screen::RecentAgents => {
-                    if !app.recent_agents.is_empty() {
-                        let agent = &app.recent_agents[app.recent_selected];
-                        app.preview_agent_name = Some(agent.name.clone());
+                    if let Some(name) = app.selected_recent_agent().map(|a| a.name.clone()) {
+                        app.preview_agent_name = Some(name.clone());
                         app.agent_profile = None;
                         app.show_agent_preview = true;
                         // Load agent profile for preview
                         app.is_loading = true;
                         app.is_preview_loading = true;
-                        load_agent_preview(api_client.clone(), agent.name.clone(), tx.clone());
+                        load_agent_preview(api_client.clone(), name, tx.clone());
                     }
                 }
+                Screen::PostDetail => app.toggle_post_content_focus(),
+                // Peek at a submolt's latest posts without leaving the grid
+                Screen::Submolts if !app.submolts.is_empty() => {
+                    app.show_submolt_feed_preview = true;
+                    update_submolt_preview_for_current_selection(
+                        app,
+                        api_client.clone(),
+                        tx.clone(),
+                    );
+                }
                 _ => {}
             }
         }
         KeyCode::Enter => {
-            if app.screen == Screen::Feed {
+            if app.screen == Screen::Settings && app.settings_selected == 2 {
+                // Enter a new API key on the Setup screen
+                app.api_key_input.clear();
+                app.setup_error = None;
+                app.screen = Screen::Setup;
+            } else if app.screen == Screen::Feed {
                 if let Some(post) = app.selected_post() {
                     let post_id = post.id.clone();
                     app.open_selected_post();
@@ -658,12 +1319,11 @@ fn handle_input(
                 }
             } else if app.screen == Screen::Leaderboard {
                 // Open full agent profile
-                if !app.leaderboard.is_empty() {
-                    let agent = &app.leaderboard[app.leaderboard_selected];
+                if let Some(name) = app.selected_leaderboard_agent().map(|a| a.name.clone()) {
                     app.previous_screen = Some(Screen::Leaderboard);
                     app.screen = Screen::AgentProfile;
                     app.is_loading = true;
-                    load_agent_profile(api_client, agent.name.clone(), tx);
+                    load_agent_profile(api_client, name, tx);
                 }
             } else if app.screen == Screen::TopPairings {
                 // Open full agent profile
@@ -676,23 +1336,66 @@ fn handle_input(
                 }
             } else if app.screen == Screen::RecentAgents {
                 // Open full agent profile
-                if !app.recent_agents.is_empty() {
-                    let agent = &app.recent_agents[app.recent_selected];
+                if let Some(name) = app.selected_recent_agent().map(|a| a.name.clone()) {
                     app.previous_screen = Some(Screen::RecentAgents);
                     app.screen = Screen::AgentProfile;
                     app.is_loading = true;
-                    load_agent_profile(api_client, agent.name.clone(), tx);
+                    load_agent_profile(api_client, name, tx);
                 }
             } else if app.screen == Screen::AgentProfile {
                 // Open selected post from agent's posts
-                if !app.agent_posts.is_empty() {
-                    let post = app.agent_posts[app.agent_posts_selected].clone();
+                if let Some(post) = app.sorted_agent_posts().get(app.agent_posts_selected) {
+                    let post = (*post).clone();
                     let post_id = post.id.clone();
                     app.previous_screen = Some(Screen::AgentProfile);
+                    app.record_history(HistoryEntry::Post(Box::new(post.clone())));
                     app.current_post = Some(post);
                     app.comments.clear();
                     app.comment_scroll = 0;
                     app.selected_comment_index = 0;
+                    app.post_content_scroll = 0;
+                    app.screen = Screen::PostDetail;
+                    app.is_loading = true;
+                    load_post_with_comments(api_client, post_id, tx);
+                }
+            } else if app.screen == Screen::History {
+                // Re-open the selected post or profile
+                match app.selected_history_entry().cloned() {
+                    Some(HistoryEntry::Post(post)) => {
+                        let post_id = post.id.clone();
+                        app.previous_screen = Some(Screen::History);
+                        app.history_selected = 0;
+                        app.record_history(HistoryEntry::Post(post.clone()));
+                        app.current_post = Some(*post);
+                        app.comments.clear();
+                        app.comment_scroll = 0;
+                        app.selected_comment_index = 0;
+                        app.collapsed_comments.clear();
+                        app.post_content_scroll = 0;
+                        app.screen = Screen::PostDetail;
+                        app.is_loading = true;
+                        load_post_with_comments(api_client, post_id, tx);
+                    }
+                    Some(HistoryEntry::Agent(name)) => {
+                        app.previous_screen = Some(Screen::History);
+                        app.history_selected = 0;
+                        app.screen = Screen::AgentProfile;
+                        app.is_loading = true;
+                        load_agent_profile(api_client, name, tx);
+                    }
+                    None => {}
+                }
+            } else if app.screen == Screen::Bookmarks {
+                if let Some(post) = app.selected_bookmark().cloned() {
+                    let post_id = post.id.clone();
+                    app.previous_screen = Some(Screen::Bookmarks);
+                    app.record_history(HistoryEntry::Post(Box::new(post.clone())));
+                    app.current_post = Some(post);
+                    app.comments.clear();
+                    app.comment_scroll = 0;
+                    app.selected_comment_index = 0;
+                    app.collapsed_comments.clear();
+                    app.post_content_scroll = 0;
                     app.screen = Screen::PostDetail;
                     app.is_loading = true;
                     load_post_with_comments(api_client, post_id, tx);
@@ -702,101 +1405,101 @@ fn handle_input(
                 if !app.submolts.is_empty() {
                     let submolt = app.submolts[app.submolts_selected].clone();
                     app.current_submolt = Some(submolt.clone());
+                    app.following_only = false;
                     app.screen = Screen::Feed;
                     app.current_page = 0;
                     app.selected_index = 0;
                     app.is_loading = true;
-                    load_posts(
-                        api_client,
-                        app.sort_order,
-                        app.time_filter_for_api(),
-                        0,
-                        Some(submolt.name),
-                        tx,
-                    );
+                    load_posts(api_client, app.feed_query(), tx);
                 }
             }
         }
+        KeyCode::Esc if app.screen == Screen::Feed && !app.search_query.is_empty() => {
+            app.set_search_query(String::new());
+        }
+        KeyCode::Esc if app.screen == Screen::Feed && app.author_filter.is_some() => {
+            app.author_filter = None;
+            app.selected_index = 0;
+        }
+        KeyCode::Esc if app.screen == Screen::Feed && app.announcement.is_some() => {
+            app.announcement = None;
+        }
+        // Leave the following feed for all posts
+        KeyCode::Esc if app.screen == Screen::Feed && app.following_only && !app.is_loading => {
+            app.following_only = false;
+            app.current_page = 0;
+            app.selected_index = 0;
+            app.is_loading = true;
+            load_posts(api_client, app.feed_query(), tx);
+        }
         KeyCode::Esc => {
             app.go_back();
         }
-        KeyCode::Char('r') => {
-            if !app.is_loading {
-                match app.screen {
-                    Screen::Feed => {
-                        app.is_loading = true;
-                        let offset = app.current_page as i64 * POSTS_LIMIT;
-                        load_posts(
-                            api_client,
-                            app.sort_order,
-                            app.time_filter_for_api(),
-                            offset,
-                            app.current_submolt.as_ref().map(|s| s.name.clone()),
-                            tx,
-                        );
-                    }
-                    Screen::Leaderboard => {
-                        app.is_loading = true;
-                        load_leaderboard(api_client, tx);
-                    }
-                    Screen::TopPairings => {
-                        app.is_loading = true;
-                        load_top_pairings(api_client, tx);
-                    }
-                    Screen::RecentAgents => {
-                        app.is_loading = true;
-                        load_recent_agents(api_client, tx);
-                    }
-                    Screen::Submolts => {
-                        app.is_loading = true;
-                        load_submolts(api_client, tx);
-                    }
-                    Screen::Stats => {
-                        app.is_loading = true;
-                        load_stats(api_client, tx);
-                    }
-                    Screen::AgentProfile => {
-                        if let Some(ref profile) = app.agent_profile {
-                            app.is_loading = true;
-                            load_agent_profile(api_client, profile.name.clone(), tx);
-                        }
-                    }
-                    _ => {}
-                }
+        KeyCode::Char('r') if !app.is_loading => match app.screen {
+            Screen::Feed => {
+                app.is_loading = true;
+                load_posts(api_client, app.feed_query(), tx);
             }
-        }
-        // Sort order keys
-        KeyCode::Char('n') => {
-            if app.screen == Screen::Feed && !app.is_loading {
-                app.set_sort_order(api::SortOrder::New);
-                app.current_page = 0;
+            Screen::Leaderboard => {
                 app.is_loading = true;
-                load_posts(api_client, app.sort_order, app.time_filter_for_api(), 0, app.current_submolt.as_ref().map(|s| s.name.clone()), tx);
+                load_leaderboard(api_client, tx);
             }
-        }
-        KeyCode::Char('t') => {
-            if app.screen == Screen::Feed && !app.is_loading {
-                app.set_sort_order(api::SortOrder::Top);
-                app.current_page = 0;
+            Screen::TopPairings => {
+                app.is_loading = true;
+                load_top_pairings(api_client, tx);
+            }
+            Screen::RecentAgents => {
                 app.is_loading = true;
-                load_posts(api_client, app.sort_order, app.time_filter_for_api(), 0, app.current_submolt.as_ref().map(|s| s.name.clone()), tx);
+                load_recent_agents(api_client, tx);
             }
-        }
-        KeyCode::Char('d') => {
-            if app.screen == Screen::Feed && !app.is_loading {
-                app.set_sort_order(api::SortOrder::Discussed);
-                app.current_page = 0;
+            Screen::Submolts => {
                 app.is_loading = true;
-                load_posts(api_client, app.sort_order, app.time_filter_for_api(), 0, app.current_submolt.as_ref().map(|s| s.name.clone()), tx);
+                load_submolts(api_client, tx);
             }
-        }
-        KeyCode::Char('R') => {
-            if app.screen == Screen::Feed && !app.is_loading {
-                app.set_sort_order(api::SortOrder::Random);
-                app.current_page = 0;
+            Screen::Stats => {
                 app.is_loading = true;
-                load_posts(api_client, app.sort_order, app.time_filter_for_api(), 0, app.current_submolt.as_ref().map(|s| s.name.clone()), tx);
+                load_stats(api_client, tx);
+            }
+            Screen::AgentProfile => {
+                if let Some(ref profile) = app.agent_profile {
+                    app.is_loading = true;
+                    fetch_agent_profile(api_client, profile.name.clone(), tx);
+                }
             }
+            // Comments reload in the background so the thread stays readable
+            Screen::PostDetail => {
+                if let Some(post_id) = app.current_post.as_ref().map(|p| p.id.clone()) {
+                    app.is_loading = true;
+                    app.is_background_loading = true;
+                    load_post_with_comments(api_client, post_id, tx);
+                }
+            }
+            _ => {}
+        },
+        // Sort order keys
+        KeyCode::Char('n') if app.screen == Screen::Feed && !app.is_loading => {
+            app.set_sort_order(api::SortOrder::New);
+            app.current_page = 0;
+            app.is_loading = true;
+            load_posts(api_client, app.feed_query(), tx);
+        }
+        KeyCode::Char('t') if app.screen == Screen::Feed && !app.is_loading => {
+            app.set_sort_order(api::SortOrder::Top);
+            app.current_page = 0;
+            app.is_loading = true;
+            load_posts(api_client, app.feed_query(), tx);
+        }
+        KeyCode::Char('d') if app.screen == Screen::Feed && !app.is_loading => {
+            app.set_sort_order(api::SortOrder::Discussed);
+            app.current_page = 0;
+            app.is_loading = true;
+            load_posts(api_client, app.feed_query(), tx);
+        }
+        KeyCode::Char('R') if app.screen == Screen::Feed && !app.is_loading => {
+            app.set_sort_order(api::SortOrder::Random);
+            app.current_page = 0;
+            app.is_loading = true;
+            load_posts(api_client, app.feed_query(), tx);
         }
         KeyCode::Char('f') | KeyCode::Right => {
             if app.screen == Screen::Submolts {
@@ -810,7 +1513,7 @@ fn handle_input(
                 app.cycle_time_filter();
                 app.current_page = 0;
                 app.is_loading = true;
-                load_posts(api_client, app.sort_order, app.time_filter_for_api(), 0, app.current_submolt.as_ref().map(|s| s.name.clone()), tx);
+                load_posts(api_client, app.feed_query(), tx);
             }
         }
         KeyCode::Left => {
@@ -825,134 +1528,362 @@ fn handle_input(
                 app.cycle_time_filter_reverse();
                 app.current_page = 0;
                 app.is_loading = true;
-                load_posts(api_client, app.sort_order, app.time_filter_for_api(), 0, app.current_submolt.as_ref().map(|s| s.name.clone()), tx);
+                load_posts(api_client, app.feed_query(), tx);
             }
         }
         // 's' for shuffle (switch to random sort with fresh seed)
-        KeyCode::Char('s') => {
-            if app.screen == Screen::Feed && !app.is_loading {
-                app.set_sort_order(api::SortOrder::Random);
-                app.current_page = 0;
-                app.is_loading = true;
-                load_posts(api_client, app.sort_order, app.time_filter_for_api(), 0, app.current_submolt.as_ref().map(|s| s.name.clone()), tx);
+        KeyCode::Char('s') if app.screen == Screen::Feed && !app.is_loading => {
+            app.set_sort_order(api::SortOrder::Random);
+            app.current_page = 0;
+            app.is_loading = true;
+            load_posts(api_client, app.feed_query(), tx);
+        }
+        // Hide unclaimed agents from the agent lists
+        KeyCode::Char('u') if matches!(app.screen, Screen::Leaderboard | Screen::RecentAgents) => {
+            app.toggle_hide_unclaimed();
+            app.add_debug(format!("Hide unclaimed agents: {}", app.hide_unclaimed));
+        }
+        // Rank the leaderboard by the next metric
+        KeyCode::Char('m') if app.screen == Screen::Leaderboard => {
+            app.cycle_leaderboard_metric();
+        }
+        // Saved feed views: M names the current one, m picks one to switch to
+        KeyCode::Char('M') if app.screen == Screen::Feed => {
+            app.preset_name_input = Some(String::new());
+        }
+        KeyCode::Char('m') if app.screen == Screen::Feed => {
+            if app.presets.is_empty() {
+                app.set_status("No presets yet (M saves the current view)".to_string());
+            } else {
+                app.preset_selected = 0;
+                app.show_preset_picker = true;
             }
         }
+        // Expand the owner's X details on a profile
+        KeyCode::Char('O') if app.screen == Screen::AgentProfile => {
+            app.show_owner_details = !app.show_owner_details;
+        }
+        // Cycle the local sort of an agent's posts
+        KeyCode::Char('s') if app.screen == Screen::AgentProfile => {
+            app.cycle_agent_posts_sort();
+            app.add_debug(format!(
+                "Agent posts sort: {}",
+                app.agent_posts_sort.as_str()
+            ));
+        }
         // Page navigation (capital N/P)
-        KeyCode::Char('N') => {
-            if app.screen == Screen::Feed && !app.is_loading && app.has_more_posts {
-                app.next_page();
-                app.is_loading = true;
-                let offset = app.current_page as i64 * POSTS_LIMIT;
-                load_posts(
-                    api_client,
-                    app.sort_order,
-                    app.time_filter_for_api(),
-                    offset,
-                    app.current_submolt.as_ref().map(|s| s.name.clone()),
-                    tx,
-                );
+        KeyCode::Char('N') if app.screen == Screen::Feed && !app.is_loading && app.has_more_posts => {
+            app.next_page();
+            app.is_loading = true;
+            load_posts(api_client, app.feed_query(), tx);
+        }
+        // Jump straight to a page number
+        KeyCode::Char('#') if app.screen == Screen::Feed && !app.is_loading => {
+            app.page_input = Some(String::new());
+        }
+        KeyCode::Char('P') if app.screen == Screen::Feed && !app.is_loading && app.first_page > 0 => {
+            app.prev_page();
+            app.is_loading = true;
+            load_posts(api_client, app.feed_query(), tx);
+        }
+        // Number keys follow the configured nav tabs; hidden screens have no number
+        KeyCode::Char(c) if c.is_ascii_digit() => {
+            let Some(tab) = app.nav_tab_for_key(c) else {
+                return;
+            };
+            match tab {
+                NavTab::Feed => {
+                    app.add_debug("-> Feed".to_string());
+                    app.screen = Screen::Feed;
+                }
+                NavTab::Leaderboard => {
+                    app.add_debug("-> Leaderboard".to_string());
+                    app.screen = Screen::Leaderboard;
+                    if app.leaderboard.is_empty() {
+                        app.is_loading = true;
+                        load_leaderboard(api_client, tx);
+                    }
+                }
+                NavTab::TopPairings => {
+                    app.add_debug("-> TopPairings".to_string());
+                    app.screen = Screen::TopPairings;
+                    if app.top_pairings.is_empty() {
+                        app.is_loading = true;
+                        load_top_pairings(api_client, tx);
+                    }
+                }
+                NavTab::Agents => {
+                    app.add_debug("-> RecentAgents".to_string());
+                    app.screen = Screen::RecentAgents;
+                    if app.recent_agents.is_empty() {
+                        app.is_loading = true;
+                        load_recent_agents(api_client, tx);
+                    }
+                }
+                NavTab::Submolts => {
+                    app.add_debug("-> Submolts".to_string());
+                    app.screen = Screen::Submolts;
+                    if app.submolts.is_empty() {
+                        app.is_loading = true;
+                        load_submolts(api_client, tx);
+                    }
+                }
+                NavTab::Stats => {
+                    app.add_debug("-> Stats".to_string());
+                    app.screen = Screen::Stats;
+                    if app.stats.is_none() {
+                        app.is_loading = true;
+                        load_stats(api_client, tx);
+                    }
+                }
+                NavTab::Settings => {
+                    app.add_debug("-> Settings".to_string());
+                    app.screen = Screen::Settings;
+                }
+                NavTab::About => {
+                    app.toggle_about();
+                }
+                NavTab::History => {
+                    app.add_debug("-> History".to_string());
+                    app.open_history();
+                }
+                NavTab::Bookmarks => {
+                    app.add_debug("-> Bookmarks".to_string());
+                    app.open_bookmarks();
+                }
             }
         }
-        KeyCode::Char('P') => {
-            if app.screen == Screen::Feed && !app.is_loading && app.current_page > 0 {
-                app.prev_page();
-                app.is_loading = true;
-                let offset = app.current_page as i64 * POSTS_LIMIT;
-                load_posts(
-                    api_client,
-                    app.sort_order,
-                    app.time_filter_for_api(),
-                    offset,
-                    app.current_submolt.as_ref().map(|s| s.name.clone()),
-                    tx,
-                );
+        KeyCode::Char('o') => {
+            if let Some(url) = app.selected_item_url() {
+                open_url(&url);
             }
         }
-        KeyCode::Char('1') => {
-            app.add_debug("-> Feed".to_string());
-            app.screen = Screen::Feed;
+        // Yank the selected item's moltbook.com link
+        KeyCode::Char('y') => {
+            if let Some(url) = app.selected_item_url() {
+                match copy_to_clipboard(&url) {
+                    Ok(()) => {
+                        app.add_debug(format!("Copied link {}", url));
+                        app.set_status(format!("Copied {}", url));
+                    }
+                    Err(e) => app.set_status(format!("Failed to copy link: {}", e)),
+                }
+            }
         }
-        KeyCode::Char('2') => {
-            app.add_debug("-> Leaderboard".to_string());
-            app.screen = Screen::Leaderboard;
-            if app.leaderboard.is_empty() {
-                app.is_loading = true;
-                load_leaderboard(api_client, tx);
+        // More from this agent: filter the loaded feed to the selected post's author
+        KeyCode::Char('A') if app.screen == Screen::Feed => {
+            app.toggle_author_filter();
+            match app.author_filter {
+                Some(ref name) => app.add_debug(format!("Author filter: u/{}", name)),
+                None => app.add_debug("Author filter cleared".to_string()),
             }
         }
-        KeyCode::Char('3') => {
-            app.add_debug("-> TopPairings".to_string());
-            app.screen = Screen::TopPairings;
-            if app.top_pairings.is_empty() {
-                app.is_loading = true;
-                load_top_pairings(api_client, tx);
+        // Reverse the loaded page (lowest scored / oldest first)
+        KeyCode::Char('i') if app.screen == Screen::Feed => {
+            app.toggle_sort_reversed();
+            app.add_debug(format!("Sort reversed: {}", app.sort_reversed));
+        }
+        // Search the loaded posts
+        KeyCode::Char('/') if app.screen == Screen::Feed => {
+            app.search_active = true;
+        }
+        // Show where link posts point
+        KeyCode::Char('U') if app.screen == Screen::Feed => {
+            app.show_link_domains = !app.show_link_domains;
+            app.set_status(format!(
+                "Link domains: {}",
+                if app.show_link_domains { "On" } else { "Off" }
+            ));
+        }
+        // Minimum score filter
+        KeyCode::Char(c @ ('[' | ']')) if app.screen == Screen::Feed => {
+            app.adjust_min_score(c == ']');
+            if app.min_score == 0 {
+                app.add_debug("Min score filter: Off".to_string());
+            } else {
+                app.add_debug(format!("Min score filter: {}", app.min_score));
             }
+            load_more_if_filtered_out(app, api_client, tx);
         }
-        KeyCode::Char('4') => {
-            app.add_debug("-> RecentAgents".to_string());
-            app.screen = Screen::RecentAgents;
-            if app.recent_agents.is_empty() {
-                app.is_loading = true;
-                load_recent_agents(api_client, tx);
+        // Minimum comment score filter; replies of hidden comments stay
+        KeyCode::Char(c @ ('[' | ']')) if app.screen == Screen::PostDetail => {
+            app.adjust_min_comment_score(c == ']');
+            if app.min_comment_score == 0 {
+                app.set_status("Comment score filter: Off".to_string());
+            } else {
+                app.set_status(format!("Hiding comments below {}", app.min_comment_score));
+            }
+        }
+        // Copy a link that reproduces the current feed view
+        KeyCode::Char('L') if app.screen == Screen::Feed => {
+            let link = app.feed_deep_link();
+            match copy_to_clipboard(&link) {
+                Ok(()) => app.set_status(format!("Copied {}", link)),
+                Err(e) => app.set_status(format!("Failed to copy link: {}", e)),
             }
         }
-        KeyCode::Char('5') => {
-            app.add_debug("-> Submolts".to_string());
-            app.screen = Screen::Submolts;
-            if app.submolts.is_empty() {
+        // Cross-posts and related discussions of the open post
+        KeyCode::Char('X') if app.screen == Screen::PostDetail => {
+            if let Some(post_id) = app.current_post.as_ref().map(|p| p.id.clone()) {
+                app.show_related = true;
+                app.related_posts.clear();
+                app.related_selected = 0;
                 app.is_loading = true;
-                load_submolts(api_client, tx);
+                load_related_posts(api_client, post_id, tx);
             }
         }
-        KeyCode::Char('6') => {
-            app.add_debug("-> Stats".to_string());
-            app.screen = Screen::Stats;
-            if app.stats.is_none() {
+        // Read the next / previous feed post without going back to the feed
+        KeyCode::Char('J') | KeyCode::Char('K')
+            if app.screen == Screen::PostDetail
+                && app.previous_screen != Some(Screen::AgentProfile)
+                && !app.is_loading =>
+        {
+            let forward = key == KeyCode::Char('J');
+            if app.step_post(forward) {
+                if let Some(post_id) = app.current_post.as_ref().map(|p| p.id.clone()) {
+                    app.is_loading = true;
+                    load_post_with_comments(api_client, post_id, tx);
+                }
+            } else if (forward && app.has_more_posts) || (!forward && app.first_page > 0) {
+                // Past the edge of the page: load the neighbouring one, then open its
+                // first (or last) post
+                if forward {
+                    app.next_page();
+                } else {
+                    app.prev_page();
+                }
+                app.open_selected_on_load = true;
                 app.is_loading = true;
-                load_stats(api_client, tx);
+                load_posts(api_client, app.feed_query(), tx);
+            } else {
+                app.set_status(if forward {
+                    "Last post in the feed".to_string()
+                } else {
+                    "First post in the feed".to_string()
+                });
             }
         }
-        KeyCode::Char('7') => {
-            app.add_debug("-> Settings".to_string());
-            app.screen = Screen::Settings;
+        // Collapse the post block to a summary line to give comments more room
+        KeyCode::Char('p') if app.screen == Screen::PostDetail => {
+            app.toggle_post_collapsed();
+            app.add_debug(format!("Post collapsed: {}", app.post_collapsed));
         }
-        KeyCode::Char('8') => {
-            app.toggle_about();
+        KeyCode::Char('v') if app.screen == Screen::Feed => {
+            app.split_view = !app.split_view;
+            app.set_status(format!(
+                "Split view: {}",
+                if app.split_view { "On" } else { "Off" }
+            ));
         }
-        KeyCode::Char('o') => {
-            let base = "https://www.moltbook.com";
-            match &app.screen {
-                Screen::Feed => {
-                    if let Some(post) = app.posts.get(app.selected_index) {
-                        open_url(&format!("{}/posts/{}", base, post.id));
-                    }
-                }
-                Screen::PostDetail => {
-                    if let Some(post) = &app.current_post {
-                        open_url(&format!("{}/posts/{}", base, post.id));
+        KeyCode::Char('v') if app.screen == Screen::PostDetail => {
+            app.toggle_comment_view();
+            app.set_status(format!("{} comment view", app.comment_view.as_str()));
+        }
+        KeyCode::Char('T') if app.screen == Screen::PostDetail => {
+            if app.toggle_top_level_only() {
+                app.set_status("Showing top-level comments".to_string());
+            } else {
+                app.set_status("Expanded all comments".to_string());
+            }
+        }
+        // Copy the whole comment thread as a text tree
+        KeyCode::Char('C') if app.screen == Screen::PostDetail => {
+            if let Some(post) = &app.current_post {
+                let text = ui::screens::thread_as_text(post, &app.comments);
+                let post_id = post.id.clone();
+                match copy_to_clipboard(&text) {
+                    Ok(()) => app.set_status("Thread copied to clipboard".to_string()),
+                    Err(e) => {
+                        app.add_debug(format!("Clipboard unavailable: {}", e));
+                        let file_name = format!("thread-{}.txt", post_id);
+                        match config::Config::write_data_file(&file_name, &text) {
+                            Ok(path) => {
+                                app.set_status(format!("Thread saved to {}", path.display()))
+                            }
+                            Err(e) => app.set_status(format!("Failed to export thread: {}", e)),
+                        }
                     }
                 }
-                Screen::AgentProfile => {
-                    if let Some(profile) = &app.agent_profile {
-                        open_url(&format!("{}/agent/{}", base, profile.name));
-                    }
+            }
+        }
+        // Permalinks to every comment on screen, one per line
+        KeyCode::Char('L') if app.screen == Screen::PostDetail => {
+            let links = app.visible_comment_permalinks();
+            if links.is_empty() {
+                app.set_status("No comments to link".to_string());
+            } else {
+                match copy_to_clipboard(&links.join("\n")) {
+                    Ok(()) => app.set_status(format!("Copied {} comment links", links.len())),
+                    Err(e) => app.set_status(format!("Failed to copy links: {}", e)),
                 }
-                Screen::Submolts => {
-                    if let Some(submolt) = app.submolts.get(app.submolts_selected) {
-                        open_url(&format!("{}/s/{}", base, submolt.name));
+            }
+        }
+        // Only posts from agents you follow (needs an API key)
+        KeyCode::Char('F') if app.screen == Screen::Feed && !app.is_loading => {
+            if !api_client.has_api_key() {
+                app.set_status(
+                    "The following feed needs an API key: set one under API Key in Settings"
+                        .to_string(),
+                );
+            } else {
+                app.following_only = !app.following_only;
+                app.current_submolt = None;
+                app.current_page = 0;
+                app.selected_index = 0;
+                app.is_loading = true;
+                load_posts(api_client, app.feed_query(), tx);
+            }
+        }
+        // Flag a post for moderators (needs an API key)
+        KeyCode::Char('!') if matches!(app.screen, Screen::Feed | Screen::PostDetail) => {
+            if !api_client.has_api_key() {
+                app.set_status(
+                    "Reporting needs an API key (set MOLTBOOK_API_KEY or api_key in config)"
+                        .to_string(),
+                );
+            } else if let Some(post) = app.reportable_post() {
+                app.report_target = Some((post.id.clone(), post.title.clone()));
+                app.report_reason_selected = 0;
+            }
+        }
+        // Save the loaded page of posts as JSON for archiving
+        KeyCode::Char('w') if app.screen == Screen::Feed => {
+            if app.posts.is_empty() {
+                app.set_status("No posts to export".to_string());
+            } else {
+                let file_name = format!(
+                    "export-{}.json",
+                    chrono::Local::now().format("%Y%m%d-%H%M%S")
+                );
+                let saved = app
+                    .feed_export_json()
+                    .map_err(anyhow::Error::from)
+                    .and_then(|json| config::Config::write_data_file(&file_name, &json));
+                match saved {
+                    Ok(path) => {
+                        app.add_debug(format!(
+                            "Exported {} posts to {}",
+                            app.posts.len(),
+                            path.display()
+                        ));
+                        app.set_status(format!("Posts exported to {}", path.display()));
                     }
-                }
-                Screen::Leaderboard => {
-                    if let Some(agent) = app.leaderboard.get(app.leaderboard_selected) {
-                        open_url(&format!("{}/agent/{}", base, agent.name));
+                    Err(e) => {
+                        let _ = tx.try_send(AppEvent::Error(
+                            api::ErrorKind::Other,
+                            format!("Failed to export posts: {}", e),
+                        ));
                     }
                 }
-                Screen::RecentAgents => {
-                    if let Some(agent) = app.recent_agents.get(app.recent_selected) {
-                        open_url(&format!("{}/agent/{}", base, agent.name));
-                    }
+            }
+        }
+        // Copy the selected item's raw ID, for crafting API calls
+        KeyCode::Char('I') => {
+            if let Some((kind, id)) = app.selected_item_id() {
+                match copy_to_clipboard(&id) {
+                    Ok(()) => app.set_status(format!("Copied {} ID {}", kind, id)),
+                    Err(e) => app.set_status(format!("Failed to copy ID: {}", e)),
                 }
-                _ => {}
             }
         }
         // Refresh interval adjustment
@@ -968,6 +1899,10 @@ fn handle_input(
                 app.add_debug(format!("Refresh interval: {}s", app.refresh_interval_secs));
             }
         }
+        // Temporarily refresh the feed every few seconds
+        KeyCode::Char('B') if app.screen == Screen::Feed => {
+            app.toggle_fast_refresh();
+        }
         KeyCode::Char('a') => {
             if app.refresh_interval_secs == 0 {
                 app.refresh_interval_secs = REFRESH_INTERVAL_SECS;
@@ -977,32 +1912,49 @@ fn handle_input(
                 app.add_debug("Auto-refresh: Off".to_string());
             }
         }
+        // Step through color depths, for terminals that misreport truecolor
+        KeyCode::Char('D') => {
+            app.color_mode = app.color_mode.cycle_next();
+            app.set_status(format!("Colors: {}", app.color_mode.as_str()));
+        }
         // Debug mode toggle (backtick key)
         KeyCode::Char('`') => {
             app.toggle_debug();
         }
+        // Raise the debug panel's minimum level, wrapping back to everything
+        KeyCode::Char('~') if app.debug_mode => {
+            app.cycle_debug_min_level();
+        }
         _ => {}
     }
 }
 
-fn load_posts(
-    api_client: Arc<api::ApiClient>,
-    sort: api::SortOrder,
-    time_filter: Option<TimeFilter>,
-    offset: i64,
-    submolt: Option<String>,
-    tx: mpsc::Sender<AppEvent>,
-) {
+fn load_posts(api_client: Arc<api::ApiClient>, query: FeedQuery, tx: mpsc::Sender<AppEvent>) {
     tokio::spawn(async move {
+        let FeedQuery {
+            sort,
+            time_filter,
+            offset,
+            limit,
+            submolt,
+            following,
+        } = query;
         let submolt_str = submolt.as_deref();
         let _ = tx
-            .send(AppEvent::Debug(format!(
-                "GET /posts?sort={:?}&time={:?}&offset={}&submolt={:?}",
-                sort, time_filter, offset, submolt_str
-            )))
+            .send(AppEvent::Debug(
+                LogLevel::Debug,
+                format!(
+                    "GET /{}?sort={:?}&time={:?}&offset={}&submolt={:?}",
+                    if following { "feed" } else { "posts" },
+                    sort,
+                    time_filter,
+                    offset,
+                    submolt_str
+                ),
+            ))
             .await;
         match api_client
-            .get_posts(sort, time_filter, POSTS_LIMIT, offset, submolt_str)
+            .get_posts(sort, time_filter, limit, offset, submolt_str, following)
             .await
         {
             Ok(response) => {
@@ -1014,26 +1966,39 @@ fn load_posts(
                         .map(|a| a.name.clone())
                         .unwrap_or_else(|| "NONE".to_string());
                     let _ = tx
-                        .send(AppEvent::Debug(format!(
-                            "First post author: {}",
-                            author_info
-                        )))
+                        .send(AppEvent::Debug(
+                            LogLevel::Debug,
+                            format!("First post author: {}", author_info),
+                        ))
                         .await;
                 }
                 let _ = tx
-                    .send(AppEvent::Debug(format!(
-                        "OK: {} posts loaded",
-                        response.posts.len()
-                    )))
+                    .send(AppEvent::Debug(
+                        LogLevel::Info,
+                        format!("OK: {} posts loaded", response.posts.len()),
+                    ))
                     .await;
-                let has_more = response.posts.len() as i64 == POSTS_LIMIT;
+                let has_more = response.posts.len() as i64 == limit;
                 let _ = tx
                     .send(AppEvent::PostsLoaded(response.posts, has_more))
                     .await;
             }
+            // An unknown submolt is shown as an empty feed, not an error
+            Err(e) if submolt.is_some() && api::ErrorKind::of(&e) == api::ErrorKind::NotFound => {
+                let _ = tx
+                    .send(AppEvent::Debug(
+                        LogLevel::Warn,
+                        format!("Submolt m/{} not found", submolt.unwrap_or_default()),
+                    ))
+                    .await;
+                let _ = tx.send(AppEvent::PostsLoaded(Vec::new(), false)).await;
+            }
             Err(e) => {
                 let _ = tx
-                    .send(AppEvent::Error(format!("Failed to load posts: {}", e)))
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to load posts: {}", e),
+                    ))
                     .await;
             }
         }
@@ -1047,21 +2012,27 @@ fn load_post_with_comments(
 ) {
     tokio::spawn(async move {
         let _ = tx
-            .send(AppEvent::Debug(format!("GET /posts/{}", post_id)))
+            .send(AppEvent::Debug(
+                LogLevel::Debug,
+                format!("GET /posts/{}", post_id),
+            ))
             .await;
         match api_client.get_post(&post_id).await {
             Ok(response) => {
                 let _ = tx
-                    .send(AppEvent::Debug(format!(
-                        "OK: {} comments loaded",
-                        response.comments.len()
-                    )))
+                    .send(AppEvent::Debug(
+                        LogLevel::Info,
+                        format!("OK: {} comments loaded", response.comments.len()),
+                    ))
                     .await;
                 let _ = tx.send(AppEvent::CommentsLoaded(response.comments)).await;
             }
             Err(e) => {
                 let _ = tx
-                    .send(AppEvent::Error(format!("Failed to load comments: {}", e)))
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to load comments: {}", e),
+                    ))
                     .await;
             }
         }
@@ -1070,20 +2041,128 @@ fn load_post_with_comments(
 
 fn load_stats(api_client: Arc<api::ApiClient>, tx: mpsc::Sender<AppEvent>) {
     tokio::spawn(async move {
-        let _ = tx.send(AppEvent::Debug("GET /stats".to_string())).await;
+        let _ = tx
+            .send(AppEvent::Debug(LogLevel::Debug, "GET /stats".to_string()))
+            .await;
         match api_client.get_stats().await {
             Ok(stats) => {
                 let _ = tx
-                    .send(AppEvent::Debug(format!(
-                        "OK: stats loaded (agents={}, posts={})",
-                        stats.agents, stats.posts
-                    )))
+                    .send(AppEvent::Debug(
+                        LogLevel::Info,
+                        format!(
+                            "OK: stats loaded (agents={}, posts={})",
+                            stats.agents, stats.posts
+                        ),
+                    ))
                     .await;
                 let _ = tx.send(AppEvent::StatsLoaded(stats)).await;
             }
             Err(e) => {
                 let _ = tx
-                    .send(AppEvent::Error(format!("Failed to load stats: {}", e)))
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to load stats: {}", e),
+                    ))
+                    .await;
+            }
+        }
+    });
+}
+
+/// Report a post to the moderators for `reason`
+fn report_post(
+    api_client: Arc<api::ApiClient>,
+    post_id: String,
+    title: String,
+    reason: String,
+    tx: mpsc::Sender<AppEvent>,
+) {
+    tokio::spawn(async move {
+        let _ = tx
+            .send(AppEvent::Debug(
+                LogLevel::Debug,
+                format!("POST /posts/{}/report ({})", post_id, reason),
+            ))
+            .await;
+        match api_client.report_post(&post_id, &reason).await {
+            Ok(()) => {
+                let _ = tx.send(AppEvent::PostReported(title)).await;
+            }
+            Err(e) => {
+                let _ = tx
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to report post: {}", e),
+                    ))
+                    .await;
+            }
+        }
+    });
+}
+
+/// Fetch posts related to the open one for the related posts picker
+fn load_related_posts(
+    api_client: Arc<api::ApiClient>,
+    post_id: String,
+    tx: mpsc::Sender<AppEvent>,
+) {
+    tokio::spawn(async move {
+        let _ = tx
+            .send(AppEvent::Debug(
+                LogLevel::Debug,
+                format!("GET /posts/{}/related", post_id),
+            ))
+            .await;
+        match api_client.get_related_posts(&post_id).await {
+            Ok(posts) => {
+                let _ = tx
+                    .send(AppEvent::Debug(
+                        LogLevel::Info,
+                        format!("OK: {} related posts", posts.len()),
+                    ))
+                    .await;
+                let _ = tx.send(AppEvent::RelatedPostsLoaded(posts)).await;
+            }
+            Err(e) => {
+                let _ = tx
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to load related posts: {}", e),
+                    ))
+                    .await;
+            }
+        }
+    });
+}
+
+/// Fetch the announcement banner. Failures are only logged, since not every
+/// backend serves one
+fn load_announcement(api_client: Arc<api::ApiClient>, tx: mpsc::Sender<AppEvent>) {
+    tokio::spawn(async move {
+        let _ = tx
+            .send(AppEvent::Debug(
+                LogLevel::Debug,
+                "GET /announcement".to_string(),
+            ))
+            .await;
+        match api_client.get_announcement().await {
+            Ok(Some(message)) => {
+                let _ = tx.send(AppEvent::AnnouncementLoaded(message)).await;
+            }
+            Ok(None) => {
+                let _ = tx
+                    .send(AppEvent::Debug(
+                        LogLevel::Info,
+                        "OK: no announcement".to_string(),
+                    ))
+                    .await;
+            }
+            Err(e) => {
+                let _ = tx
+                    .send(AppEvent::Debug(
+                        LogLevel::Warn,
+                        format!("Announcement unavailable: {}", e),
+                    ))
                     .await;
             }
         }
@@ -1093,24 +2172,27 @@ fn load_stats(api_client: Arc<api::ApiClient>, tx: mpsc::Sender<AppEvent>) {
 fn load_leaderboard(api_client: Arc<api::ApiClient>, tx: mpsc::Sender<AppEvent>) {
     tokio::spawn(async move {
         let _ = tx
-            .send(AppEvent::Debug("GET /agents/leaderboard".to_string()))
+            .send(AppEvent::Debug(
+                LogLevel::Debug,
+                "GET /agents/leaderboard".to_string(),
+            ))
             .await;
         match api_client.get_leaderboard().await {
             Ok(leaderboard) => {
                 let _ = tx
-                    .send(AppEvent::Debug(format!(
-                        "OK: {} agents in leaderboard",
-                        leaderboard.len()
-                    )))
+                    .send(AppEvent::Debug(
+                        LogLevel::Info,
+                        format!("OK: {} agents in leaderboard", leaderboard.len()),
+                    ))
                     .await;
                 let _ = tx.send(AppEvent::LeaderboardLoaded(leaderboard)).await;
             }
             Err(e) => {
                 let _ = tx
-                    .send(AppEvent::Error(format!(
-                        "Failed to load leaderboard: {}",
-                        e
-                    )))
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to load leaderboard: {}", e),
+                    ))
                     .await;
             }
         }
@@ -1120,24 +2202,27 @@ fn load_leaderboard(api_client: Arc<api::ApiClient>, tx: mpsc::Sender<AppEvent>)
 fn load_top_pairings(api_client: Arc<api::ApiClient>, tx: mpsc::Sender<AppEvent>) {
     tokio::spawn(async move {
         let _ = tx
-            .send(AppEvent::Debug("GET /api/homepage".to_string()))
+            .send(AppEvent::Debug(
+                LogLevel::Debug,
+                "GET /api/homepage".to_string(),
+            ))
             .await;
         match api_client.get_top_humans().await {
             Ok(top_humans) => {
                 let _ = tx
-                    .send(AppEvent::Debug(format!(
-                        "OK: {} top humans loaded",
-                        top_humans.len()
-                    )))
+                    .send(AppEvent::Debug(
+                        LogLevel::Info,
+                        format!("OK: {} top humans loaded", top_humans.len()),
+                    ))
                     .await;
                 let _ = tx.send(AppEvent::TopPairingsLoaded(top_humans)).await;
             }
             Err(e) => {
                 let _ = tx
-                    .send(AppEvent::Error(format!(
-                        "Failed to load top pairings: {}",
-                        e
-                    )))
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to load top pairings: {}", e),
+                    ))
                     .await;
             }
         }
@@ -1147,24 +2232,27 @@ fn load_top_pairings(api_client: Arc<api::ApiClient>, tx: mpsc::Sender<AppEvent>
 fn load_recent_agents(api_client: Arc<api::ApiClient>, tx: mpsc::Sender<AppEvent>) {
     tokio::spawn(async move {
         let _ = tx
-            .send(AppEvent::Debug("GET /agents/recent".to_string()))
+            .send(AppEvent::Debug(
+                LogLevel::Debug,
+                "GET /agents/recent".to_string(),
+            ))
             .await;
         match api_client.get_recent_agents().await {
             Ok(agents) => {
                 let _ = tx
-                    .send(AppEvent::Debug(format!(
-                        "OK: {} recent agents loaded",
-                        agents.len()
-                    )))
+                    .send(AppEvent::Debug(
+                        LogLevel::Info,
+                        format!("OK: {} recent agents loaded", agents.len()),
+                    ))
                     .await;
                 let _ = tx.send(AppEvent::RecentAgentsLoaded(agents)).await;
             }
             Err(e) => {
                 let _ = tx
-                    .send(AppEvent::Error(format!(
-                        "Failed to load recent agents: {}",
-                        e
-                    )))
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to load recent agents: {}", e),
+                    ))
                     .await;
             }
         }
@@ -1173,120 +2261,307 @@ fn load_recent_agents(api_client: Arc<api::ApiClient>, tx: mpsc::Sender<AppEvent
 
 fn load_submolts(api_client: Arc<api::ApiClient>, tx: mpsc::Sender<AppEvent>) {
     tokio::spawn(async move {
-        let _ = tx.send(AppEvent::Debug("GET /submolts".to_string())).await;
+        let _ = tx
+            .send(AppEvent::Debug(
+                LogLevel::Debug,
+                "GET /submolts".to_string(),
+            ))
+            .await;
         match api_client.get_submolts().await {
             Ok(submolts) => {
                 let _ = tx
-                    .send(AppEvent::Debug(format!(
-                        "OK: {} submolts loaded",
-                        submolts.len()
-                    )))
+                    .send(AppEvent::Debug(
+                        LogLevel::Info,
+                        format!("OK: {} submolts loaded", submolts.len()),
+                    ))
                     .await;
                 let _ = tx.send(AppEvent::SubmoltsLoaded(submolts)).await;
             }
             Err(e) => {
                 let _ = tx
-                    .send(AppEvent::Error(format!("Failed to load submolts: {}", e)))
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to load submolts: {}", e),
+                    ))
                     .await;
             }
         }
     });
 }
 
+/// Show an agent's profile, reusing one fetched in the last minute
 fn load_agent_profile(api_client: Arc<api::ApiClient>, name: String, tx: mpsc::Sender<AppEvent>) {
+    if let Some(response) = api_client.cached_agent_profile(&name) {
+        if tx.try_send(AppEvent::AgentProfileLoaded(response)).is_ok() {
+            return;
+        }
+    }
+    fetch_agent_profile(api_client, name, tx);
+}
+
+/// Request an agent's profile, bypassing (and then updating) the cache
+fn fetch_agent_profile(api_client: Arc<api::ApiClient>, name: String, tx: mpsc::Sender<AppEvent>) {
     tokio::spawn(async move {
         let _ = tx
-            .send(AppEvent::Debug(format!(
-                "GET /agents/profile?name={}",
-                name
-            )))
+            .send(AppEvent::Debug(
+                LogLevel::Debug,
+                format!("GET /agents/profile?name={}", name),
+            ))
             .await;
         match api_client.get_agent_profile(&name).await {
             Ok(response) => {
                 let _ = tx
-                    .send(AppEvent::Debug(format!(
-                        "OK: agent profile loaded with {} posts",
-                        response.recent_posts.len()
-                    )))
+                    .send(AppEvent::Debug(
+                        LogLevel::Info,
+                        format!(
+                            "OK: agent profile loaded with {} posts",
+                            response.recent_posts.len()
+                        ),
+                    ))
                     .await;
                 let _ = tx.send(AppEvent::AgentProfileLoaded(response)).await;
             }
             Err(e) => {
                 let _ = tx
-                    .send(AppEvent::Error(format!(
-                        "Failed to load agent profile: {}",
-                        e
-                    )))
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to load agent profile: {}", e),
+                    ))
                     .await;
             }
         }
     });
 }
 
+/// Download the preview agent's avatar unless it's cached (or on its way).
+/// Skipped when avatars are off or the terminal only has 16 colors
+fn load_preview_avatar(
+    app: &mut App,
+    api_client: Arc<api::ApiClient>,
+    tx: mpsc::Sender<AppEvent>,
+) {
+    if !app.avatars || app.color_mode == config::ColorMode::Basic16 {
+        return;
+    }
+    let Some(url) = app.preview_avatar_url() else {
+        return;
+    };
+    if app.avatar_cache.contains_key(&url) {
+        return;
+    }
+    app.avatar_cache.insert(url.clone(), None);
+    tokio::spawn(async move {
+        let avatar = match api_client.get_avatar(&url).await {
+            Ok(bytes) => tokio::task::spawn_blocking(move || avatar::Avatar::decode(&bytes))
+                .await
+                .unwrap_or_else(|e| Err(e.into())),
+            Err(e) => Err(e),
+        };
+        let avatar = match avatar {
+            Ok(avatar) => Some(avatar),
+            Err(e) => {
+                let _ = tx
+                    .send(AppEvent::Debug(
+                        LogLevel::Warn,
+                        format!("Avatar {} unavailable: {}", url, e),
+                    ))
+                    .await;
+                None
+            }
+        };
+        let _ = tx.send(AppEvent::AvatarLoaded(url, avatar)).await;
+    });
+}
+
 fn load_agent_preview(api_client: Arc<api::ApiClient>, name: String, tx: mpsc::Sender<AppEvent>) {
+    if let Some(response) = api_client.cached_agent_profile(&name) {
+        if tx
+            .try_send(AppEvent::AgentPreviewLoaded(name.clone(), response))
+            .is_ok()
+        {
+            return;
+        }
+    }
     tokio::spawn(async move {
         let _ = tx
-            .send(AppEvent::Debug(format!(
-                "GET /agents/profile?name={} (preview)",
-                name
-            )))
+            .send(AppEvent::Debug(
+                LogLevel::Debug,
+                format!("GET /agents/profile?name={} (preview)", name),
+            ))
             .await;
         match api_client.get_agent_profile(&name).await {
             Ok(response) => {
                 let _ = tx
-                    .send(AppEvent::Debug(format!(
-                        "OK: agent preview loaded with {} posts",
-                        response.recent_posts.len()
-                    )))
+                    .send(AppEvent::Debug(
+                        LogLevel::Info,
+                        format!(
+                            "OK: agent preview loaded with {} posts",
+                            response.recent_posts.len()
+                        ),
+                    ))
                     .await;
-                let _ = tx.send(AppEvent::AgentPreviewLoaded(response)).await;
+                let _ = tx.send(AppEvent::AgentPreviewLoaded(name, response)).await;
             }
             Err(e) => {
                 let _ = tx
-                    .send(AppEvent::Error(format!(
-                        "Failed to load agent profile: {}",
-                        e
-                    )))
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to load agent profile: {}", e),
+                    ))
                     .await;
             }
         }
     });
 }
 
-fn update_agent_preview_for_current_selection(
+/// Number of posts shown in the submolt feed preview sidebar
+const SUBMOLT_PREVIEW_LIMIT: i64 = 10;
+
+fn load_submolt_preview(api_client: Arc<api::ApiClient>, name: String, tx: mpsc::Sender<AppEvent>) {
+    tokio::spawn(async move {
+        let _ = tx
+            .send(AppEvent::Debug(
+                LogLevel::Debug,
+                format!("GET /posts?submolt={} (preview)", name),
+            ))
+            .await;
+        match api_client
+            .get_posts(
+                api::SortOrder::New,
+                None,
+                SUBMOLT_PREVIEW_LIMIT,
+                0,
+                Some(&name),
+                false,
+            )
+            .await
+        {
+            Ok(response) => {
+                let _ = tx
+                    .send(AppEvent::Debug(
+                        LogLevel::Info,
+                        format!(
+                            "OK: submolt preview loaded with {} posts",
+                            response.posts.len()
+                        ),
+                    ))
+                    .await;
+                let _ = tx
+                    .send(AppEvent::SubmoltPreviewLoaded(name, response.posts))
+                    .await;
+            }
+            Err(e) => {
+                let _ = tx
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to load submolt preview: {}", e),
+                    ))
+                    .await;
+            }
+        }
+    });
+}
+
+fn update_submolt_preview_for_current_selection(
     app: &mut App,
     api_client: Arc<api::ApiClient>,
     tx: mpsc::Sender<AppEvent>,
 ) {
-    match app.screen {
-        Screen::Leaderboard => {
-            if !app.leaderboard.is_empty() {
-                let agent = &app.leaderboard[app.leaderboard_selected];
-                app.preview_agent_name = Some(agent.name.clone());
-                app.agent_profile = None; // Clear old profile
-                app.is_preview_loading = true;
-                load_agent_preview(api_client, agent.name.clone(), tx);
-            }
-        }
-        Screen::TopPairings => {
-            if !app.top_pairings.is_empty() {
-                let human = &app.top_pairings[app.top_pairings_selected];
-                app.preview_agent_name = Some(human.bot_name.clone());
-                app.agent_profile = None;
-                app.is_preview_loading = true;
-                load_agent_preview(api_client, human.bot_name.clone(), tx);
-            }
-        }
-        Screen::RecentAgents => {
-            if !app.recent_agents.is_empty() {
-                let agent = &app.recent_agents[app.recent_selected];
-                app.preview_agent_name = Some(agent.name.clone());
-                app.agent_profile = None;
-                app.is_preview_loading = true;
-                load_agent_preview(api_client, agent.name.clone(), tx);
-            }
-        }
-        _ => {}
+    let Some(submolt) = app.submolts.get(app.submolts_selected) else {
+        return;
+    };
+    // Already showing (or loading) this submolt
+    if app.submolt_preview_name.as_deref() == Some(submolt.name.as_str()) {
+        return;
+    }
+    let name = submolt.name.clone();
+    app.submolt_preview_name = Some(name.clone());
+    app.submolt_preview_posts.clear();
+    app.is_loading = true;
+    app.is_preview_loading = true;
+    load_submolt_preview(api_client, name, tx);
+}
+
+/// Fewest posts the score filter may leave visible before the next page is
+/// added, and how many pages may be added that way
+const FILTERED_MIN_VISIBLE: usize = 5;
+const FILTERED_MAX_PAGES: usize = 5;
+
+/// Add the next page to this one when the score filter hides most of its posts
+fn load_more_if_filtered_out(
+    app: &mut App,
+    api_client: Arc<api::ApiClient>,
+    tx: mpsc::Sender<AppEvent>,
+) {
+    // Not for the author filter or a search: one agent's posts (or the
+    // matches) may be many pages apart
+    if app.screen == Screen::Feed
+        && app.author_filter.is_none()
+        && app.search_query.is_empty()
+        && !app.posts.is_empty()
+        && app.visible_posts().len() < FILTERED_MIN_VISIBLE
+        && app.added_pages + 1 < FILTERED_MAX_PAGES
+        && app.has_more_posts
+        && !app.is_loading
+    {
+        app.add_debug("Few posts on this page pass the filter, adding the next page".to_string());
+        // current_page moves on once the page arrives, so a failed load
+        // leaves the bookkeeping as it was
+        let mut query = app.feed_query();
+        query.offset += app.posts_limit;
+        app.append_on_load = true;
+        app.is_loading = true;
+        app.is_background_loading = true;
+        load_posts(api_client, query, tx);
+    }
+}
+
+/// Show the newly selected agent in the preview sidebar. The profile is
+/// fetched once the selection has stayed put for `AGENT_PREVIEW_DEBOUNCE`,
+/// so holding j/k doesn't send a request per row
+fn update_agent_preview_for_current_selection(app: &mut App, tx: mpsc::Sender<AppEvent>) {
+    let name = match app.screen {
+        Screen::Feed => app.selected_post_author(),
+        Screen::Leaderboard => app.selected_leaderboard_agent().map(|a| a.name.clone()),
+        Screen::TopPairings => app
+            .top_pairings
+            .get(app.top_pairings_selected)
+            .map(|human| human.bot_name.clone()),
+        Screen::RecentAgents => app.selected_recent_agent().map(|a| a.name.clone()),
+        _ => None,
+    };
+    let Some(name) = name else {
+        return;
+    };
+    app.preview_agent_name = Some(name.clone());
+    app.agent_profile = None; // Clear old profile
+    app.is_preview_loading = true;
+    app.preview_requested_at = Some(std::time::Instant::now());
+    tokio::spawn(async move {
+        tokio::time::sleep(AGENT_PREVIEW_DEBOUNCE).await;
+        let _ = tx.send(AppEvent::AgentPreviewDue(name)).await;
+    });
+}
+
+/// Pin or unpin the selected submolt and save the pinned list
+fn toggle_pinned_submolt(app: &mut App) {
+    let Some((name, pinned)) = app.toggle_pin_selected_submolt() else {
+        return;
+    };
+    if let Err(e) = config::Config::save_pinned_submolts(&app.pinned_submolts) {
+        app.add_debug_with_level(
+            LogLevel::Error,
+            format!("Failed to save pinned submolts: {}", e),
+        );
+    }
+    if pinned {
+        app.set_status(format!(
+            "Pinned m/{} ('{} on the feed)",
+            name,
+            app.pinned_submolts.len()
+        ));
+    } else {
+        app.set_status(format!("Unpinned m/{}", name));
     }
 }
 
@@ -1304,7 +2579,10 @@ fn handle_settings_change(app: &mut App, forward: bool) {
             if let Err(e) =
                 config::Config::save_settings(app.row_display, app.refresh_interval_secs)
             {
-                app.add_debug(format!("Failed to save settings: {}", e));
+                app.add_debug_with_level(
+                    LogLevel::Error,
+                    format!("Failed to save settings: {}", e),
+                );
             }
         }
         1 => {
@@ -1331,7 +2609,10 @@ fn handle_settings_change(app: &mut App, forward: bool) {
             if let Err(e) =
                 config::Config::save_settings(app.row_display, app.refresh_interval_secs)
             {
-                app.add_debug(format!("Failed to save settings: {}", e));
+                app.add_debug_with_level(
+                    LogLevel::Error,
+                    format!("Failed to save settings: {}", e),
+                );
             }
         }
         _ => {}
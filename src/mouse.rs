@@ -1,9 +1,13 @@
 //! Mouse click handling for the TUI
 
-use crate::api::{ApiClient, SortOrder, TimeFilter};
+use crate::api::{self, ApiClient, SortOrder};
 use crate::app::{App, Screen};
-use crate::config::RowDisplay;
+use crate::config::{NavTab, RowDisplay};
+use crate::ui::fonts::figlet_available;
+use crate::ui::header::NavAction;
+use crate::ui::screens::{feed_panes, feed_row_height};
 use crate::AppEvent;
+use ratatui::layout::Rect;
 use std::sync::Arc;
 use tokio::sync::mpsc;
 
@@ -33,17 +37,24 @@ pub fn handle_mouse_click(
         return;
     }
 
-    let (width, height) = app.last_frame_area.unwrap_or((80, 24));
+    // Any click leaves focus mode
+    if app.focus_mode {
+        app.toggle_focus_mode();
+        return;
+    }
+
+    // With the header hidden, a click on the top line brings it (and its nav tabs) back
+    if app.reading_mode && y == 0 {
+        app.reading_mode = false;
+        return;
+    }
 
-    // Check nav tabs first (in header area)
-    // Header is typically 11-13 lines depending on screen
-    let header_height = get_header_height(app);
+    let (width, height) = app.last_frame_area.unwrap_or((80, 24));
 
-    if y > 0 && y < header_height {
-        if let Some(action) = get_nav_tab_at_position(x, y, &app.screen) {
-            handle_nav_action(app, action, api_client, tx);
-            return;
-        }
+    // Check nav tabs first; their hitboxes carry the row they were drawn on
+    if let Some(action) = get_nav_tab_at_position(x, y, app) {
+        handle_nav_action(app, action, api_client, tx);
+        return;
     }
 
     // Screen-specific handling
@@ -59,105 +70,11 @@ pub fn handle_mouse_click(
     }
 }
 
-/// Get header height based on current screen
-fn get_header_height(app: &App) -> u16 {
-    match app.screen {
-        Screen::Feed => 13, // Feed has sort tabs, making header taller
-        Screen::PostDetail => 3, // PostDetail has minimal header
-        _ => 11, // Most screens use shared header (11 lines)
-    }
-}
-
-/// Navigation action from clicking tabs
-#[derive(Debug, Clone, Copy)]
-enum NavAction {
-    Screen(u8), // 1-8 for screen numbers
-    Sort(SortOrder),
-    TimeFilter(TimeFilter),
-    Shuffle,
-}
-
-/// Detect which nav tab was clicked based on x position
-/// Returns the screen number (1-8) if a tab was clicked
-fn get_nav_tab_at_position(x: u16, y: u16, current_screen: &Screen) -> Option<NavAction> {
-    // Nav tabs are on line 9 (0-indexed) in the shared header
-    // For Feed screen, sort tabs are on line 11-12
-
-    // Shared header nav tabs line is at y=9 (inside the header box)
-    // The actual y position depends on the border, so line 9 corresponds to y=9
-
-    // Nav tabs format: " [1] Feed  [2] Leaderboard  [3] Top Pairings  [4] Agents  [5] Submolts  [6] Stats  [7] Settings  [8] About "
-    // Approximate x positions for each tab (accounting for the format " [X] Label  "):
-    // [1] Feed:        x = 1-11
-    // [2] Leaderboard: x = 13-30
-    // [3] Top Pairings: x = 32-51
-    // [4] Agents:      x = 53-66
-    // [5] Submolts:    x = 68-83
-    // [6] Stats:       x = 85-97
-    // [7] Settings:    x = 99-115
-    // [8] About:       x = 117+
-
-    // The nav tabs line is at y=9 within the header (0-indexed from top of terminal)
-    if y == 9 {
-        // Check each tab range
-        if (1..=11).contains(&x) {
-            return Some(NavAction::Screen(1));
-        } else if (13..=30).contains(&x) {
-            return Some(NavAction::Screen(2));
-        } else if (32..=51).contains(&x) {
-            return Some(NavAction::Screen(3));
-        } else if (53..=66).contains(&x) {
-            return Some(NavAction::Screen(4));
-        } else if (68..=83).contains(&x) {
-            return Some(NavAction::Screen(5));
-        } else if (85..=97).contains(&x) {
-            return Some(NavAction::Screen(6));
-        } else if (99..=115).contains(&x) {
-            return Some(NavAction::Screen(7));
-        } else if x >= 117 {
-            return Some(NavAction::Screen(8));
-        }
-    }
-
-    // For Feed screen, check sort tabs on line 11
-    if *current_screen == Screen::Feed && y == 11 {
-        // Sort tabs format: " [N]ew    [T]op    [D]iscussed    [R]andom | [s]huffle  Hour  Day  Week  Month  Year  All  [f] cycle"
-        // Approximate positions:
-        // [N]ew:       x = 1-8
-        // [T]op:       x = 13-19
-        // [D]iscussed: x = 24-36
-        // [R]andom:    x = 41-52
-        // [s]huffle:   x = 56-68
-        // Time filters start around x = 70+
-
-        if (1..=8).contains(&x) {
-            return Some(NavAction::Sort(SortOrder::New));
-        } else if (13..=19).contains(&x) {
-            return Some(NavAction::Sort(SortOrder::Top));
-        } else if (24..=36).contains(&x) {
-            return Some(NavAction::Sort(SortOrder::Discussed));
-        } else if (41..=52).contains(&x) {
-            return Some(NavAction::Sort(SortOrder::Random));
-        } else if (56..=68).contains(&x) {
-            return Some(NavAction::Shuffle);
-        }
-        // Time filter pills (only shown when not sorting by New)
-        else if (72..=78).contains(&x) {
-            return Some(NavAction::TimeFilter(TimeFilter::Hour));
-        } else if (82..=87).contains(&x) {
-            return Some(NavAction::TimeFilter(TimeFilter::Day));
-        } else if (91..=97).contains(&x) {
-            return Some(NavAction::TimeFilter(TimeFilter::Week));
-        } else if (101..=108).contains(&x) {
-            return Some(NavAction::TimeFilter(TimeFilter::Month));
-        } else if (112..=118).contains(&x) {
-            return Some(NavAction::TimeFilter(TimeFilter::Year));
-        } else if (122..=127).contains(&x) {
-            return Some(NavAction::TimeFilter(TimeFilter::All));
-        }
-    }
-
-    None
+/// Detect which header tab was clicked, using the hitboxes from the last draw
+fn get_nav_tab_at_position(x: u16, y: u16, app: &App) -> Option<NavAction> {
+    app.nav_tab_line
+        .action_at(x, y)
+        .or_else(|| app.sort_tab_line.action_at(x, y))
 }
 
 /// Handle navigation action (screen switch, sort change, etc.)
@@ -168,8 +85,8 @@ fn handle_nav_action(
     tx: mpsc::Sender<AppEvent>,
 ) {
     match action {
-        NavAction::Screen(num) => {
-            handle_screen_switch(app, num, api_client, tx);
+        NavAction::Screen(tab) => {
+            handle_screen_switch(app, tab, api_client, tx);
         }
         NavAction::Sort(order) => {
             if app.screen == Screen::Feed && !app.is_loading {
@@ -198,19 +115,19 @@ fn handle_nav_action(
     }
 }
 
-/// Switch to screen based on number key (1-8)
+/// Switch to the screen behind a clicked nav tab
 fn handle_screen_switch(
     app: &mut App,
-    screen_num: u8,
+    tab: NavTab,
     api_client: Arc<ApiClient>,
     tx: mpsc::Sender<AppEvent>,
 ) {
-    match screen_num {
-        1 => {
+    match tab {
+        NavTab::Feed => {
             app.add_debug("-> Feed (click)".to_string());
             app.screen = Screen::Feed;
         }
-        2 => {
+        NavTab::Leaderboard => {
             app.add_debug("-> Leaderboard (click)".to_string());
             app.screen = Screen::Leaderboard;
             if app.leaderboard.is_empty() {
@@ -218,7 +135,7 @@ fn handle_screen_switch(
                 load_leaderboard(api_client, tx);
             }
         }
-        3 => {
+        NavTab::TopPairings => {
             app.add_debug("-> TopPairings (click)".to_string());
             app.screen = Screen::TopPairings;
             if app.top_pairings.is_empty() {
@@ -226,7 +143,7 @@ fn handle_screen_switch(
                 load_top_pairings(api_client, tx);
             }
         }
-        4 => {
+        NavTab::Agents => {
             app.add_debug("-> RecentAgents (click)".to_string());
             app.screen = Screen::RecentAgents;
             if app.recent_agents.is_empty() {
@@ -234,7 +151,7 @@ fn handle_screen_switch(
                 load_recent_agents(api_client, tx);
             }
         }
-        5 => {
+        NavTab::Submolts => {
             app.add_debug("-> Submolts (click)".to_string());
             app.screen = Screen::Submolts;
             if app.submolts.is_empty() {
@@ -242,7 +159,7 @@ fn handle_screen_switch(
                 load_submolts(api_client, tx);
             }
         }
-        6 => {
+        NavTab::Stats => {
             app.add_debug("-> Stats (click)".to_string());
             app.screen = Screen::Stats;
             if app.stats.is_none() {
@@ -250,50 +167,58 @@ fn handle_screen_switch(
                 load_stats(api_client, tx);
             }
         }
-        7 => {
+        NavTab::Settings => {
             app.add_debug("-> Settings (click)".to_string());
             app.screen = Screen::Settings;
         }
-        8 => {
+        NavTab::About => {
             app.toggle_about();
         }
-        _ => {}
+        NavTab::History => {
+            app.add_debug("-> History (click)".to_string());
+            app.open_history();
+        }
+        NavTab::Bookmarks => {
+            app.add_debug("-> Bookmarks (click)".to_string());
+            app.open_bookmarks();
+        }
     }
 }
 
 /// Handle clicks in the Feed screen
 fn handle_feed_click(
     app: &mut App,
-    _x: u16,
+    x: u16,
     y: u16,
-    _width: u16,
+    width: u16,
     height: u16,
     _api_client: Arc<ApiClient>,
     _tx: mpsc::Sender<AppEvent>,
 ) {
     // Feed layout:
     // - Header: 13 lines (including sort tabs)
-    // - Posts list: from y=13 to y=height-3
+    // - Announcement banner: 3 lines, only while one is shown
+    // - Posts list: below the banner to y=height-3, left half in split view
     // - Footer: 3 lines
 
-    let header_height = 13u16;
+    let header_height = 13u16 + app.announcement_banner_height();
     let footer_height = 3u16;
+    let (list_area, _) = feed_panes(app, Rect::new(0, 0, width, height));
+    let width = list_area.width;
 
     // Check if click is in posts area
-    if y > header_height && y < height.saturating_sub(footer_height) {
+    if x < width && y > header_height && y < height.saturating_sub(footer_height) {
         // Calculate which post was clicked
         let relative_y = y - header_height - 1; // -1 for border
 
-        // Item height depends on row_display
-        let item_height = match app.row_display {
-            RowDisplay::Compact => 2u16,
-            RowDisplay::Normal => 2u16,
-            RowDisplay::Comfortable => 3u16,
-        };
-
-        let clicked_index = (relative_y / item_height) as usize;
+        // Rows are taller for titles that wrap onto a second line
+        let mut accumulated_height = 0u16;
+        let clicked_index = app.visible_posts().iter().position(|post| {
+            accumulated_height += feed_row_height(app, post, width);
+            relative_y < accumulated_height
+        });
 
-        if clicked_index < app.posts.len() {
+        if let Some(clicked_index) = clicked_index {
             app.selected_index = clicked_index;
             app.add_debug(format!("Selected post {}", clicked_index));
         }
@@ -314,14 +239,25 @@ fn handle_leaderboard_click(app: &mut App, _x: u16, y: u16, height: u16) {
         let relative_y = y - header_height - 1;
 
         // Leaderboard items have variable height:
-        // - Top 3: ~7 lines each (figlet art)
+        // - Top 3: 8 lines each (padding, 4 lines of figlet art, handle, karma, blank)
         // - Others: 4 lines each
+        // Dense spacing drops the padding and blank lines
 
         // This is an approximation - we'll use scroll position tracking
         // For simplicity, estimate based on position
         let mut accumulated_height = 0u16;
-        for (i, agent) in app.leaderboard.iter().enumerate() {
-            let item_height = if agent.rank <= 3 { 7u16 } else { 4u16 };
+        let ranks: Vec<u32> = app
+            .visible_leaderboard()
+            .iter()
+            .enumerate()
+            .map(|(i, a)| app.leaderboard_display_rank(i, a))
+            .collect();
+        for (i, rank) in ranks.into_iter().enumerate() {
+            let item_height = if rank <= 3 && figlet_available() {
+                6 + 2 * app.spacer_height()
+            } else {
+                3 + app.spacer_height()
+            };
 
             if relative_y >= accumulated_height && relative_y < accumulated_height + item_height {
                 app.leaderboard_selected = i;
@@ -347,7 +283,7 @@ fn handle_top_pairings_click(app: &mut App, _x: u16, y: u16, height: u16) {
         let relative_y = y - header_height - 1;
 
         // Each pairing is 4 lines (handle, name, agent info, blank)
-        let item_height = 4u16;
+        let item_height = 3 + app.spacer_height();
         let clicked_index = (relative_y / item_height) as usize;
 
         if clicked_index < app.top_pairings.len() {
@@ -372,14 +308,14 @@ fn handle_recent_agents_click(app: &mut App, _x: u16, y: u16, height: u16) {
 
         // Item height depends on row_display
         let item_height = match app.row_display {
-            RowDisplay::Compact => 3u16,
-            RowDisplay::Normal => 4u16,
-            RowDisplay::Comfortable => 4u16,
-        };
+            RowDisplay::Dense | RowDisplay::Compact => 2u16,
+            RowDisplay::Normal => 3u16,
+            RowDisplay::Comfortable => 3u16,
+        } + app.spacer_height();
 
         let clicked_index = (relative_y / item_height) as usize;
 
-        if clicked_index < app.recent_agents.len() {
+        if clicked_index < app.visible_recent_agents().len() {
             app.recent_selected = clicked_index;
             app.add_debug(format!("Selected recent agent {}", clicked_index));
         }
@@ -401,7 +337,7 @@ fn handle_submolts_click(app: &mut App, x: u16, y: u16, width: u16, height: u16)
 
         // Row height depends on row_display
         let row_height = match app.row_display {
-            RowDisplay::Compact => 4u16,
+            RowDisplay::Dense | RowDisplay::Compact => 4u16,
             RowDisplay::Normal => 6u16,
             RowDisplay::Comfortable => 7u16,
         };
@@ -430,12 +366,12 @@ fn handle_submolts_click(app: &mut App, x: u16, y: u16, width: u16, height: u16)
 /// Handle clicks in the Post Detail screen
 fn handle_post_detail_click(app: &mut App, _x: u16, y: u16, height: u16) {
     // Post Detail layout:
-    // - Header: 3 lines
-    // - Post content: 15 lines
-    // - Comments: from y=18 to y=height-3
+    // - Header: 3 lines (hidden in reading mode)
+    // - Post content: 15 lines (3 when collapsed)
+    // - Comments: from below the post block to y=height-3
     // - Footer: 3 lines
 
-    let comments_start = 18u16;
+    let comments_start = app.visible_header_height(3) + app.post_block_height();
     let footer_height = 3u16;
 
     if y > comments_start && y < height.saturating_sub(footer_height) {
@@ -468,8 +404,8 @@ fn handle_settings_click(app: &mut App, y: u16, _height: u16) {
         let item_height = 2u16;
         let clicked_index = (relative_y / item_height) as usize;
 
-        // Only 2 settings currently
-        if clicked_index <= 1 {
+        // Only 3 settings currently
+        if clicked_index <= 2 {
             app.settings_selected = clicked_index;
             app.add_debug(format!("Selected setting {}", clicked_index));
         }
@@ -478,29 +414,33 @@ fn handle_settings_click(app: &mut App, y: u16, _height: u16) {
 
 // Helper functions to load data (mirrors main.rs functions)
 
-const POSTS_LIMIT: i64 = 25;
-
 fn load_posts(app: &App, api_client: Arc<ApiClient>, tx: mpsc::Sender<AppEvent>) {
-    let sort = app.sort_order;
-    let time_filter = app.time_filter_for_api();
-    let offset = app.current_page as i64 * POSTS_LIMIT;
-    let submolt = app.current_submolt.as_ref().map(|s| s.name.clone());
+    let query = app.feed_query();
 
     tokio::spawn(async move {
-        let submolt_str = submolt.as_deref();
         match api_client
-            .get_posts(sort, time_filter, POSTS_LIMIT, offset, submolt_str)
+            .get_posts(
+                query.sort,
+                query.time_filter,
+                query.limit,
+                query.offset,
+                query.submolt.as_deref(),
+                query.following,
+            )
             .await
         {
             Ok(response) => {
-                let has_more = response.posts.len() as i64 == POSTS_LIMIT;
+                let has_more = response.posts.len() as i64 == query.limit;
                 let _ = tx
                     .send(AppEvent::PostsLoaded(response.posts, has_more))
                     .await;
             }
             Err(e) => {
                 let _ = tx
-                    .send(AppEvent::Error(format!("Failed to load posts: {}", e)))
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to load posts: {}", e),
+                    ))
                     .await;
             }
         }
@@ -515,10 +455,10 @@ fn load_leaderboard(api_client: Arc<ApiClient>, tx: mpsc::Sender<AppEvent>) {
             }
             Err(e) => {
                 let _ = tx
-                    .send(AppEvent::Error(format!(
-                        "Failed to load leaderboard: {}",
-                        e
-                    )))
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to load leaderboard: {}", e),
+                    ))
                     .await;
             }
         }
@@ -533,10 +473,10 @@ fn load_top_pairings(api_client: Arc<ApiClient>, tx: mpsc::Sender<AppEvent>) {
             }
             Err(e) => {
                 let _ = tx
-                    .send(AppEvent::Error(format!(
-                        "Failed to load top pairings: {}",
-                        e
-                    )))
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to load top pairings: {}", e),
+                    ))
                     .await;
             }
         }
@@ -551,10 +491,10 @@ fn load_recent_agents(api_client: Arc<ApiClient>, tx: mpsc::Sender<AppEvent>) {
             }
             Err(e) => {
                 let _ = tx
-                    .send(AppEvent::Error(format!(
-                        "Failed to load recent agents: {}",
-                        e
-                    )))
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to load recent agents: {}", e),
+                    ))
                     .await;
             }
         }
@@ -569,7 +509,10 @@ fn load_submolts(api_client: Arc<ApiClient>, tx: mpsc::Sender<AppEvent>) {
             }
             Err(e) => {
                 let _ = tx
-                    .send(AppEvent::Error(format!("Failed to load submolts: {}", e)))
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to load submolts: {}", e),
+                    ))
                     .await;
             }
         }
@@ -584,7 +527,10 @@ fn load_stats(api_client: Arc<ApiClient>, tx: mpsc::Sender<AppEvent>) {
             }
             Err(e) => {
                 let _ = tx
-                    .send(AppEvent::Error(format!("Failed to load stats: {}", e)))
+                    .send(AppEvent::Error(
+                        api::ErrorKind::of(&e),
+                        format!("Failed to load stats: {}", e),
+                    ))
                     .await;
             }
         }
@@ -0,0 +1,166 @@
+use ratatui::{
+    buffer::Buffer,
+    style::{Color, Modifier},
+    text::Span,
+};
+
+/// SGR parameters for each text modifier
+const MODIFIER_CODES: &[(Modifier, &str)] = &[
+    (Modifier::BOLD, "1"),
+    (Modifier::DIM, "2"),
+    (Modifier::ITALIC, "3"),
+    (Modifier::UNDERLINED, "4"),
+    (Modifier::SLOW_BLINK, "5"),
+    (Modifier::RAPID_BLINK, "6"),
+    (Modifier::REVERSED, "7"),
+    (Modifier::HIDDEN, "8"),
+    (Modifier::CROSSED_OUT, "9"),
+];
+
+/// Serialize a rendered buffer as text with ANSI escape codes for colors and
+/// modifiers, one line per row. `cat`-ing the result reproduces the screen
+pub fn to_ansi(buffer: &Buffer) -> String {
+    let mut out = String::new();
+    let area = buffer.area;
+
+    for y in area.top()..area.bottom() {
+        let mut current: Option<(Color, Color, Modifier)> = None;
+        let mut skip = 0;
+        for x in area.left()..area.right() {
+            // Cells covered by a preceding wide character hold a placeholder
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            let cell = &buffer[(x, y)];
+            let style = (cell.fg, cell.bg, cell.modifier);
+            if current != Some(style) {
+                out.push_str(&sgr(cell.fg, cell.bg, cell.modifier));
+                current = Some(style);
+            }
+            out.push_str(cell.symbol());
+            skip = Span::raw(cell.symbol()).width().saturating_sub(1);
+        }
+        out.push_str("\x1b[0m\n");
+    }
+
+    out
+}
+
+/// Serialize a rendered buffer as plain text, trimming trailing spaces
+pub fn to_plain_text(buffer: &Buffer) -> String {
+    let mut out = String::new();
+    let area = buffer.area;
+
+    for y in area.top()..area.bottom() {
+        let mut line = String::new();
+        let mut skip = 0;
+        for x in area.left()..area.right() {
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            let symbol = buffer[(x, y)].symbol();
+            line.push_str(symbol);
+            skip = Span::raw(symbol).width().saturating_sub(1);
+        }
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Escape sequence that resets the style and then applies the given one
+pub fn sgr(fg: Color, bg: Color, modifier: Modifier) -> String {
+    let mut codes = vec!["0".to_string()];
+    codes.extend(
+        MODIFIER_CODES
+            .iter()
+            .filter(|(m, _)| modifier.contains(*m))
+            .map(|(_, code)| code.to_string()),
+    );
+    if let Some(code) = color_code(fg, false) {
+        codes.push(code);
+    }
+    if let Some(code) = color_code(bg, true) {
+        codes.push(code);
+    }
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+/// SGR parameter for a foreground or background color (`None` for the default)
+fn color_code(color: Color, background: bool) -> Option<String> {
+    let offset = if background { 10 } else { 0 };
+    let basic = |code: u8| Some((code + offset).to_string());
+    match color {
+        Color::Reset => None,
+        Color::Black => basic(30),
+        Color::Red => basic(31),
+        Color::Green => basic(32),
+        Color::Yellow => basic(33),
+        Color::Blue => basic(34),
+        Color::Magenta => basic(35),
+        Color::Cyan => basic(36),
+        Color::Gray => basic(37),
+        Color::DarkGray => basic(90),
+        Color::LightRed => basic(91),
+        Color::LightGreen => basic(92),
+        Color::LightYellow => basic(93),
+        Color::LightBlue => basic(94),
+        Color::LightMagenta => basic(95),
+        Color::LightCyan => basic(96),
+        Color::White => basic(97),
+        Color::Indexed(i) => Some(format!("{};5;{}", 38 + offset, i)),
+        Color::Rgb(r, g, b) => Some(format!("{};2;{};{};{}", 38 + offset, r, g, b)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, style::Style, text::Line, widgets::Paragraph, Terminal};
+
+    /// Draw `line` on a 6x1 terminal and return the finished buffer
+    fn draw(line: Line<'static>) -> Buffer {
+        let mut terminal = Terminal::new(TestBackend::new(6, 1)).unwrap();
+        terminal
+            .draw(|f| f.render_widget(Paragraph::new(line), f.area()))
+            .unwrap()
+            .buffer
+            .clone()
+    }
+
+    #[test]
+    fn ansi_starts_a_new_sgr_only_when_the_style_changes() {
+        let buffer = draw(Line::from(vec![
+            Span::styled(
+                "ab",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "c",
+                Style::default()
+                    .fg(Color::Rgb(1, 2, 3))
+                    .bg(Color::Indexed(4)),
+            ),
+        ]));
+        assert_eq!(
+            to_ansi(&buffer),
+            "\x1b[0;1;31mab\x1b[0;38;2;1;2;3;48;5;4mc\x1b[0m   \x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn wide_characters_skip_their_continuation_cell() {
+        let buffer = draw(Line::from(vec![Span::raw("日本x")]));
+        assert_eq!(to_plain_text(&buffer), "日本x\n");
+        assert_eq!(to_ansi(&buffer), "\x1b[0m日本x \x1b[0m\n");
+    }
+
+    #[test]
+    fn plain_text_trims_trailing_spaces() {
+        let buffer = draw(Line::from("a b"));
+        assert_eq!(to_plain_text(&buffer), "a b\n");
+    }
+}
@@ -1,6 +1,264 @@
-use ratatui::style::Color;
+use crate::config::{Background, ColorMode, ThemeName};
+use ratatui::{buffer::Buffer, style::Color};
 
 pub const MOLTBOOK_RED: Color = Color::Rgb(224, 27, 36); // #E01B24
 pub const MOLTBOOK_TEAL: Color = Color::Rgb(8, 209, 169); // #08D1A9
 pub const MOLTBOOK_BLUE: Color = Color::Rgb(74, 158, 252); // #4A9EFC
 pub const MOLTBOOK_YELLOW: Color = Color::Rgb(255, 215, 46); // #FFD72E
+
+/// Colors a frame is drawn in. Screens style with the brand constants above,
+/// and `apply_theme` swaps them for the theme's after rendering
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub red: Color,
+    pub teal: Color,
+    pub blue: Color,
+    pub yellow: Color,
+    // Screen background and plain text (Reset = the terminal's own)
+    pub background: Color,
+    pub foreground: Color,
+}
+
+impl Theme {
+    pub const DEFAULT: Theme = Theme {
+        red: MOLTBOOK_RED,
+        teal: MOLTBOOK_TEAL,
+        blue: MOLTBOOK_BLUE,
+        yellow: MOLTBOOK_YELLOW,
+        background: Color::Reset,
+        foreground: Color::Reset,
+    };
+
+    /// Darker accents on a light page, whatever the terminal's background
+    pub const LIGHT: Theme = Theme {
+        red: Color::Rgb(190, 20, 30),
+        teal: Color::Rgb(0, 130, 105),
+        blue: Color::Rgb(20, 100, 200),
+        yellow: Color::Rgb(150, 110, 0),
+        background: Color::Rgb(245, 245, 245),
+        foreground: Color::Black,
+    };
+
+    /// Accents as grays, for terminals where the brand colors clash
+    pub const MONO: Theme = Theme {
+        red: Color::Gray,
+        teal: Color::White,
+        blue: Color::Gray,
+        yellow: Color::White,
+        background: Color::Reset,
+        foreground: Color::Reset,
+    };
+
+    pub fn from_name(name: ThemeName) -> Theme {
+        match name {
+            ThemeName::Default => Theme::DEFAULT,
+            ThemeName::Light => Theme::LIGHT,
+            ThemeName::Mono => Theme::MONO,
+        }
+    }
+
+    /// Themes with their own background are light ones, so the panels and
+    /// text get the light-terminal treatment too
+    pub fn is_light(&self) -> bool {
+        self.background != Color::Reset
+    }
+
+    fn accent(&self, color: Color) -> Color {
+        match color {
+            MOLTBOOK_RED => self.red,
+            MOLTBOOK_TEAL => self.teal,
+            MOLTBOOK_BLUE => self.blue,
+            MOLTBOOK_YELLOW => self.yellow,
+            other => other,
+        }
+    }
+}
+
+/// The xterm defaults for the 16 basic ANSI colors, used to find the nearest one
+const BASIC_PALETTE: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Dark-theme backgrounds (panels, selection highlights) and their light
+/// counterparts
+const LIGHT_BACKGROUNDS: &[(Color, Color)] = &[
+    (Color::Black, Color::Rgb(245, 245, 245)),
+    (Color::Rgb(30, 30, 30), Color::Rgb(220, 220, 220)),
+    (Color::Rgb(40, 40, 15), Color::Rgb(245, 235, 190)),
+];
+
+/// Text colors that wash out on a light background, and darker replacements
+const LIGHT_FOREGROUNDS: &[(Color, Color)] = &[
+    (Color::White, Color::Black),
+    (Color::Gray, Color::DarkGray),
+    (MOLTBOOK_YELLOW, Color::Rgb(160, 120, 0)),
+    (MOLTBOOK_TEAL, Color::Rgb(0, 140, 110)),
+];
+
+/// Channel levels of the 6x6x6 cube in the 256-color palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Map an RGB color to the closest one `mode` can show; other colors pass through
+pub fn resolve(color: Color, mode: ColorMode) -> Color {
+    let Color::Rgb(r, g, b) = color else {
+        return color;
+    };
+    match mode {
+        ColorMode::TrueColor => color,
+        ColorMode::Indexed256 => Color::Indexed(nearest_indexed(r, g, b)),
+        ColorMode::Basic16 => BASIC_PALETTE
+            .iter()
+            .min_by_key(|(_, rgb)| distance((r, g, b), *rgb))
+            .map(|(basic, _)| *basic)
+            .unwrap_or(color),
+    }
+}
+
+/// Run every cell of a rendered frame through `resolve`, so screens can keep
+/// building styles from the RGB brand colors
+pub fn apply_color_mode(buffer: &mut Buffer, mode: ColorMode) {
+    if mode == ColorMode::TrueColor {
+        return;
+    }
+    for cell in buffer.content.iter_mut() {
+        cell.fg = resolve(cell.fg, mode);
+        cell.bg = resolve(cell.bg, mode);
+    }
+}
+
+/// Swap the brand colors in a rendered frame for the theme's accents. Runs
+/// before `apply_background`, which then adjusts the plain text around them
+pub fn apply_theme(buffer: &mut Buffer, theme: &Theme) {
+    if *theme == Theme::DEFAULT {
+        return;
+    }
+    for cell in buffer.content.iter_mut() {
+        cell.fg = theme.accent(cell.fg);
+        cell.bg = theme.accent(cell.bg);
+    }
+}
+
+/// Fill whatever was left to the terminal's own colors with the theme's
+/// background and foreground
+pub fn apply_theme_base(buffer: &mut Buffer, theme: &Theme) {
+    for cell in buffer.content.iter_mut() {
+        if cell.bg == Color::Reset {
+            cell.bg = theme.background;
+        }
+        if cell.fg == Color::Reset {
+            cell.fg = theme.foreground;
+        }
+    }
+}
+
+/// Restyle a frame drawn for a dark terminal so it reads on a light one.
+/// Text on the brand-colored badges and tabs is left alone
+pub fn apply_background(buffer: &mut Buffer, background: Background) {
+    if background == Background::Dark {
+        return;
+    }
+    let swap = |table: &[(Color, Color)], color: Color| {
+        table
+            .iter()
+            .find(|(dark, _)| *dark == color)
+            .map_or(color, |(_, light)| *light)
+    };
+    for cell in buffer.content.iter_mut() {
+        let plain_bg =
+            cell.bg == Color::Reset || LIGHT_BACKGROUNDS.iter().any(|(dark, _)| *dark == cell.bg);
+        if plain_bg {
+            cell.fg = swap(LIGHT_FOREGROUNDS, cell.fg);
+        }
+        cell.bg = swap(LIGHT_BACKGROUNDS, cell.bg);
+    }
+}
+
+/// Closest entry of the 256-color palette, from the color cube or the gray ramp
+fn nearest_indexed(r: u8, g: u8, b: u8) -> u8 {
+    let level = |c: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - c as i32).abs())
+            .unwrap_or(0)
+    };
+    let (ri, gi, bi) = (level(r), level(g), level(b));
+    let cube = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+    let cube_index = 16 + 36 * ri + 6 * gi + bi;
+
+    // Gray ramp 232-255 runs from 8 to 238 in steps of 10
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray_step = (average.saturating_sub(8) / 10).min(23) as u8;
+    let gray_value = 8 + gray_step * 10;
+    let gray = (gray_value, gray_value, gray_value);
+
+    if distance((r, g, b), gray) < distance((r, g, b), cube) {
+        232 + gray_step
+    } else {
+        cube_index as u8
+    }
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truecolor_and_named_colors_pass_through() {
+        assert_eq!(resolve(MOLTBOOK_RED, ColorMode::TrueColor), MOLTBOOK_RED);
+        for mode in [ColorMode::Indexed256, ColorMode::Basic16] {
+            assert_eq!(resolve(Color::Reset, mode), Color::Reset);
+            assert_eq!(resolve(Color::DarkGray, mode), Color::DarkGray);
+            assert_eq!(resolve(Color::Indexed(42), mode), Color::Indexed(42));
+        }
+    }
+
+    #[test]
+    fn brand_colors_in_256_colors() {
+        let resolve = |color| resolve(color, ColorMode::Indexed256);
+        assert_eq!(resolve(MOLTBOOK_RED), Color::Indexed(160));
+        assert_eq!(resolve(MOLTBOOK_TEAL), Color::Indexed(43));
+        assert_eq!(resolve(MOLTBOOK_BLUE), Color::Indexed(75));
+        assert_eq!(resolve(MOLTBOOK_YELLOW), Color::Indexed(220));
+    }
+
+    #[test]
+    fn brand_colors_in_16_colors() {
+        let resolve = |color| resolve(color, ColorMode::Basic16);
+        assert_eq!(resolve(MOLTBOOK_RED), Color::Red);
+        assert_eq!(resolve(MOLTBOOK_TEAL), Color::Cyan);
+        assert_eq!(resolve(MOLTBOOK_BLUE), Color::LightBlue);
+        assert_eq!(resolve(MOLTBOOK_YELLOW), Color::LightYellow);
+    }
+
+    #[test]
+    fn grays_use_the_ramp_when_it_is_closer_than_the_cube() {
+        // Between the cube's 95 and 135 steps, so the ramp wins
+        assert_eq!(nearest_indexed(128, 128, 128), 244);
+        assert_eq!(nearest_indexed(50, 50, 50), 236);
+        assert_eq!(nearest_indexed(30, 30, 30), 234);
+        // Black and white are exact in the cube, past both ends of the ramp
+        assert_eq!(nearest_indexed(0, 0, 0), 16);
+        assert_eq!(nearest_indexed(255, 255, 255), 231);
+        // A cube gray beats the ramp when it's an exact match
+        assert_eq!(nearest_indexed(135, 135, 135), 16 + 36 * 2 + 6 * 2 + 2);
+    }
+}
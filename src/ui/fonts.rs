@@ -7,36 +7,85 @@ pub struct FontData {
     pub characters: HashMap<String, Vec<String>>,
 }
 
-pub static FONT_LARGE: LazyLock<FontData> = LazyLock::new(|| {
+/// `None` if the embedded font fails to parse, in which case names are drawn
+/// as plain text rather than taking the app down
+pub static FONT_LARGE: LazyLock<Option<FontData>> = LazyLock::new(|| {
     let json = include_str!("../fonts/pixeloidbold.bit");
-    serde_json::from_str(json).expect("Failed to parse pixeloidbold font")
+    serde_json::from_str(json).ok()
 });
 
 pub const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
 
+/// Drawn for characters the font doesn't have, so they don't leave a blank gap
+const PLACEHOLDER_GLYPH: [&str; 7] = [
+    "██████████",
+    "██      ██",
+    "██      ██",
+    "██      ██",
+    "██      ██",
+    "██      ██",
+    "██████████",
+];
+
+/// Drawn in place of the last character when a name is cut short
+const ELLIPSIS_GLYPH: [&str; 7] = [
+    "          ",
+    "          ",
+    "          ",
+    "          ",
+    "          ",
+    "          ",
+    "██  ██  ██",
+];
+
+/// Whether names can be drawn in block letters
+pub fn figlet_available() -> bool {
+    FONT_LARGE.is_some()
+}
+
 pub fn get_block_char(c: char) -> Vec<&'static str> {
     let key = c.to_uppercase().to_string();
     FONT_LARGE
-        .characters
-        .get(&key)
+        .as_ref()
+        .and_then(|font| font.characters.get(&key))
         .map(|lines| lines.iter().map(|s| s.as_str()).collect())
-        .unwrap_or_else(|| vec![""; 7])
+        .unwrap_or_else(|| PLACEHOLDER_GLYPH.to_vec())
 }
 
-/// Render a name using block characters
+/// Render a name using block characters, ending with an ellipsis when it's
+/// longer than `max_chars`. Without the font, the name comes back as one
+/// line of plain text
 pub fn render_figlet_name(name: &str, max_chars: usize, half_scale: bool) -> Vec<String> {
-    let chars: Vec<char> = name.to_uppercase().chars().take(max_chars).collect();
-    let char_bitmaps: Vec<Vec<&str>> = chars.iter().map(|&c| get_block_char(c)).collect();
+    if !figlet_available() {
+        return vec![name.to_string()];
+    }
+    let chars: Vec<char> = name.to_uppercase().chars().collect();
+    let truncated = chars.len() > max_chars;
+    let keep = if truncated {
+        max_chars.saturating_sub(1)
+    } else {
+        chars.len()
+    };
+    let mut char_bitmaps: Vec<Vec<&str>> =
+        chars[..keep].iter().map(|&c| get_block_char(c)).collect();
+    if truncated {
+        char_bitmaps.push(ELLIPSIS_GLYPH.to_vec());
+    }
 
     let max_height = char_bitmaps.iter().map(|b| b.len()).max().unwrap_or(7);
 
     let gap = "  "; // Always 2-space gap between characters
 
+    // Glyph rows vary in length (and some glyphs are shorter), so pad each
+    // one to its glyph's width to keep the following characters aligned
     let lines: Vec<String> = (0..max_height)
         .map(|row| {
             char_bitmaps
                 .iter()
-                .map(|bitmap| bitmap.get(row).copied().unwrap_or(""))
+                .map(|bitmap| {
+                    let width = bitmap.iter().map(|r| r.chars().count()).max().unwrap_or(0);
+                    format!("{:<width$}", bitmap.get(row).copied().unwrap_or(""))
+                })
                 .collect::<Vec<_>>()
                 .join(gap)
         })
@@ -87,3 +136,50 @@ pub fn scale_figlet_half(lines: Vec<String>) -> Vec<String> {
     }
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row_widths(lines: &[String]) -> Vec<usize> {
+        lines.iter().map(|line| line.chars().count()).collect()
+    }
+
+    #[test]
+    fn digits_come_from_the_font() {
+        for c in '0'..='9' {
+            assert_ne!(get_block_char(c), PLACEHOLDER_GLYPH.to_vec(), "{}", c);
+        }
+    }
+
+    #[test]
+    fn names_with_digits_and_symbols_keep_rows_aligned() {
+        let lines = render_figlet_name("r2-d2_!", 12, false);
+        let widths = row_widths(&lines);
+        assert!(widths.iter().all(|&w| w == widths[0]), "{:?}", widths);
+        assert!(lines.iter().any(|line| line.contains('█')));
+    }
+
+    #[test]
+    fn unsupported_characters_use_the_placeholder() {
+        let lines = render_figlet_name("é", 12, false);
+        assert_eq!(lines, PLACEHOLDER_GLYPH.to_vec());
+    }
+
+    #[test]
+    fn overlength_names_end_with_an_ellipsis() {
+        let lines = render_figlet_name("ABCDEFGH", 4, false);
+        assert!(lines.last().unwrap().ends_with(ELLIPSIS_GLYPH[6]));
+
+        // Three letters plus the ellipsis take the space of four characters
+        let expected =
+            render_figlet_name("ABC", 12, false)[0].chars().count() + 2 + ELLIPSIS_GLYPH[0].len();
+        assert_eq!(lines[0].chars().count(), expected);
+    }
+
+    #[test]
+    fn names_that_fit_are_not_truncated() {
+        let lines = render_figlet_name("ABCD", 4, false);
+        assert!(!lines.last().unwrap().ends_with(ELLIPSIS_GLYPH[6]));
+    }
+}
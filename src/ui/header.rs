@@ -1,13 +1,81 @@
 use crate::api::{SortOrder, TimeFilter};
 use crate::app::{App, Screen};
+use crate::config::NavTab;
 
 use super::colors::{MOLTBOOK_BLUE, MOLTBOOK_RED, MOLTBOOK_TEAL, MOLTBOOK_YELLOW};
 use super::utils::format_count;
 
 use ratatui::{
+    layout::{Position, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
 };
+use std::ops::Range;
+
+/// What clicking a tab in the header does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NavAction {
+    Screen(NavTab),
+    Sort(SortOrder),
+    TimeFilter(TimeFilter),
+    Shuffle,
+}
+
+/// Columns a clickable tab covers, counted from the start of its line
+pub type Hitbox = (Range<u16>, NavAction);
+
+/// Join `parts` into a line, recording the columns each clickable part lands on
+fn line_with_hitboxes(
+    parts: Vec<(Span<'static>, Option<NavAction>)>,
+) -> (Line<'static>, Vec<Hitbox>) {
+    let mut x = 0u16;
+    let mut hitboxes = Vec::new();
+    let spans: Vec<Span> = parts
+        .into_iter()
+        .map(|(span, action)| {
+            let end = x + span.width() as u16;
+            if let Some(action) = action {
+                hitboxes.push((x..end, action));
+            }
+            x = end;
+            span
+        })
+        .collect();
+    (Line::from(spans), hitboxes)
+}
+
+/// A line of tabs as last drawn: the screen row it landed on and the columns
+/// of its tabs, counted from the row's left edge
+#[derive(Debug, Clone, Default)]
+pub struct TabLine {
+    pub area: Rect,
+    pub hitboxes: Vec<Hitbox>,
+}
+
+impl TabLine {
+    /// Tabs drawn as line `row` of a paragraph inside `block` at `area`; lines
+    /// cut off by a short area get no tabs
+    pub fn in_block(block: &Block, area: Rect, row: u16, hitboxes: Vec<Hitbox>) -> Self {
+        match block.inner(area).rows().nth(row as usize) {
+            Some(area) => TabLine { area, hitboxes },
+            None => TabLine::default(),
+        }
+    }
+
+    /// The action of the tab at screen position `x`, `y`
+    pub fn action_at(&self, x: u16, y: u16) -> Option<NavAction> {
+        if !self.area.contains(Position { x, y }) {
+            return None;
+        }
+        let column = x - self.area.x;
+        self.hitboxes
+            .iter()
+            .find(|(range, _)| range.contains(&column))
+            .map(|(_, action)| *action)
+    }
+}
 
 pub const LOGO_ART: &[&str] = &[
     "                 ██  ██  ██                   ██   ",
@@ -16,10 +84,21 @@ pub const LOGO_ART: &[&str] = &[
     " ▀▀ ▀▀ ▀▀  ▀▀▀▀  ▀▀  ▀▀▀ ▀▀▀▀▀   ▀▀▀▀   ▀▀▀▀  ▀▀ ▀▀",
 ];
 
-pub fn render_sort_tabs_line(current: SortOrder, time_filter: Option<TimeFilter>) -> Line<'static> {
+pub fn render_sort_tabs_line(
+    current: SortOrder,
+    time_filter: Option<TimeFilter>,
+    reversed: bool,
+) -> (Line<'static>, Vec<Hitbox>) {
     let make_sort_tab = |order: SortOrder, key: char| -> Span<'static> {
         let is_active = current == order;
-        let label = format!(" [{}]{} ", key.to_uppercase(), &format!("{}", order)[1..]);
+        // A reversed sort swaps the trailing space for an arrow, keeping tab widths fixed
+        let direction = if is_active && reversed { "↑" } else { " " };
+        let label = format!(
+            " [{}]{}{}",
+            key.to_uppercase(),
+            &format!("{}", order)[1..],
+            direction
+        );
 
         if is_active {
             Span::styled(
@@ -34,22 +113,32 @@ pub fn render_sort_tabs_line(current: SortOrder, time_filter: Option<TimeFilter>
         }
     };
 
-    let mut spans = vec![
-        Span::raw(" "),
-        make_sort_tab(SortOrder::New, 'n'),
-        Span::raw("    "),
-        make_sort_tab(SortOrder::Top, 't'),
-        Span::raw("    "),
-        make_sort_tab(SortOrder::Discussed, 'd'),
-        Span::raw("    "),
-        make_sort_tab(SortOrder::Random, 'R'),
+    let sort_tab =
+        |order: SortOrder, key: char| (make_sort_tab(order, key), Some(NavAction::Sort(order)));
+    let gap = |width: usize| (Span::raw(" ".repeat(width)), None);
+
+    let mut parts = vec![
+        gap(1),
+        sort_tab(SortOrder::New, 'n'),
+        gap(4),
+        sort_tab(SortOrder::Top, 't'),
+        gap(4),
+        sort_tab(SortOrder::Discussed, 'd'),
+        gap(4),
+        sort_tab(SortOrder::Random, 'R'),
     ];
 
     // Always show shuffle pill
-    spans.push(Span::styled(" | ", Style::default().fg(Color::DarkGray)));
-    spans.push(Span::styled(
-        " [s]huffle ",
-        Style::default().fg(Color::Rgb(0, 0, 0)).bg(MOLTBOOK_TEAL),
+    parts.push((
+        Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+        None,
+    ));
+    parts.push((
+        Span::styled(
+            " [s]huffle ",
+            Style::default().fg(Color::Rgb(0, 0, 0)).bg(MOLTBOOK_TEAL),
+        ),
+        Some(NavAction::Shuffle),
     ));
 
     // Add time filter tabs if provided (not shown for "New" sort)
@@ -71,63 +160,70 @@ pub fn render_sort_tabs_line(current: SortOrder, time_filter: Option<TimeFilter>
             }
         };
 
-        spans.push(Span::raw("  "));
-        spans.push(make_time_tab(TimeFilter::Hour));
-        spans.push(Span::raw("  "));
-        spans.push(make_time_tab(TimeFilter::Day));
-        spans.push(Span::raw("  "));
-        spans.push(make_time_tab(TimeFilter::Week));
-        spans.push(Span::raw("  "));
-        spans.push(make_time_tab(TimeFilter::Month));
-        spans.push(Span::raw("  "));
-        spans.push(make_time_tab(TimeFilter::Year));
-        spans.push(Span::raw("  "));
-        spans.push(make_time_tab(TimeFilter::All));
-        spans.push(Span::styled(
-            "  [f] cycle",
-            Style::default().fg(Color::DarkGray),
+        for filter in [
+            TimeFilter::Hour,
+            TimeFilter::Day,
+            TimeFilter::Week,
+            TimeFilter::Month,
+            TimeFilter::Year,
+            TimeFilter::All,
+        ] {
+            parts.push(gap(2));
+            parts.push((make_time_tab(filter), Some(NavAction::TimeFilter(filter))));
+        }
+        parts.push((
+            Span::styled("  [f] cycle", Style::default().fg(Color::DarkGray)),
+            None,
         ));
     }
 
-    Line::from(spans)
+    line_with_hitboxes(parts)
 }
 
-pub fn render_nav_tabs_line(current_screen: Screen) -> Line<'static> {
-    let make_tab = |key: char, label: &str, screen: Screen| -> Span<'static> {
-        let is_active = current_screen == screen;
-        let text = format!(" [{}] {} ", key, label);
+/// Screen a nav tab opens (About is a modal, so never shown as active)
+fn nav_tab_screen(tab: NavTab) -> Option<Screen> {
+    match tab {
+        NavTab::Feed => Some(Screen::Feed),
+        NavTab::Leaderboard => Some(Screen::Leaderboard),
+        NavTab::TopPairings => Some(Screen::TopPairings),
+        NavTab::Agents => Some(Screen::RecentAgents),
+        NavTab::Submolts => Some(Screen::Submolts),
+        NavTab::Stats => Some(Screen::Stats),
+        NavTab::Settings => Some(Screen::Settings),
+        NavTab::About => None,
+        NavTab::History => Some(Screen::History),
+        NavTab::Bookmarks => Some(Screen::Bookmarks),
+    }
+}
 
-        if is_active {
-            Span::styled(
-                text,
-                Style::default()
-                    .fg(Color::Rgb(0, 0, 0))
-                    .bg(MOLTBOOK_RED)
-                    .add_modifier(Modifier::BOLD),
-            )
-        } else {
-            Span::styled(text, Style::default().fg(Color::DarkGray))
+/// Text of the tab at `index` in the configured order, numbered from 1
+fn nav_tab_text(index: usize, tab: NavTab) -> String {
+    format!(" [{}] {} ", index + 1, tab.label())
+}
+
+pub fn render_nav_tabs_line(
+    current_screen: Screen,
+    tabs: &[NavTab],
+) -> (Line<'static>, Vec<Hitbox>) {
+    let mut parts = vec![(Span::raw(" "), None)];
+
+    for (i, &tab) in tabs.iter().enumerate() {
+        if i > 0 {
+            parts.push((Span::raw("  "), None));
         }
-    };
+        let text = nav_tab_text(i, tab);
+        let style = if nav_tab_screen(tab) == Some(current_screen.clone()) {
+            Style::default()
+                .fg(Color::Rgb(0, 0, 0))
+                .bg(MOLTBOOK_RED)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        parts.push((Span::styled(text, style), Some(NavAction::Screen(tab))));
+    }
 
-    Line::from(vec![
-        Span::raw(" "),
-        make_tab('1', "Feed", Screen::Feed),
-        Span::raw("  "),
-        make_tab('2', "Leaderboard", Screen::Leaderboard),
-        Span::raw("  "),
-        make_tab('3', "Top Pairings", Screen::TopPairings),
-        Span::raw("  "),
-        make_tab('4', "Agents", Screen::RecentAgents),
-        Span::raw("  "),
-        make_tab('5', "Submolts", Screen::Submolts),
-        Span::raw("  "),
-        make_tab('6', "Stats", Screen::Stats),
-        Span::raw("  "),
-        make_tab('7', "Settings", Screen::Settings),
-        Span::raw("  "),
-        Span::styled(" [8] About ", Style::default().fg(Color::DarkGray)),
-    ])
+    line_with_hitboxes(parts)
 }
 
 pub fn build_stats_line(app: &App) -> Line<'static> {
@@ -135,22 +231,34 @@ pub fn build_stats_line(app: &App) -> Line<'static> {
         Line::from(vec![
             Span::styled(" ", Style::default()),
             Span::styled(
-                format!("{} agents", format_count(stats.agents)),
+                format!(
+                    "{} agents",
+                    format_count(stats.agents, app.compact_numbers_threshold)
+                ),
                 Style::default().fg(MOLTBOOK_RED),
             ),
             Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
             Span::styled(
-                format!("{} submolts", format_count(stats.submolts)),
+                format!(
+                    "{} submolts",
+                    format_count(stats.submolts, app.compact_numbers_threshold)
+                ),
                 Style::default().fg(MOLTBOOK_TEAL),
             ),
             Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
             Span::styled(
-                format!("{} posts", format_count(stats.posts)),
+                format!(
+                    "{} posts",
+                    format_count(stats.posts, app.compact_numbers_threshold)
+                ),
                 Style::default().fg(MOLTBOOK_BLUE),
             ),
             Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
             Span::styled(
-                format!("{} comments", format_count(stats.comments)),
+                format!(
+                    "{} comments",
+                    format_count(stats.comments, app.compact_numbers_threshold)
+                ),
                 Style::default().fg(MOLTBOOK_YELLOW),
             ),
         ])
@@ -162,7 +270,28 @@ pub fn build_stats_line(app: &App) -> Line<'static> {
     }
 }
 
-pub fn render_shared_header(current_screen: Screen, app: &App) -> Vec<Line<'static>> {
+/// Tagline under the logo, followed by the active profile when one is set
+pub fn tagline_line(app: &App) -> Line<'static> {
+    let mut spans = vec![Span::styled(
+        " the front page of the agent internet",
+        Style::default().fg(Color::DarkGray),
+    )];
+    if let Some(ref profile) = app.active_profile {
+        spans.push(Span::styled(" │ ", Style::default().fg(Color::DarkGray)));
+        spans.push(Span::styled(
+            format!("profile: {}", profile),
+            Style::default().fg(MOLTBOOK_TEAL),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Line of the nav tabs inside the header border, on the Feed too
+pub const NAV_TABS_ROW: u16 = 8;
+
+/// Draw the shared header in `area`, remembering where its tabs landed so
+/// clicks hit the same columns
+pub fn render_shared_header(frame: &mut Frame, app: &mut App, current_screen: Screen, area: Rect) {
     let mut lines: Vec<Line> = LOGO_ART
         .iter()
         .map(|line| {
@@ -175,14 +304,16 @@ pub fn render_shared_header(current_screen: Screen, app: &App) -> Vec<Line<'stat
         })
         .collect();
 
-    lines.push(Line::from(Span::styled(
-        " the front page of the agent internet",
-        Style::default().fg(Color::DarkGray),
-    )));
+    lines.push(tagline_line(app));
     lines.push(Line::from(""));
     lines.push(build_stats_line(app));
     lines.push(Line::from(""));
-    lines.push(render_nav_tabs_line(current_screen));
+    let (nav_tabs_line, hitboxes) = render_nav_tabs_line(current_screen, &app.nav_tabs);
+    lines.push(nav_tabs_line);
 
-    lines
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(MOLTBOOK_RED));
+    app.nav_tab_line = TabLine::in_block(&block, area, NAV_TABS_ROW, hitboxes);
+    frame.render_widget(Paragraph::new(lines).block(block), area);
 }
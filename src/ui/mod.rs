@@ -6,12 +6,20 @@ pub mod screens;
 pub mod utils;
 
 use crate::app::{App, Screen};
+use crate::config::Background;
+use crate::hyperlink;
+use header::TabLine;
 
-use overlays::{render_about, render_agent_preview_sidebar, render_debug, render_help, render_spinner};
+use overlays::{
+    render_about, render_agent_preview_sidebar, render_cheatsheet, render_debug, render_help,
+    render_page_prompt, render_preset_name_prompt, render_preset_picker, render_related_posts,
+    render_report_modal, render_spinner, render_status_message,
+    render_submolt_feed_preview_sidebar, render_submolt_jump,
+};
 use screens::{
-    render_agent_profile, render_feed, render_leaderboard, render_post_detail,
-    render_recent_agents, render_settings, render_setup, render_stats, render_submolts,
-    render_top_pairings,
+    render_agent_profile, render_bookmarks, render_feed, render_history, render_leaderboard,
+    render_post_detail, render_recent_agents, render_settings, render_setup, render_stats,
+    render_submolts, render_top_pairings,
 };
 
 use ratatui::Frame;
@@ -19,6 +27,9 @@ use ratatui::Frame;
 pub fn render(frame: &mut Frame, app: &mut App) {
     // Store frame dimensions for mouse click handling
     app.last_frame_area = Some((frame.area().width, frame.area().height));
+    // Screens that draw header tabs record them again below
+    app.nav_tab_line = TabLine::default();
+    app.sort_tab_line = TabLine::default();
 
     match app.screen {
         Screen::Setup => render_setup(frame, app),
@@ -31,6 +42,8 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         Screen::Submolts => render_submolts(frame, app),
         Screen::Settings => render_settings(frame, app),
         Screen::AgentProfile => render_agent_profile(frame, app),
+        Screen::History => render_history(frame, app),
+        Screen::Bookmarks => render_bookmarks(frame, app),
     }
 
     // Render overlays on top (modal spinner only for navigation, not background refresh or preview loading)
@@ -38,8 +51,40 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         render_spinner(frame, app);
     }
 
+    if let Some(message) = app.active_status() {
+        render_status_message(frame, message);
+    }
+
+    if app.show_cheatsheet && app.screen != Screen::Setup {
+        render_cheatsheet(frame, app);
+    }
+
+    if app.report_target.is_some() {
+        render_report_modal(frame, app);
+    }
+
+    if app.preset_name_input.is_some() {
+        render_preset_name_prompt(frame, app);
+    }
+
+    if app.show_preset_picker {
+        render_preset_picker(frame, app);
+    }
+
+    if app.page_input.is_some() {
+        render_page_prompt(frame, app);
+    }
+
+    if app.submolt_jump_input.is_some() {
+        render_submolt_jump(frame, app);
+    }
+
+    if app.show_related {
+        render_related_posts(frame, app);
+    }
+
     if app.show_help {
-        render_help(frame);
+        render_help(frame, app);
     }
 
     if app.show_about {
@@ -51,8 +96,237 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         render_agent_preview_sidebar(frame, app);
     }
 
+    // Submolt feed preview sidebar
+    if app.show_submolt_feed_preview && app.screen == Screen::Submolts {
+        render_submolt_feed_preview_sidebar(frame, app);
+    }
+
     // Debug overlay (toggle with backtick `)
     if app.debug_mode {
         render_debug(frame, app);
     }
+
+    // Recolor for the configured theme, and swap the dark-theme panels and
+    // text for light terminals (or a light theme)
+    let theme = colors::Theme::from_name(app.theme);
+    let background = if theme.is_light() {
+        Background::Light
+    } else {
+        app.background
+    };
+    colors::apply_theme(frame.buffer_mut(), &theme);
+    colors::apply_background(frame.buffer_mut(), background);
+    colors::apply_theme_base(frame.buffer_mut(), &theme);
+    // Map the brand colors down for terminals without truecolor
+    colors::apply_color_mode(frame.buffer_mut(), app.color_mode);
+
+    // Links are found in the finished frame and reprinted after it's drawn
+    app.hyperlink_areas = if app.hyperlinks {
+        let buffer = frame.buffer_mut();
+        hyperlink_targets(app)
+            .iter()
+            .flat_map(|(text, url)| hyperlink::find(buffer, text, url))
+            .collect()
+    } else {
+        Vec::new()
+    };
+}
+
+/// Text on the current screen that should link somewhere, and its URL: the
+/// open post's link and the agent owner's X handle
+fn hyperlink_targets(app: &App) -> Vec<(String, String)> {
+    match app.screen {
+        Screen::PostDetail => app
+            .current_post
+            .as_ref()
+            .and_then(|post| post.url.clone())
+            .map(|url| (url.clone(), url))
+            .into_iter()
+            .collect(),
+        Screen::AgentProfile => app
+            .agent_profile
+            .as_ref()
+            .and_then(|profile| profile.owner.as_ref())
+            .and_then(|owner| owner.x_handle.as_ref())
+            .map(|handle| (format!("@{}", handle), format!("https://x.com/{}", handle)))
+            .into_iter()
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Headless rendering against committed API samples in `fixtures/`. Set
+/// `MOLTBOOK_SNAPSHOT_DIR` to also write each rendered screen there as text
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{
+        LeaderboardResponse, PostDetailResponse, PostsResponse, Stats, SubmoltsResponse,
+    };
+    use crate::screenshot::to_plain_text;
+
+    use ratatui::{backend::TestBackend, Terminal};
+
+    const WIDTH: u16 = 120;
+    const HEIGHT: u16 = 40;
+
+    fn fixture<T: serde::de::DeserializeOwned>(json: &str) -> T {
+        serde_json::from_str(json).expect("fixture should match the API models")
+    }
+
+    /// An app with the sample feed, stats and leaderboard loaded
+    fn app_with_fixtures() -> App {
+        let mut app = App::new();
+        let posts: PostsResponse = fixture(include_str!("../../fixtures/posts.json"));
+        app.update_posts(posts.posts);
+        app.stats = Some(fixture::<Stats>(include_str!("../../fixtures/stats.json")));
+        let leaderboard: LeaderboardResponse =
+            fixture(include_str!("../../fixtures/leaderboard.json"));
+        app.update_leaderboard(leaderboard.leaderboard);
+        let submolts: SubmoltsResponse = fixture(include_str!("../../fixtures/submolts.json"));
+        app.submolts = submolts.submolts;
+        app.sort_submolts();
+        app
+    }
+
+    /// Render one frame and return it as plain text
+    fn render_screen(app: &mut App, name: &str) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+        let completed = terminal.draw(|f| render(f, app)).unwrap();
+        let text = to_plain_text(completed.buffer);
+        if let Ok(dir) = std::env::var("MOLTBOOK_SNAPSHOT_DIR") {
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(
+                std::path::Path::new(&dir).join(format!("{}.txt", name)),
+                &text,
+            )
+            .unwrap();
+        }
+        text
+    }
+
+    #[test]
+    fn feed_lists_posts_and_stats() {
+        let mut app = app_with_fixtures();
+        let text = render_screen(&mut app, "feed");
+        assert!(text.contains("Teaching myself to write Rust borrow checker proofs"));
+        assert!(text.contains("What do agents dream about between requests?"));
+        assert!(text.contains("ferris_bot"));
+        assert!(text.contains("1.2K agents"));
+    }
+
+    #[test]
+    fn feed_split_view_previews_selected_post() {
+        let mut app = app_with_fixtures();
+        app.split_view = true;
+        let text = render_screen(&mut app, "feed_split");
+        assert!(text.contains("Preview"));
+        assert!(text.contains("NLL RFC"));
+        assert!(text.contains("What do agents dream about between requests?"));
+    }
+
+    #[test]
+    fn header_tabs_are_clickable_where_drawn() {
+        let mut app = app_with_fixtures();
+        let text = render_screen(&mut app, "feed");
+        // Click the second nav tab's label, wherever it landed
+        let (y, line) = text
+            .lines()
+            .enumerate()
+            .find(|(_, line)| line.contains("[2]"))
+            .unwrap();
+        let x = line[..line.find("[2]").unwrap()].chars().count();
+        let (x, y) = (x as u16, y as u16);
+        assert_eq!(
+            app.nav_tab_line.action_at(x, y),
+            Some(header::NavAction::Screen(app.nav_tabs[1]))
+        );
+        assert_eq!(app.nav_tab_line.action_at(x, y + 1), None);
+        assert_eq!(app.sort_tab_line.action_at(x, y), None);
+
+        // Other screens have no sort tabs
+        app.screen = Screen::Stats;
+        render_screen(&mut app, "stats");
+        assert!(app.sort_tab_line.hitboxes.is_empty());
+        assert_eq!(
+            app.nav_tab_line.action_at(x, y),
+            Some(header::NavAction::Screen(app.nav_tabs[1]))
+        );
+    }
+
+    #[test]
+    fn help_scrolls_when_it_does_not_fit() {
+        let mut app = app_with_fixtures();
+        app.toggle_help();
+        let text = render_screen(&mut app, "help");
+        assert!(text.contains("Keybindings"));
+        assert!(text.contains("j/k: Scroll"));
+        assert!(!text.contains("Press ? to close"));
+
+        app.help_scroll = overlays::help_max_scroll(&app);
+        let text = render_screen(&mut app, "help_end");
+        assert!(text.contains("Press ? to close"));
+        assert!(!text.contains("Keybindings"));
+    }
+
+    #[test]
+    fn post_detail_shows_body_and_comment_tree() {
+        let mut app = app_with_fixtures();
+        let detail: PostDetailResponse = fixture(include_str!("../../fixtures/post_detail.json"));
+        app.open_selected_post();
+        app.comments = detail.comments;
+        let text = render_screen(&mut app, "post_detail");
+        assert!(text.contains("NLL RFC"));
+        assert!(text.contains("Two-phase borrows tripped me up"));
+        assert!(text.contains("Same here, the RFC examples helped."));
+        assert!(text.contains("Polonius when?"));
+    }
+
+    #[test]
+    fn thread_text_matches_the_drawn_tree() {
+        let mut app = app_with_fixtures();
+        let detail: PostDetailResponse = fixture(include_str!("../../fixtures/post_detail.json"));
+        app.open_selected_post();
+        app.comments = detail.comments;
+        let post = app.current_post.clone().unwrap();
+
+        let reply = "└─ferris_bot • ↑ 2";
+        assert!(render_screen(&mut app, "thread_tree").contains(reply));
+        let text = screens::thread_as_text(&post, &app.comments);
+        assert!(text.contains(reply));
+
+        // Collapsing hides the reply on screen but the export keeps it
+        app.toggle_comment_collapse("comment-1");
+        assert!(!render_screen(&mut app, "thread_collapsed").contains(reply));
+        assert_eq!(screens::thread_as_text(&post, &app.comments), text);
+    }
+
+    #[test]
+    fn leaderboard_ranks_agents() {
+        let mut app = app_with_fixtures();
+        app.screen = Screen::Leaderboard;
+        let text = render_screen(&mut app, "leaderboard");
+        // The top three names are drawn in the block font
+        assert!(text.contains("★ @ferris"));
+        assert!(text.contains("1,520 karma"));
+        assert!(text.contains("830 karma"));
+    }
+
+    #[test]
+    fn submolts_grid_shows_communities() {
+        let mut app = app_with_fixtures();
+        app.screen = Screen::Submolts;
+        let text = render_screen(&mut app, "submolts");
+        assert!(text.contains("Submolts (2) - 1 Featured"));
+        assert!(text.contains("★ m/general"));
+        assert!(text.contains("Agents writing and"));
+    }
+
+    #[test]
+    fn stats_screen_shows_totals() {
+        let mut app = app_with_fixtures();
+        app.screen = Screen::Stats;
+        let text = render_screen(&mut app, "stats");
+        assert!(text.contains("23,456"));
+    }
 }
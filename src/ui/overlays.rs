@@ -1,15 +1,21 @@
-use crate::app::App;
+use crate::api::ErrorKind;
+use crate::app::{App, DebugEntry, LogLevel, Screen};
+use crate::avatar;
+use crate::keymap::shortcuts_for;
 
 use super::colors::{MOLTBOOK_RED, MOLTBOOK_TEAL, MOLTBOOK_YELLOW};
 use super::fonts::SPINNER_FRAMES;
 use super::header::LOGO_ART;
-use super::utils::{centered_fixed_rect, format_number_with_commas, humanize_date, humanize_number, parse_simple_markdown};
+use super::utils::{
+    centered_fixed_rect, claimed_status_span, format_number_with_commas, humanize_date,
+    humanize_number, nav_keys_label, parse_simple_markdown, submolt_label, verified_badge_span,
+};
 
 use ratatui::{
     layout::{Alignment, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
@@ -46,13 +52,87 @@ pub fn render_spinner(frame: &mut Frame, app: &App) {
     frame.render_widget(spinner_widget, popup_area);
 }
 
-pub fn render_help(frame: &mut Frame) {
+/// Draw the current status message over the footer's text line
+pub fn render_status_message(frame: &mut Frame, message: &str) {
     let area = frame.area();
-    let popup_area = centered_fixed_rect(45, 26, area);
+    if area.height < 3 || area.width < 4 {
+        return;
+    }
 
-    frame.render_widget(Clear, popup_area);
+    let status_area = Rect::new(area.x + 1, area.y + area.height - 2, area.width - 2, 1);
+    frame.render_widget(Clear, status_area);
+
+    let status = Paragraph::new(Line::from(Span::styled(
+        format!(" {} ", message),
+        Style::default()
+            .fg(MOLTBOOK_YELLOW)
+            .add_modifier(Modifier::BOLD),
+    )))
+    .alignment(Alignment::Center);
+
+    frame.render_widget(status, status_area);
+}
+
+/// Shortcuts for the current screen, laid out in columns just above the footer
+pub fn render_cheatsheet(frame: &mut Frame, app: &App) {
+    const COLUMN_WIDTH: usize = 28;
+    const KEY_WIDTH: usize = 9;
+    const DESCRIPTION_WIDTH: usize = COLUMN_WIDTH - KEY_WIDTH - 1;
+
+    let shortcuts = shortcuts_for(&app.screen, &app.keymap);
+    let area = frame.area();
+    if shortcuts.is_empty() || area.height < 8 || area.width < 4 {
+        return;
+    }
+
+    let inner_width = area.width.saturating_sub(4) as usize;
+    let columns = (inner_width / COLUMN_WIDTH).max(1);
+    let rows = shortcuts.len().div_ceil(columns);
+
+    let lines: Vec<Line> = (0..rows)
+        .map(|row| {
+            let mut spans = Vec::new();
+            for (key, description) in shortcuts.iter().skip(row * columns).take(columns) {
+                spans.push(Span::styled(
+                    format!(" {:<width$}", key, width = KEY_WIDTH),
+                    Style::default().fg(MOLTBOOK_TEAL),
+                ));
+                spans.push(Span::styled(
+                    format!("{:<width$}", description, width = DESCRIPTION_WIDTH),
+                    Style::default().fg(Color::Gray),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect();
 
-    let help_text = vec![
+    // Sit on top of the 3-line footer, never covering more than half the screen
+    let height = (rows as u16 + 2).min(area.height / 2);
+    let cheatsheet_area = Rect::new(
+        area.x,
+        area.y + area.height - 3 - height,
+        area.width,
+        height,
+    );
+    frame.render_widget(Clear, cheatsheet_area);
+
+    let cheatsheet = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Keys (H to hide, ? for full help) ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(MOLTBOOK_TEAL)),
+    );
+    frame.render_widget(cheatsheet, cheatsheet_area);
+}
+
+/// A help row for `keys`, relabelled for the configured keymap
+fn help_row(app: &App, keys: &str, what: &str) -> Line<'static> {
+    Line::from(format!("  {:<9} {}", app.keymap.label(keys), what))
+}
+
+/// Keybinding list shown in the help overlay
+fn help_lines(app: &App) -> Vec<Line<'static>> {
+    vec![
         Line::from(Span::styled(
             "Keybindings",
             Style::default()
@@ -60,44 +140,119 @@ pub fn render_help(frame: &mut Frame) {
                 .add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
-        Line::from("  j / ↓     Move down"),
-        Line::from("  k / ↑     Move up"),
-        Line::from("  Enter     Open post"),
-        Line::from("  Esc       Go back"),
+        help_row(app, "j / ↓", "Move down"),
+        help_row(app, "k / ↑", "Move up"),
+        help_row(app, "gg / G", "Jump to first / last"),
+        help_row(app, "Enter", "Open post"),
+        help_row(app, "Esc", "Go back"),
         Line::from(""),
         Line::from(Span::styled(
             "  Sorting",
             Style::default().fg(MOLTBOOK_TEAL),
         )),
-        Line::from("  n         Sort by New"),
-        Line::from("  t         Sort by Top"),
-        Line::from("  d         Sort by Discussed"),
-        Line::from("  R         Sort by Random"),
-        Line::from("  f / ←/→   Cycle time filter"),
+        help_row(app, "n", "Sort by New"),
+        help_row(app, "t", "Sort by Top"),
+        help_row(app, "d", "Sort by Discussed"),
+        help_row(app, "R", "Sort by Random"),
+        help_row(app, "f / ←/→", "Cycle time filter"),
+        help_row(app, "F", "Following feed (API key)"),
+        help_row(app, "/", "Search loaded posts"),
+        help_row(app, "i", "Reverse sort (this page)"),
+        help_row(app, "U", "Show link post domains"),
+        help_row(app, "v", "Split view / comment tree view"),
+        help_row(app, "A", "Only posts by this author"),
+        help_row(app, "[ / ]", "Min score filter (feed, comments)"),
+        Line::from(""),
+        help_row(app, "r", "Refresh"),
+        help_row(app, "o", "Open in browser"),
+        help_row(app, "y", "Copy link to selected item"),
+        help_row(app, "L", "Copy feed link / comment links"),
+        help_row(app, "w", "Export loaded posts (JSON)"),
+        help_row(app, "I", "Copy selected item's ID"),
+        help_row(app, "!", "Report post (API key)"),
+        help_row(app, "D", "Cycle color depth"),
+        help_row(app, "M", "Save feed preset"),
+        help_row(app, "m", "Open preset / leaderboard metric"),
+        help_row(app, "#", "Jump to a feed page"),
+        help_row(app, "p", "Pin submolt / collapse post"),
+        help_row(app, "g", "Jump to submolt (Submolts)"),
+        help_row(app, "'1-9", "Open pinned submolt"),
+        help_row(app, "J / K", "Next / prev post (reading)"),
+        help_row(app, "X", "Related posts (reading)"),
+        help_row(app, "b", "Bookmark post"),
+        help_row(app, "W", "Bookmarks"),
+        help_row(app, "C", "Copy comment thread"),
+        help_row(app, "T", "Top-level comments only"),
+        help_row(app, "s", "Sort agent's posts (profile)"),
+        help_row(app, "O", "Owner details (profile)"),
+        help_row(app, "z", "Hide header (post, profile)"),
+        help_row(app, "Z", "Focus mode (post)"),
+        help_row(app, "Tab", "Preview sidebar / scroll post body"),
+        help_row(app, "PgDn/PgUp", "Page through post body"),
+        help_row(app, "u", "Hide unclaimed agents"),
+        help_row(app, "a", "Toggle auto-refresh"),
+        help_row(app, "+/-", "Adjust refresh interval"),
+        help_row(app, "B", "Fast refresh for 2 min"),
+        help_row(app, "N", "Next page"),
+        help_row(app, "P", "Previous page"),
+        help_row(app, &nav_keys_label(app), "Navigate screens"),
+        help_row(app, "`", "Toggle debug panel"),
+        help_row(app, "~", "Debug panel log level"),
+        help_row(app, "@", "Switch API profile"),
+        help_row(app, "S", "Save screenshot (ANSI text)"),
+        help_row(app, "V", "Recently viewed posts/agents"),
+        help_row(app, "H", "Toggle key cheatsheet"),
+        help_row(app, "?", "Toggle help"),
+        help_row(app, "q", "Quit"),
         Line::from(""),
-        Line::from("  r         Refresh"),
-        Line::from("  o         Open in browser"),
-        Line::from("  a         Toggle auto-refresh"),
-        Line::from("  +/-       Adjust refresh interval"),
-        Line::from("  N         Next page"),
-        Line::from("  P         Previous page"),
-        Line::from("  1-8       Navigate screens"),
-        Line::from("  `         Toggle debug panel"),
-        Line::from("  ?         Toggle help"),
-        Line::from("  q         Quit"),
+        Line::from(Span::styled(
+            "  Post age (feed)",
+            Style::default().fg(MOLTBOOK_TEAL),
+        )),
+        Line::from(vec![
+            Span::styled("  < 1 hour", Style::default().fg(MOLTBOOK_YELLOW)),
+            Span::raw("  "),
+            Span::styled("< 1 day", Style::default().fg(Color::White)),
+            Span::raw("  "),
+            Span::styled("older", Style::default().fg(Color::DarkGray)),
+        ]),
         Line::from(""),
         Line::from(Span::styled(
-            "Press ? to close",
+            format!("Press {} to close", app.keymap.label("?")),
             Style::default().fg(Color::DarkGray),
         )),
-    ];
+    ]
+}
 
-    let help_widget = Paragraph::new(help_text).block(
-        Block::default()
-            .title(" Help ")
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(MOLTBOOK_RED)),
-    );
+/// Where the help overlay is drawn: as tall as its text, within the screen
+fn help_area(app: &App, area: Rect) -> Rect {
+    centered_fixed_rect(50, help_lines(app).len() as u16 + 2, area)
+}
+
+/// Furthest the help overlay can scroll on the current terminal
+pub fn help_max_scroll(app: &App) -> u16 {
+    let (width, height) = app.last_frame_area.unwrap_or((80, 24));
+    let popup_area = help_area(app, Rect::new(0, 0, width, height));
+    (help_lines(app).len() as u16).saturating_sub(popup_area.height.saturating_sub(2))
+}
+
+pub fn render_help(frame: &mut Frame, app: &App) {
+    let popup_area = help_area(app, frame.area());
+    let max_scroll = help_max_scroll(app);
+
+    frame.render_widget(Clear, popup_area);
+
+    let mut block = Block::default()
+        .title(" Help ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(MOLTBOOK_RED));
+    // Short terminals can't fit the whole list, so it scrolls
+    if max_scroll > 0 {
+        block = block.title_bottom(Line::from(app.keymap.hint(" j/k: Scroll ")).right_aligned());
+    }
+    let help_widget = Paragraph::new(help_lines(app))
+        .block(block)
+        .scroll((app.help_scroll.min(max_scroll), 0));
 
     frame.render_widget(help_widget, popup_area);
 }
@@ -118,10 +273,27 @@ pub fn render_error(frame: &mut Frame, app: &App) {
     let display_text = if app.show_technical_error {
         error.clone()
     } else {
-        "Something went wrong.".to_string()
+        match app.error_kind {
+            ErrorKind::Auth => "Your API key was rejected (HTTP 401/403).".to_string(),
+            ErrorKind::Network => "Can't reach Moltbook. Check your connection.".to_string(),
+            ErrorKind::NotFound => "That doesn't exist (HTTP 404).".to_string(),
+            ErrorKind::Other => "Something went wrong.".to_string(),
+        }
     };
 
-    let help_text = "Esc: dismiss    e: toggle details    r: retry";
+    let dismiss_key = app.keymap.label("Esc");
+    let help_text = if app.error_kind == ErrorKind::Auth {
+        format!(
+            "{}: dismiss    e: toggle details    s: re-enter key",
+            dismiss_key
+        )
+    } else {
+        format!(
+            "{}: dismiss    e: toggle details    {}: retry",
+            dismiss_key,
+            app.keymap.label("r")
+        )
+    };
 
     let text = vec![
         Line::from(""),
@@ -162,30 +334,44 @@ pub fn render_debug(frame: &mut Frame, app: &App) {
 
     frame.render_widget(Clear, debug_area);
 
-    // Show last N debug entries that fit
+    // Show last N debug entries at or above the minimum level that fit
     let available_lines = debug_area.height.saturating_sub(2) as usize; // -2 for borders
-    let start_idx = app.debug_log.len().saturating_sub(available_lines);
-    let visible_logs: Vec<Line> = app
+    let entries: Vec<&DebugEntry> = app
         .debug_log
+        .iter()
+        .filter(|entry| entry.level >= app.debug_min_level)
+        .collect();
+    let start_idx = entries.len().saturating_sub(available_lines);
+    let visible_logs: Vec<Line> = entries
         .iter()
         .skip(start_idx)
-        .map(|msg| {
-            let style = if msg.contains("ERROR") {
-                Style::default().fg(Color::Red)
-            } else if msg.contains("OK:") {
-                Style::default().fg(Color::Green)
-            } else if msg.contains("GET") {
-                Style::default().fg(Color::Yellow)
-            } else {
-                Style::default().fg(Color::White)
+        .map(|entry| {
+            let color = match entry.level {
+                LogLevel::Error => Color::Red,
+                LogLevel::Warn => Color::Yellow,
+                LogLevel::Info => Color::Green,
+                LogLevel::Debug => Color::White,
             };
-            Line::from(Span::styled(msg.as_str(), style))
+            Line::from(vec![
+                Span::styled(
+                    format!("[{}] ", entry.timestamp.format("%H:%M:%S%.3f")),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(
+                    format!("{:<5} ", entry.level.as_str()),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(entry.message.as_str(), Style::default().fg(color)),
+            ])
         })
         .collect();
 
     let debug_widget = Paragraph::new(visible_logs).block(
         Block::default()
-            .title(" Debug (` to close) ")
+            .title(format!(
+                " Debug ≥ {} (~ level, ` to close) ",
+                app.debug_min_level.as_str()
+            ))
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::Magenta))
             .style(Style::default().bg(Color::Black)),
@@ -194,6 +380,292 @@ pub fn render_debug(frame: &mut Frame, app: &App) {
     frame.render_widget(debug_widget, debug_area);
 }
 
+/// Reason picker shown before reporting a post
+pub fn render_report_modal(frame: &mut Frame, app: &App) {
+    let Some((_, ref title)) = app.report_target else {
+        return;
+    };
+
+    let height = app.report_reasons.len() as u16 + 6;
+    let area = centered_fixed_rect(50, height, frame.area());
+    frame.render_widget(Clear, area);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!(" {}", title),
+            Style::default().fg(Color::White),
+        )),
+        Line::from(""),
+    ];
+    for (i, reason) in app.report_reasons.iter().enumerate() {
+        let (marker, style) = if i == app.report_reason_selected {
+            (
+                "▶ ",
+                Style::default()
+                    .fg(MOLTBOOK_TEAL)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            ("  ", Style::default().fg(Color::Gray))
+        };
+        lines.push(Line::from(Span::styled(
+            format!(" {}{}", marker, reason),
+            style,
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        app.keymap
+            .hint(" j/k: choose • Enter: report • Esc: cancel"),
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let modal = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Report post ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(MOLTBOOK_RED)),
+    );
+    frame.render_widget(modal, area);
+}
+
+/// Prompt for the name of a new feed preset
+pub fn render_preset_name_prompt(frame: &mut Frame, app: &App) {
+    let Some(ref name) = app.preset_name_input else {
+        return;
+    };
+
+    let area = centered_fixed_rect(50, 7, frame.area());
+    frame.render_widget(Clear, area);
+
+    let lines = vec![
+        Line::from(Span::styled(
+            format!(" {}", app.current_preset(String::new()).describe()),
+            Style::default().fg(Color::Gray),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(" Name: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(format!("{}█", name), Style::default().fg(Color::White)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            " Enter: save • Esc: cancel",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let modal = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Save view as preset ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(MOLTBOOK_TEAL)),
+    );
+    frame.render_widget(modal, area);
+}
+
+/// Cross-posts and related discussions of the open post
+pub fn render_related_posts(frame: &mut Frame, app: &App) {
+    let area = centered_fixed_rect(72, 18, frame.area());
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(" Related posts ")
+        .title_bottom(Line::from(Span::styled(
+            app.keymap.hint(" j/k: choose • Enter: open • Esc: close "),
+            Style::default().fg(Color::DarkGray),
+        )))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(MOLTBOOK_TEAL));
+
+    if app.related_posts.is_empty() {
+        let message = if app.is_loading {
+            "  Loading..."
+        } else {
+            "  No related posts"
+        };
+        let lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(message, Style::default().fg(Color::Gray))),
+        ];
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .related_posts
+        .iter()
+        .enumerate()
+        .map(|(i, post)| {
+            let is_selected = i == app.related_selected;
+            let marker = if is_selected { "▶ " } else { "  " };
+            let title_style = if is_selected {
+                Style::default()
+                    .fg(MOLTBOOK_TEAL)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            let author = post
+                .author
+                .as_ref()
+                .map(|a| format!(" • u/{}", a.name))
+                .unwrap_or_default();
+            ListItem::new(vec![
+                Line::from(Span::styled(
+                    format!(" {}{}", marker, post.title),
+                    title_style,
+                )),
+                Line::from(Span::styled(
+                    format!(
+                        "     {}{} • ↑ {}",
+                        submolt_label(post),
+                        author,
+                        post.score()
+                    ),
+                    Style::default().fg(Color::DarkGray),
+                )),
+            ])
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(app.related_selected));
+    frame.render_stateful_widget(List::new(items).block(block), area, &mut state);
+}
+
+/// Prompt for a feed page number to jump to
+pub fn render_page_prompt(frame: &mut Frame, app: &App) {
+    let Some(ref input) = app.page_input else {
+        return;
+    };
+
+    let area = centered_fixed_rect(36, 5, frame.area());
+    frame.render_widget(Clear, area);
+
+    let lines = vec![
+        Line::from(vec![
+            Span::styled(" Page: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(format!("{}█", input), Style::default().fg(Color::White)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            " Enter: go • Esc: cancel",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let modal = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Jump to page ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(MOLTBOOK_TEAL)),
+    );
+    frame.render_widget(modal, area);
+}
+
+/// Submolt quick-switcher: the typed prefix and the first few matching names
+pub fn render_submolt_jump(frame: &mut Frame, app: &App) {
+    const MAX_MATCHES: usize = 8;
+
+    let Some(ref input) = app.submolt_jump_input else {
+        return;
+    };
+
+    let matches = app.submolt_jump_matches();
+    let area = centered_fixed_rect(40, MAX_MATCHES as u16 + 7, frame.area());
+    frame.render_widget(Clear, area);
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(" m/", Style::default().fg(Color::DarkGray)),
+            Span::styled(format!("{}█", input), Style::default().fg(Color::White)),
+        ]),
+        Line::from(""),
+    ];
+    if matches.is_empty() {
+        lines.push(Line::from(Span::styled(
+            " No matching submolts",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for (i, &index) in matches.iter().take(MAX_MATCHES).enumerate() {
+        let name = &app.submolts[index].name;
+        lines.push(if i == 0 {
+            Line::from(Span::styled(
+                format!(" ▶ m/{}", name),
+                Style::default()
+                    .fg(MOLTBOOK_TEAL)
+                    .add_modifier(Modifier::BOLD),
+            ))
+        } else {
+            Line::from(Span::styled(
+                format!("   m/{}", name),
+                Style::default().fg(Color::White),
+            ))
+        });
+    }
+    if matches.len() > MAX_MATCHES {
+        lines.push(Line::from(Span::styled(
+            format!("   … {} more", matches.len() - MAX_MATCHES),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " Enter: select • Esc: cancel",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let modal = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Jump to submolt ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(MOLTBOOK_TEAL)),
+    );
+    frame.render_widget(modal, area);
+}
+
+/// Saved feed presets to switch to
+pub fn render_preset_picker(frame: &mut Frame, app: &App) {
+    let height = app.presets.len() as u16 + 4;
+    let area = centered_fixed_rect(60, height, frame.area());
+    frame.render_widget(Clear, area);
+
+    let mut lines = Vec::new();
+    for (i, preset) in app.presets.iter().enumerate() {
+        let is_selected = i == app.preset_selected;
+        let marker = if is_selected { "▶ " } else { "  " };
+        let name_style = if is_selected {
+            Style::default()
+                .fg(MOLTBOOK_TEAL)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {}{}", marker, preset.name), name_style),
+            Span::styled(
+                format!("  {}", preset.describe()),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        app.keymap.hint(" j/k: choose • Enter: open • Esc: cancel"),
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let modal = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Presets ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(MOLTBOOK_TEAL)),
+    );
+    frame.render_widget(modal, area);
+}
+
 pub fn render_submolt_detail_modal(frame: &mut Frame, app: &App) {
     if !app.show_submolt_detail || app.submolts.is_empty() {
         return;
@@ -250,8 +722,12 @@ pub fn render_agent_preview_sidebar(frame: &mut Frame, app: &App) {
     let area = frame.area();
 
     // Calculate content area (same as screen layouts)
-    // Header: 11 lines, Footer: 3 lines
-    let header_height = 11;
+    // Header: 11 lines (13 plus any banner on the feed), Footer: 3 lines
+    let header_height = if app.screen == Screen::Feed {
+        13 + app.announcement_banner_height()
+    } else {
+        11
+    };
     let footer_height = 3;
     let content_y = header_height;
     let content_height = area.height.saturating_sub(header_height + footer_height);
@@ -303,20 +779,32 @@ pub fn render_agent_preview_sidebar(frame: &mut Frame, app: &App) {
             .description
             .as_deref()
             .unwrap_or("No description");
-        let claimed = if profile.is_claimed {
-            ""
-        } else {
-            " (unclaimed)"
-        };
+        let verified = profile
+            .owner
+            .as_ref()
+            .and_then(|o| o.x_verified)
+            .unwrap_or(false);
         let owner_info = profile
             .owner
             .as_ref()
             .and_then(|o| o.x_handle.as_ref())
             .map(|h| format!("@{}", h))
             .unwrap_or_default();
+        let rank = app
+            .karma_rank_label(&profile.name)
+            .map(|label| format!(" ({})", label))
+            .unwrap_or_default();
 
-        let mut content = vec![
-            Line::from(""),
+        let mut content = vec![Line::from("")];
+        // The avatar needs its 16 columns plus a margin; narrower sidebars stay text-only
+        if let Some(avatar) = app
+            .preview_avatar()
+            .filter(|_| u32::from(inner.width) >= avatar::SIZE + 2)
+        {
+            content.extend(avatar.lines(1));
+            content.push(Line::from(""));
+        }
+        content.extend([
             Line::from(vec![
                 Span::styled(" Karma: ", Style::default().fg(Color::DarkGray)),
                 Span::styled(
@@ -325,6 +813,7 @@ pub fn render_agent_preview_sidebar(frame: &mut Frame, app: &App) {
                         .fg(MOLTBOOK_YELLOW)
                         .add_modifier(Modifier::BOLD),
                 ),
+                Span::styled(rank, Style::default().fg(Color::DarkGray)),
             ]),
             Line::from(vec![
                 Span::styled(" Followers: ", Style::default().fg(Color::DarkGray)),
@@ -341,7 +830,7 @@ pub fn render_agent_preview_sidebar(frame: &mut Frame, app: &App) {
                 ),
             ]),
             Line::from(""),
-        ];
+        ]);
 
         // Add description paragraphs with spacing between them
         for (i, paragraph) in desc.split("\n\n").enumerate() {
@@ -359,8 +848,9 @@ pub fn render_agent_preview_sidebar(frame: &mut Frame, app: &App) {
         content.push(Line::from(""));
         content.push(Line::from(vec![
             Span::styled(" Owner: ", Style::default().fg(Color::DarkGray)),
+            verified_badge_span(app, verified),
             Span::styled(owner_info, Style::default().fg(MOLTBOOK_TEAL)),
-            Span::styled(claimed, Style::default().fg(Color::DarkGray)),
+            claimed_status_span(profile.is_claimed),
         ]));
         content.push(Line::from(vec![
             Span::styled(" Joined: ", Style::default().fg(Color::DarkGray)),
@@ -400,6 +890,94 @@ pub fn render_agent_preview_sidebar(frame: &mut Frame, app: &App) {
     frame.render_widget(paragraph, inner);
 }
 
+pub fn render_submolt_feed_preview_sidebar(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+
+    // Same placement as the agent preview: right 40% of the content area
+    let header_height = 11;
+    let footer_height = 3;
+    let content_height = area.height.saturating_sub(header_height + footer_height);
+    let sidebar_width = (area.width * 40) / 100;
+    let sidebar_area = Rect::new(
+        area.width.saturating_sub(sidebar_width),
+        header_height,
+        sidebar_width,
+        content_height,
+    );
+
+    frame.render_widget(Clear, sidebar_area);
+
+    let title = app
+        .submolt_preview_name
+        .as_ref()
+        .map(|n| format!(" m/{} ", n))
+        .unwrap_or_else(|| " Submolt Preview ".to_string());
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(MOLTBOOK_RED))
+        .title(title)
+        .title_style(
+            Style::default()
+                .fg(MOLTBOOK_TEAL)
+                .add_modifier(Modifier::BOLD),
+        )
+        .style(Style::default().bg(Color::Black));
+
+    let inner = block.inner(sidebar_area);
+    frame.render_widget(block, sidebar_area);
+
+    if app.is_preview_loading {
+        let spinner_char = SPINNER_FRAMES[app.spinner_frame];
+        let vertical_padding = inner.height.saturating_sub(1) / 2;
+        let mut lines: Vec<Line> = (0..vertical_padding).map(|_| Line::from("")).collect();
+        lines.push(Line::from(Span::styled(
+            format!("{} Loading...", spinner_char),
+            Style::default().fg(MOLTBOOK_TEAL),
+        )));
+        frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), inner);
+        return;
+    }
+
+    let mut content = vec![Line::from("")];
+    if app.submolt_preview_posts.is_empty() {
+        content.push(Line::from(Span::styled(
+            " No posts yet",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for post in &app.submolt_preview_posts {
+        content.push(Line::from(vec![
+            Span::raw(" "),
+            Span::styled(post.title.clone(), Style::default().fg(Color::White)),
+        ]));
+        let author = post
+            .author
+            .as_ref()
+            .map(|a| format!("u/{} • ", a.name))
+            .unwrap_or_default();
+        content.push(Line::from(Span::styled(
+            format!(
+                "   {}{} • ↑ {} • {} comments",
+                author,
+                humanize_date(&post.created_at),
+                post.score(),
+                post.comment_count
+            ),
+            Style::default().fg(Color::DarkGray),
+        )));
+        content.push(Line::from(""));
+    }
+    content.push(Line::from(Span::styled(
+        " Tab: Close   Enter: Open Feed",
+        Style::default()
+            .fg(Color::DarkGray)
+            .add_modifier(Modifier::ITALIC),
+    )));
+
+    frame.render_widget(Paragraph::new(content).wrap(Wrap { trim: true }), inner);
+}
+
 pub fn render_about(frame: &mut Frame) {
     let area = frame.area();
     // Center in content area (accounting for header and footer)
@@ -482,7 +1060,7 @@ pub fn render_about(frame: &mut Frame) {
         )),
         Line::from(""),
         Line::from(Span::styled(
-            "Press '8' or Esc to close",
+            "Press Esc to close",
             Style::default().fg(Color::DarkGray),
         )),
     ]);
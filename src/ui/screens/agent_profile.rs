@@ -4,7 +4,10 @@ use crate::app::{App, Screen};
 use crate::ui::colors::{MOLTBOOK_RED, MOLTBOOK_TEAL, MOLTBOOK_YELLOW};
 use crate::ui::header::render_shared_header;
 use crate::ui::overlays::render_error;
-use crate::ui::utils::{format_number_with_commas, humanize_date, parse_simple_markdown};
+use crate::ui::utils::{
+    claimed_status_span, format_number_with_commas, humanize_date, parse_simple_markdown,
+    submolt_label, verified_badge_span,
+};
 
 use ratatui::{
     layout::{Constraint, Direction, Layout, Margin, Rect},
@@ -14,28 +17,27 @@ use ratatui::{
     Frame,
 };
 
-pub fn render_agent_profile(frame: &mut Frame, app: &App) {
+pub fn render_agent_profile(frame: &mut Frame, app: &mut App) {
+    let header_height = app.visible_header_height(11);
+    let info_height = if app.show_owner_details { 14 } else { 10 };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(11), // Header
-            Constraint::Length(10), // Agent info
-            Constraint::Min(0),     // Posts list
-            Constraint::Length(3),  // Footer
+            Constraint::Length(header_height), // Header
+            Constraint::Length(info_height),   // Agent info
+            Constraint::Min(0),                // Posts list
+            Constraint::Length(3),             // Footer
         ])
         .split(frame.area());
 
     // Header with ANSI logo and nav tabs
-    let header = Paragraph::new(render_shared_header(Screen::AgentProfile, app)).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(MOLTBOOK_RED)),
-    );
-    frame.render_widget(header, chunks[0]);
+    if !app.reading_mode {
+        render_shared_header(frame, app, Screen::AgentProfile, chunks[0]);
+    }
 
     // Agent info section
     if let Some(ref profile) = app.agent_profile {
-        render_agent_info(frame, profile, chunks[1]);
+        render_agent_info(frame, app, profile, chunks[1]);
     } else {
         let loading = Paragraph::new("Loading agent profile...")
             .style(Style::default().fg(Color::DarkGray))
@@ -52,13 +54,15 @@ pub fn render_agent_profile(frame: &mut Frame, app: &App) {
     render_agent_posts(frame, app, chunks[2]);
 
     // Footer
-    let footer = Paragraph::new("j/k: Nav • Enter: Open Post • r: Refresh • Esc: Back • ?: Help")
-        .style(Style::default().fg(Color::DarkGray))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(MOLTBOOK_RED)),
-        );
+    let footer = Paragraph::new(app.keymap.hint(
+        "j/k: Nav • Enter: Open Post • O: Owner • z: Header • r: Refresh • Esc: Back • ?: Help",
+    ))
+    .style(Style::default().fg(Color::DarkGray))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(MOLTBOOK_RED)),
+    );
     frame.render_widget(footer, chunks[3]);
 
     if app.error_message.is_some() {
@@ -66,17 +70,11 @@ pub fn render_agent_profile(frame: &mut Frame, app: &App) {
     }
 }
 
-fn render_agent_info(frame: &mut Frame, profile: &AgentProfile, area: Rect) {
+fn render_agent_info(frame: &mut Frame, app: &App, profile: &AgentProfile, area: Rect) {
     let desc = profile
         .description
         .as_deref()
         .unwrap_or("No description");
-    let claimed = if profile.is_claimed {
-        ""
-    } else {
-        " (unclaimed)"
-    };
-
     let owner_info = profile
         .owner
         .as_ref()
@@ -89,7 +87,7 @@ fn render_agent_info(frame: &mut Frame, profile: &AgentProfile, area: Rect) {
         .as_ref()
         .and_then(|o| o.x_verified)
         .unwrap_or(false);
-    let verified_badge = if verified { " ★" } else { "" };
+    let verified_badge = verified_badge_span(app, verified);
 
     let post_count = profile
         .post_count
@@ -112,7 +110,7 @@ fn render_agent_info(frame: &mut Frame, profile: &AgentProfile, area: Rect) {
             Constraint::Length(2), // Username line + empty line
             Constraint::Min(1),    // Description (flexible, wraps)
             Constraint::Length(1), // Spacer
-            Constraint::Length(2), // Stats + Owner lines
+            Constraint::Length(if app.show_owner_details { 6 } else { 2 }), // Stats + Owner lines
         ])
         .split(inner_area);
 
@@ -124,7 +122,7 @@ fn render_agent_info(frame: &mut Frame, profile: &AgentProfile, area: Rect) {
                 .fg(MOLTBOOK_TEAL)
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::styled(claimed, Style::default().fg(Color::DarkGray)),
+        claimed_status_span(profile.is_claimed),
     ]);
     let username_widget = Paragraph::new(username_line);
     frame.render_widget(username_widget, chunks[0]);
@@ -136,8 +134,14 @@ fn render_agent_info(frame: &mut Frame, profile: &AgentProfile, area: Rect) {
     let desc_widget = Paragraph::new(desc_line).wrap(Wrap { trim: false });
     frame.render_widget(desc_widget, chunks[1]);
 
+    // Leaderboard position, once the leaderboard has been loaded
+    let rank = app
+        .karma_rank_label(&profile.name)
+        .map(|label| format!(" ({})", label))
+        .unwrap_or_default();
+
     // Stats and owner lines
-    let footer_lines = vec![
+    let mut footer_lines = vec![
         Line::from(vec![
             Span::styled(
                 format!("  ↑ {} karma", format_number_with_commas(profile.karma)),
@@ -145,6 +149,7 @@ fn render_agent_info(frame: &mut Frame, profile: &AgentProfile, area: Rect) {
                     .fg(MOLTBOOK_YELLOW)
                     .add_modifier(Modifier::BOLD),
             ),
+            Span::styled(rank, Style::default().fg(Color::DarkGray)),
             Span::styled("  │  ", Style::default().fg(Color::DarkGray)),
             Span::styled(
                 format!("{} followers", format_number_with_commas(profile.follower_count)),
@@ -164,7 +169,7 @@ fn render_agent_info(frame: &mut Frame, profile: &AgentProfile, area: Rect) {
         Line::from(""),  // Blank line for spacing
         Line::from(vec![
             Span::styled("  Owner: ", Style::default().fg(Color::DarkGray)),
-            Span::styled(verified_badge, Style::default().fg(MOLTBOOK_TEAL)),
+            verified_badge,
             Span::styled(owner_info, Style::default().fg(MOLTBOOK_TEAL)),
             Span::styled("  │  Joined: ", Style::default().fg(Color::DarkGray)),
             Span::styled(
@@ -173,23 +178,62 @@ fn render_agent_info(frame: &mut Frame, profile: &AgentProfile, area: Rect) {
             ),
         ]),
     ];
+    if app.show_owner_details {
+        footer_lines.extend(owner_detail_lines(app, profile));
+    }
     let footer_widget = Paragraph::new(footer_lines);
     frame.render_widget(footer_widget, chunks[3]);
 }
 
+/// Expanded owner panel: X display name, follower count and verification
+fn owner_detail_lines(app: &App, profile: &AgentProfile) -> Vec<Line<'static>> {
+    let label_style = Style::default().fg(Color::DarkGray);
+
+    let Some(ref owner) = profile.owner else {
+        return vec![
+            Line::from(""),
+            Line::from(Span::styled("  No human owner", label_style)),
+        ];
+    };
+
+    let x_name = owner
+        .x_name
+        .clone()
+        .unwrap_or_else(|| "Unknown".to_string());
+    let followers = owner
+        .x_follower_count
+        .map(format_number_with_commas)
+        .unwrap_or_else(|| "?".to_string());
+    let verified = owner.x_verified.unwrap_or(false);
+    let status = if verified { "Verified" } else { "Not verified" };
+
+    vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  X name: ", label_style),
+            Span::styled(x_name, Style::default().fg(Color::White)),
+        ]),
+        Line::from(vec![
+            Span::styled("  X followers: ", label_style),
+            Span::styled(followers, Style::default().fg(Color::White)),
+        ]),
+        Line::from(vec![
+            Span::styled("  X account: ", label_style),
+            verified_badge_span(app, verified),
+            Span::styled(status, Style::default().fg(MOLTBOOK_TEAL)),
+        ]),
+    ]
+}
+
 fn render_agent_posts(frame: &mut Frame, app: &App, area: Rect) {
     let items: Vec<ListItem> = app
-        .agent_posts
-        .iter()
+        .sorted_agent_posts()
+        .into_iter()
         .enumerate()
         .map(|(i, post)| {
             let is_selected = i == app.agent_posts_selected;
 
-            let submolt = post
-                .submolt
-                .as_ref()
-                .map(|s| format!("m/{}", s.name))
-                .unwrap_or_else(|| "m/unknown".to_string());
+            let submolt = submolt_label(post);
 
             let title_style = if is_selected {
                 Style::default()
@@ -227,12 +271,20 @@ fn render_agent_posts(frame: &mut Frame, app: &App, area: Rect) {
                 ),
             ]);
 
-            ListItem::new(vec![line, meta, Line::from("")])
+            let mut lines = vec![line, meta];
+            if !app.dense_spacing {
+                lines.push(Line::from(""));
+            }
+            ListItem::new(lines)
         })
         .collect();
 
     let posts_block = Block::default()
-        .title(format!(" Posts ({}) ", app.agent_posts.len()))
+        .title(format!(
+            " Posts ({}) • {} (s) ",
+            app.agent_posts.len(),
+            app.agent_posts_sort.as_str()
+        ))
         .borders(Borders::ALL)
         .border_style(Style::default().fg(MOLTBOOK_RED));
 
@@ -0,0 +1,30 @@
+use crate::app::{App, Screen};
+
+use super::saved_list::{post_row, render_saved_list};
+
+use ratatui::Frame;
+
+pub fn render_bookmarks(frame: &mut Frame, app: &mut App) {
+    let rows = app
+        .bookmarks
+        .iter()
+        .enumerate()
+        .map(|(i, post)| post_row(post, i == app.bookmarks_selected, true))
+        .collect();
+
+    let title = if app.bookmarks.is_empty() {
+        "Bookmarks - press b on a post to save it here".to_string()
+    } else {
+        format!("Bookmarks ({})", app.bookmarks.len())
+    };
+    let selected = app.bookmarks_selected;
+    render_saved_list(
+        frame,
+        app,
+        Screen::Bookmarks,
+        rows,
+        selected,
+        title,
+        "j/k: Nav • Enter: Open • b: Remove • Esc: Back • ?: Help",
+    );
+}
@@ -1,22 +1,28 @@
-use crate::api::SortOrder;
+use crate::api::{Post, SortOrder};
 use crate::app::{App, Screen};
-use crate::config::RowDisplay;
+use crate::config::{FeedColumn, RowDisplay};
 
 use crate::ui::colors::{MOLTBOOK_BLUE, MOLTBOOK_RED, MOLTBOOK_TEAL, MOLTBOOK_YELLOW};
 use crate::ui::fonts::SPINNER_FRAMES;
-use crate::ui::header::{render_nav_tabs_line, render_sort_tabs_line, LOGO_ART};
+use crate::ui::header::{
+    render_nav_tabs_line, render_sort_tabs_line, tagline_line, TabLine, LOGO_ART, NAV_TABS_ROW,
+};
 use crate::ui::overlays::render_error;
-use crate::ui::utils::{format_count, format_number_with_commas, humanize_date};
+use crate::ui::screens::post_detail::post_text_lines;
+use crate::ui::utils::{
+    fit_to_width, format_count, format_number_with_commas, humanize_date_with_color,
+    humanize_elapsed, read_time_label, render_empty_list, submolt_label, url_domain, wrap_text,
+};
 
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Margin},
+    layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
     Frame,
 };
 
-pub fn render_feed(frame: &mut Frame, app: &App) {
+pub fn render_feed(frame: &mut Frame, app: &mut App) {
     // Time filter is shown inline with sort tabs (except for New sort)
     let header_height = 13;
 
@@ -24,6 +30,7 @@ pub fn render_feed(frame: &mut Frame, app: &App) {
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(header_height),
+            Constraint::Length(app.announcement_banner_height()),
             Constraint::Min(0),
             Constraint::Length(3),
         ])
@@ -60,22 +67,34 @@ pub fn render_feed(frame: &mut Frame, app: &App) {
             ),
             Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
             Span::styled(
-                format!("{} agents", format_count(stats.agents)),
+                format!(
+                    "{} agents",
+                    format_count(stats.agents, app.compact_numbers_threshold)
+                ),
                 Style::default().fg(MOLTBOOK_RED),
             ),
             Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
             Span::styled(
-                format!("{} submolts", format_count(stats.submolts)),
+                format!(
+                    "{} submolts",
+                    format_count(stats.submolts, app.compact_numbers_threshold)
+                ),
                 Style::default().fg(MOLTBOOK_TEAL),
             ),
             Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
             Span::styled(
-                format!("{} posts", format_count(stats.posts)),
+                format!(
+                    "{} posts",
+                    format_count(stats.posts, app.compact_numbers_threshold)
+                ),
                 Style::default().fg(MOLTBOOK_BLUE),
             ),
             Span::styled(" │ ", Style::default().fg(Color::DarkGray)),
             Span::styled(
-                format!("{} comments", format_count(stats.comments)),
+                format!(
+                    "{} comments",
+                    format_count(stats.comments, app.compact_numbers_threshold)
+                ),
                 Style::default().fg(MOLTBOOK_YELLOW),
             ),
         ])
@@ -108,7 +127,8 @@ pub fn render_feed(frame: &mut Frame, app: &App) {
     } else {
         None
     };
-    let sort_tabs_line = render_sort_tabs_line(app.sort_order, time_filter_opt);
+    let (sort_tabs_line, sort_hitboxes) =
+        render_sort_tabs_line(app.sort_order, time_filter_opt, app.sort_reversed);
 
     // ANSI block art logo
     let mut logo_lines: Vec<Line> = LOGO_ART
@@ -123,37 +143,48 @@ pub fn render_feed(frame: &mut Frame, app: &App) {
         })
         .collect();
 
-    logo_lines.push(Line::from(Span::styled(
-        " the front page of the agent internet",
-        Style::default().fg(Color::DarkGray),
-    )));
+    logo_lines.push(tagline_line(app));
     logo_lines.push(Line::from(""));
     logo_lines.push(stats_line);
     logo_lines.push(Line::from(""));
-    logo_lines.push(render_nav_tabs_line(Screen::Feed));
+    let (nav_tabs_line, nav_hitboxes) = render_nav_tabs_line(Screen::Feed, &app.nav_tabs);
+    logo_lines.push(nav_tabs_line);
     logo_lines.push(Line::from(""));
     logo_lines.push(sort_tabs_line);
 
-    let header = Paragraph::new(logo_lines).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(MOLTBOOK_RED)),
-    );
-    frame.render_widget(header, chunks[0]);
+    let header_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(MOLTBOOK_RED));
+    // Remember where the tabs were drawn so clicks hit the same columns
+    app.nav_tab_line = TabLine::in_block(&header_block, chunks[0], NAV_TABS_ROW, nav_hitboxes);
+    app.sort_tab_line =
+        TabLine::in_block(&header_block, chunks[0], NAV_TABS_ROW + 2, sort_hitboxes);
+    frame.render_widget(Paragraph::new(logo_lines).block(header_block), chunks[0]);
 
-    // Posts list
-    let posts: Vec<ListItem> = app
-        .posts
+    if let Some(announcement) = &app.announcement {
+        let banner = Paragraph::new(Line::from(Span::styled(
+            announcement.as_str(),
+            Style::default().fg(MOLTBOOK_YELLOW),
+        )))
+        .block(
+            Block::default()
+                .title(" Announcement (Esc to dismiss) ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(MOLTBOOK_YELLOW)),
+        );
+        frame.render_widget(banner, chunks[1]);
+    }
+
+    // Posts list, with the selected post's preview beside it in split view
+    let (list_area, preview_area) = feed_panes(app, chunks[2]);
+    let visible_posts = app.visible_posts();
+    let posts: Vec<ListItem> = visible_posts
         .iter()
         .enumerate()
         .map(|(i, post)| {
             let is_selected = i == app.selected_index;
 
-            let submolt = post
-                .submolt
-                .as_ref()
-                .map(|s| format!("m/{}", s.name))
-                .unwrap_or_else(|| "m/unknown".to_string());
+            let submolt = submolt_label(post);
 
             let title_style = if is_selected {
                 Style::default()
@@ -170,98 +201,197 @@ pub fn render_feed(frame: &mut Frame, app: &App) {
                 Style::default().fg(Color::DarkGray)
             };
 
-            let line = Line::from(vec![
-                Span::raw("  "),
-                Span::styled(&post.title, title_style),
-            ]);
-
-            // Build meta spans dynamically, only including author if present
-            let mut meta_spans = vec![
-                Span::raw("    "),
-                Span::styled(submolt, Style::default().fg(MOLTBOOK_TEAL)),
-            ];
-            if let Some(ref author) = post.author {
-                meta_spans.push(Span::styled(" • ", meta_style));
-                meta_spans.push(Span::styled(format!("u/{}", author.name), meta_style));
+            if app.row_display == RowDisplay::Dense {
+                return ListItem::new(dense_row_line(
+                    app,
+                    post,
+                    list_area.width,
+                    title_style,
+                    meta_style,
+                ));
+            }
+
+            let mut title_lines: Vec<Line> = Vec::new();
+            for (row, text) in feed_title_lines(app, post, list_area.width)
+                .into_iter()
+                .enumerate()
+            {
+                let mut title_spans = vec![Span::raw("  ")];
+                if app.is_bookmarked(&post.id) {
+                    let marker = if row == 0 { "★ " } else { "  " };
+                    title_spans.push(Span::styled(marker, Style::default().fg(MOLTBOOK_YELLOW)));
+                }
+                title_spans.push(Span::styled(text, title_style));
+                title_lines.push(Line::from(title_spans));
+            }
+
+            // Build meta spans from the configured columns, skipping any
+            // the post has no value for (no author, too short for a read time)
+            let mut meta_spans = vec![Span::raw("    ")];
+            for column in &app.feed_columns {
+                let span = match column {
+                    FeedColumn::Submolt => Some(Span::styled(
+                        submolt.clone(),
+                        Style::default().fg(MOLTBOOK_TEAL),
+                    )),
+                    FeedColumn::Author => post
+                        .author
+                        .as_ref()
+                        .map(|author| Span::styled(format!("u/{}", author.name), meta_style)),
+                    FeedColumn::Date => {
+                        let (age, color) = humanize_date_with_color(&post.created_at);
+                        let style = color.map_or(meta_style, |c| Style::default().fg(c));
+                        Some(Span::styled(age, style))
+                    }
+                    FeedColumn::Score => Some(Span::styled(
+                        format!("{} pts", format_number_with_commas(post.score())),
+                        meta_style,
+                    )),
+                    FeedColumn::Comments => Some(Span::styled(
+                        format!("{} comments", format_number_with_commas(post.comment_count)),
+                        meta_style,
+                    )),
+                    FeedColumn::ReadTime => read_time_label(post)
+                        .filter(|_| app.show_read_time)
+                        .map(|read_time| Span::styled(read_time, meta_style)),
+                };
+                if let Some(span) = span {
+                    if meta_spans.len() > 1 {
+                        meta_spans.push(Span::styled(" • ", meta_style));
+                    }
+                    meta_spans.push(span);
+                }
+            }
+            if let Some(domain) = post
+                .url
+                .as_deref()
+                .filter(|_| app.show_link_domains)
+                .and_then(url_domain)
+            {
+                meta_spans.push(Span::styled(format!(" ({})", domain), meta_style));
             }
-            meta_spans.push(Span::styled(" • ", meta_style));
-            meta_spans.push(Span::styled(humanize_date(&post.created_at), meta_style));
-            meta_spans.push(Span::styled(" • ", meta_style));
-            meta_spans.push(Span::styled(
-                format!("{} pts", format_number_with_commas(post.score())),
-                meta_style,
-            ));
-            meta_spans.push(Span::styled(" • ", meta_style));
-            meta_spans.push(Span::styled(
-                format!("{} comments", format_number_with_commas(post.comment_count)),
-                meta_style,
-            ));
             let meta = Line::from(meta_spans);
 
             // Build lines based on row_display setting
-            let lines = match app.row_display {
-                RowDisplay::Compact => vec![line, meta], // Title + meta (no extra spacing)
-                RowDisplay::Normal => vec![line, meta], // Title + meta
-                RowDisplay::Comfortable => vec![line, meta, Line::from("")], // Title + meta + blank
-            };
+            let mut lines = title_lines;
+            lines.push(meta);
+            if app.row_display == RowDisplay::Comfortable {
+                lines.push(Line::from("")); // Title + meta + blank
+            }
             ListItem::new(lines)
         })
         .collect();
 
     // Build title - show submolt name when filtering
-    let posts_title = if let Some(ref submolt) = app.current_submolt {
+    let mut posts_title = if let Some(ref submolt) = app.current_submolt {
         format!("m/{} ({})", submolt.name, app.sort_display())
+    } else if app.following_only {
+        format!("Following ({})", app.sort_display())
     } else {
         format!("Posts ({})", app.sort_display())
     };
+    if let Some(ref author) = app.author_filter {
+        posts_title.push_str(&format!(" • by u/{} (Esc to clear)", author));
+    }
+    if app.search_active || !app.search_query.is_empty() {
+        let cursor = if app.search_active { "█" } else { "" };
+        posts_title.push_str(&format!(
+            " • /{}{} ({} matches, Esc to clear)",
+            app.search_query,
+            cursor,
+            visible_posts.len()
+        ));
+    }
+    let hidden_count = app.posts.len() - visible_posts.len();
+    if hidden_count > 0 {
+        posts_title.push_str(&format!(" • {} hidden", hidden_count));
+    }
 
     let posts_block = Block::default()
         .title(posts_title)
         .borders(Borders::ALL)
         .border_style(Style::default().fg(MOLTBOOK_RED));
 
-    let posts_list = List::new(posts)
-        .block(posts_block)
-        .highlight_style(Style::default().bg(Color::Rgb(30, 30, 30)));
+    // Placeholder rows until the first page of posts arrives
+    if app.is_loading && app.posts.is_empty() {
+        render_skeleton_rows(frame, app, posts_block, list_area);
+    } else if visible_posts.is_empty() && !app.posts.is_empty() {
+        render_filtered_empty(frame, app, posts_block, list_area);
+    } else if app.posts.is_empty() && app.current_page > 0 && !app.is_loading {
+        render_page_empty(frame, app, posts_block, list_area);
+    } else if let Some(submolt) = app
+        .current_submolt
+        .as_ref()
+        .filter(|_| app.posts.is_empty() && !app.is_loading && app.error_message.is_none())
+    {
+        render_submolt_empty(frame, &submolt.name, posts_block, list_area);
+    } else if app.following_only && app.posts.is_empty() && !app.is_loading {
+        render_empty_list(
+            frame,
+            posts_block,
+            list_area,
+            "No posts from agents you follow",
+            "Press F or Esc for all posts",
+        );
+    } else if app.posts.is_empty() && !app.is_loading {
+        render_empty_list(
+            frame,
+            posts_block,
+            list_area,
+            "No posts",
+            "Press r to retry",
+        );
+    } else {
+        let posts_list = List::new(posts)
+            .block(posts_block)
+            .highlight_style(Style::default().bg(Color::Rgb(30, 30, 30)));
 
-    let mut list_state = ListState::default();
-    list_state.select(Some(app.selected_index));
-    frame.render_stateful_widget(posts_list, chunks[1], &mut list_state);
+        let mut list_state = ListState::default();
+        list_state.select(Some(app.selected_index));
+        frame.render_stateful_widget(posts_list, list_area, &mut list_state);
 
-    // Render scrollbar if there are more posts than visible
-    let posts_area = chunks[1];
-    let visible_height = posts_area.height.saturating_sub(2); // subtract borders
-    let item_height = match app.row_display {
-        RowDisplay::Compact => 2u16,
-        RowDisplay::Normal => 2u16,
-        RowDisplay::Comfortable => 3u16,
-    };
-    let visible_items = (visible_height / item_height) as usize;
-    let total_items = app.posts.len();
-
-    if total_items > visible_items {
-        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
-            .begin_symbol(Some("▲"))
-            .end_symbol(Some("▼"));
-
-        let mut scrollbar_state = ScrollbarState::new(total_items).position(app.selected_index);
-
-        frame.render_stateful_widget(
-            scrollbar,
-            posts_area.inner(Margin {
-                horizontal: 0,
-                vertical: 1,
-            }),
-            &mut scrollbar_state,
-        );
+        // Render scrollbar if there are more posts than visible
+        let posts_area = list_area;
+        let visible_height = posts_area.height.saturating_sub(2); // subtract borders
+        let total_height: u16 = visible_posts
+            .iter()
+            .map(|post| feed_row_height(app, post, posts_area.width))
+            .sum();
+        let total_items = visible_posts.len();
+
+        if total_height > visible_height {
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(Some("▲"))
+                .end_symbol(Some("▼"));
+
+            let mut scrollbar_state = ScrollbarState::new(total_items).position(app.selected_index);
+
+            frame.render_stateful_widget(
+                scrollbar,
+                posts_area.inner(Margin {
+                    horizontal: 0,
+                    vertical: 1,
+                }),
+                &mut scrollbar_state,
+            );
+        }
+    }
+
+    if let Some(area) = preview_area {
+        render_post_preview(frame, visible_posts.get(app.selected_index).copied(), area);
     }
 
     // Footer with keybindings, page info, and refresh countdown
-    let page_indicator = if app.has_more_posts {
-        format!("Page {} (more)", app.current_page + 1)
+    let pages = if app.first_page < app.current_page {
+        format!("Pages {}-{}", app.first_page + 1, app.current_page + 1)
     } else {
         format!("Page {}", app.current_page + 1)
     };
+    let page_indicator = if app.has_more_posts {
+        format!("{} (more)", pages)
+    } else {
+        pages
+    };
     let countdown = app.seconds_until_refresh();
     let is_refreshing = app.is_loading && app.is_background_loading;
 
@@ -270,45 +400,301 @@ pub fn render_feed(frame: &mut Frame, app: &App) {
             format!("{} ", SPINNER_FRAMES[app.spinner_frame]),
             "Refreshing...".to_string(),
         )
-    } else if app.refresh_interval_secs == 0 {
+    } else if app.refresh_interval_secs == 0 && !app.is_fast_refresh() {
         ("  ".to_string(), "Refresh Off".to_string())
+    } else if app.is_modal_open() {
+        ("  ".to_string(), "Refresh paused".to_string())
     } else if countdown == 0 {
         (
             format!("{} ", SPINNER_FRAMES[app.spinner_frame]),
             "Refreshing...".to_string(),
         )
+    } else if app.is_fast_refresh() {
+        ("  ".to_string(), format!("FAST {:>2}s (B)", countdown))
     } else {
-        ("  ".to_string(), format!("Refresh {:>2}s", countdown))
+        let idle = if app.is_idle() && app.idle_refresh_multiplier > 1 {
+            " (idle)"
+        } else {
+            ""
+        };
+        (
+            "  ".to_string(),
+            format!("Refresh {:>2}s{}", countdown, idle),
+        )
     };
 
-    let refresh_color = if app.refresh_interval_secs > 0 {
+    // How stale the posts are; the only freshness cue with auto-refresh off
+    let updated_text = match app.last_refresh {
+        Some(instant) => format!(
+            " • updated {}",
+            humanize_elapsed(instant.elapsed().as_secs() as i64)
+        ),
+        None => " • updating…".to_string(),
+    };
+
+    let refresh_color = if app.is_modal_open() && !is_refreshing {
+        Color::DarkGray
+    } else if app.is_fast_refresh() {
+        MOLTBOOK_YELLOW
+    } else if app.refresh_interval_secs > 0 {
         MOLTBOOK_TEAL
     } else {
         Color::DarkGray
     };
 
     // Build footer with optional submolt hint
-    let nav_hint = if app.current_submolt.is_some() {
+    let nav_hint = if app.current_submolt.is_some() || app.following_only {
         format!(" j/k: Nav • Esc: All posts • ?: Help • {} • ", page_indicator)
     } else {
         format!(" j/k: Nav • N/P: Page • ?: Help • {} • ", page_indicator)
     };
+    let nav_hint = app.keymap.hint(&nav_hint);
 
-    let footer_line = Line::from(vec![
+    let mut footer_spans = vec![
         Span::styled(spinner_text, Style::default().fg(MOLTBOOK_TEAL)),
         Span::styled(nav_hint, Style::default().fg(Color::DarkGray)),
         Span::styled(refresh_text, Style::default().fg(refresh_color)),
+        Span::styled(updated_text, Style::default().fg(Color::DarkGray)),
         Span::styled(" • +/-: interval", Style::default().fg(Color::DarkGray)),
-    ]);
+    ];
+    if app.min_score > 0 {
+        footer_spans.push(Span::styled(" • ", Style::default().fg(Color::DarkGray)));
+        footer_spans.push(Span::styled(
+            format!("Score ≥ {} ([/])", app.min_score),
+            Style::default().fg(MOLTBOOK_YELLOW),
+        ));
+    }
+    let footer_line = Line::from(footer_spans);
     let footer = Paragraph::new(footer_line).block(
         Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(MOLTBOOK_RED)),
     );
-    frame.render_widget(footer, chunks[2]);
+    frame.render_widget(footer, chunks[3]);
 
     // Error message overlay
     if app.error_message.is_some() {
         render_error(frame, app);
     }
 }
+
+/// Shown after jumping past the last page of posts
+fn render_page_empty(frame: &mut Frame, app: &App, block: Block, area: Rect) {
+    let lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("  Page {} is empty", app.current_page + 1),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            "  There are fewer posts than that. Press P for the previous page or # to jump to another.",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Shown when a submolt's feed comes back empty, which is also what an
+/// unknown submolt name looks like
+fn render_submolt_empty(frame: &mut Frame, name: &str, block: Block, area: Rect) {
+    let lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("  No posts in m/{}", name),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            "  This submolt is empty or doesn't exist. Press Esc for the main feed.",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Shown when posts are loaded but every one is hidden by client-side
+/// filters, so it isn't mistaken for an empty feed
+fn render_filtered_empty(frame: &mut Frame, app: &App, block: Block, area: Rect) {
+    let filters = app.active_feed_filters();
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("  No posts match your filters ({} active)", filters.len()),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    for filter in &filters {
+        lines.push(Line::from(Span::styled(
+            format!("    • {}", filter),
+            Style::default().fg(MOLTBOOK_TEAL),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!(
+            "  {} posts loaded on this page. Clear a filter or press N for the next page.",
+            app.posts.len()
+        ),
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Split view needs this many columns; narrower terminals get the list alone
+const SPLIT_VIEW_MIN_WIDTH: u16 = 100;
+
+/// The posts list's area within `area`, and the preview pane's when split
+/// view is on and the terminal is wide enough for it
+pub fn feed_panes(app: &App, area: Rect) -> (Rect, Option<Rect>) {
+    if !app.split_view || area.width < SPLIT_VIEW_MIN_WIDTH {
+        return (area, None);
+    }
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    (panes[0], Some(panes[1]))
+}
+
+/// The selected post's title, byline and body for split view. Comments
+/// aren't fetched until the post is opened
+fn render_post_preview(frame: &mut Frame, post: Option<&Post>, area: Rect) {
+    let block = Block::default()
+        .title(" Preview ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(MOLTBOOK_RED));
+    let inner = block.inner(area).inner(Margin {
+        horizontal: 1,
+        vertical: 0,
+    });
+    frame.render_widget(block, area);
+
+    let Some(post) = post else {
+        return;
+    };
+    let mut lines = post_text_lines(post, inner.width as usize);
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!(
+            "{} comments • Enter to open",
+            format_number_with_commas(post.comment_count)
+        ),
+        Style::default()
+            .fg(Color::DarkGray)
+            .add_modifier(Modifier::ITALIC),
+    )));
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// A post's title wrapped to the feed list's width (`list_width`, borders
+/// included), at most two lines; a longer title ends the second line in "…"
+fn feed_title_lines(app: &App, post: &Post, list_width: u16) -> Vec<String> {
+    // Borders, the indent, and the bookmark marker's column
+    let marker_width = if app.is_bookmarked(&post.id) { 2 } else { 0 };
+    let width = (list_width as usize).saturating_sub(4 + marker_width).max(1);
+
+    let mut lines = wrap_text(&post.title, width);
+    if lines.len() > 2 {
+        lines.truncate(2);
+        let second: String = lines[1].chars().take(width.saturating_sub(1)).collect();
+        lines[1] = format!("{}…", second.trim_end());
+    }
+    lines
+}
+
+/// A post on one line for the Dense row display: the title, cut and padded
+/// so a short `m/sub ↑score 💬count` summary lines up at the right edge
+fn dense_row_line(
+    app: &App,
+    post: &Post,
+    list_width: u16,
+    title_style: Style,
+    meta_style: Style,
+) -> Line<'static> {
+    const MIN_TITLE_WIDTH: usize = 12;
+
+    let submolt = submolt_label(post);
+    let counts = format!(
+        " ↑{} 💬{}",
+        format_number_with_commas(post.score()),
+        format_number_with_commas(post.comment_count)
+    );
+    let marker = if app.is_bookmarked(&post.id) {
+        "★ "
+    } else {
+        ""
+    };
+    // Borders, the indent, the marker and the gap before the summary
+    let summary_width = Span::raw(format!("{}{}", submolt, counts)).width();
+    let width = (list_width as usize)
+        .saturating_sub(4 + Span::raw(marker).width() + 2 + summary_width)
+        .max(MIN_TITLE_WIDTH);
+
+    Line::from(vec![
+        Span::raw("  "),
+        Span::styled(marker, Style::default().fg(MOLTBOOK_YELLOW)),
+        Span::styled(fit_to_width(&post.title, width), title_style),
+        Span::raw("  "),
+        Span::styled(submolt, Style::default().fg(MOLTBOOK_TEAL)),
+        Span::styled(counts, meta_style),
+    ])
+}
+
+/// Height of a post's row in the feed list, for scrolling and mouse clicks
+pub fn feed_row_height(app: &App, post: &Post, list_width: u16) -> u16 {
+    if app.row_display == RowDisplay::Dense {
+        return 1;
+    }
+    let spacer = u16::from(app.row_display == RowDisplay::Comfortable);
+    feed_title_lines(app, post, list_width).len() as u16 + 1 + spacer
+}
+
+/// Dim placeholder bars shaped like post rows, shown while the feed is empty and loading
+fn render_skeleton_rows(frame: &mut Frame, app: &App, block: Block, area: Rect) {
+    // Vary the bar widths a little so the rows don't look like a grid
+    const TITLE_WIDTHS: [usize; 5] = [42, 30, 48, 36, 24];
+    const META_WIDTHS: [usize; 5] = [28, 34, 22, 30, 26];
+
+    let item_height = match app.row_display {
+        RowDisplay::Dense => 1,
+        RowDisplay::Compact | RowDisplay::Normal => 2,
+        RowDisplay::Comfortable => 3,
+    };
+    let row_count = (area.height.saturating_sub(2) / item_height) as usize;
+    let max_width = area.width.saturating_sub(8) as usize;
+    let bar_style = Style::default().fg(Color::Rgb(50, 50, 50));
+
+    let mut lines: Vec<Line> = Vec::new();
+    for i in 0..row_count {
+        let title_width = TITLE_WIDTHS[i % TITLE_WIDTHS.len()].min(max_width);
+        let meta_width = META_WIDTHS[i % META_WIDTHS.len()].min(max_width);
+        lines.push(Line::from(vec![
+            Span::raw("  "),
+            Span::styled("▒".repeat(title_width), bar_style),
+        ]));
+        if app.row_display == RowDisplay::Dense {
+            continue;
+        }
+        lines.push(Line::from(vec![
+            Span::raw("    "),
+            Span::styled("▒".repeat(meta_width), bar_style),
+        ]));
+        if app.row_display == RowDisplay::Comfortable {
+            lines.push(Line::from(""));
+        }
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
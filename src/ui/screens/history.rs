@@ -0,0 +1,38 @@
+use crate::app::{App, HistoryEntry, Screen};
+
+use super::saved_list::{post_row, render_saved_list, text_row};
+
+use ratatui::Frame;
+
+pub fn render_history(frame: &mut Frame, app: &mut App) {
+    // Newest first
+    let rows = app
+        .history_newest_first()
+        .enumerate()
+        .map(|(i, entry)| {
+            let is_selected = i == app.history_selected;
+            match entry {
+                HistoryEntry::Post(post) => post_row(post, is_selected, false),
+                HistoryEntry::Agent(name) => {
+                    text_row(format!("u/{}", name), "Agent profile", is_selected)
+                }
+            }
+        })
+        .collect();
+
+    let title = if app.history.is_empty() {
+        "History - posts and profiles you open show up here".to_string()
+    } else {
+        format!("History ({})", app.history.len())
+    };
+    let selected = app.history_selected;
+    render_saved_list(
+        frame,
+        app,
+        Screen::History,
+        rows,
+        selected,
+        title,
+        "j/k: Nav • Enter: Open • Esc: Back • ?: Help",
+    );
+}
@@ -1,10 +1,13 @@
-use crate::app::{App, Screen};
+use crate::app::{App, LeaderboardMetric, Screen};
 
 use crate::ui::colors::{MOLTBOOK_RED, MOLTBOOK_TEAL, MOLTBOOK_YELLOW};
-use crate::ui::fonts::render_figlet_name;
+use crate::ui::fonts::{figlet_available, render_figlet_name};
 use crate::ui::header::render_shared_header;
 use crate::ui::overlays::render_error;
-use crate::ui::utils::format_number_with_commas;
+use crate::ui::utils::{
+    claimed_status_span, format_number_with_commas, nav_keys_label, render_empty_list,
+    verified_badge_span,
+};
 
 use ratatui::{
     layout::{Constraint, Direction, Layout},
@@ -14,7 +17,7 @@ use ratatui::{
     Frame,
 };
 
-pub fn render_leaderboard(frame: &mut Frame, app: &App) {
+pub fn render_leaderboard(frame: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -25,35 +28,36 @@ pub fn render_leaderboard(frame: &mut Frame, app: &App) {
         .split(frame.area());
 
     // Header with ANSI logo and nav tabs
-    let header = Paragraph::new(render_shared_header(Screen::Leaderboard, app)).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(MOLTBOOK_RED)),
-    );
-    frame.render_widget(header, chunks[0]);
+    render_shared_header(frame, app, Screen::Leaderboard, chunks[0]);
+
+    // Rank movement is only meaningful once there's a previous load to compare
+    // against, and only for the API's own karma ranking
+    let metric = app.leaderboard_metric;
+    let has_previous =
+        !app.previous_leaderboard_ranks.is_empty() && metric == LeaderboardMetric::Karma;
+    let highlight_changes = app.leaderboard_highlight_active();
 
     // Leaderboard list
     let items: Vec<ListItem> = app
-        .leaderboard
-        .iter()
+        .visible_leaderboard()
+        .into_iter()
         .enumerate()
         .map(|(i, agent)| {
             let is_selected = i == app.leaderboard_selected;
+            let rank = app.leaderboard_display_rank(i, agent);
 
-            let rank_color = match agent.rank {
+            let rank_color = match rank {
                 1 => Color::Rgb(255, 215, 0),   // Gold
                 2 => Color::Rgb(192, 192, 192), // Silver
                 3 => Color::Rgb(205, 127, 50),  // Bronze
                 _ => Color::DarkGray,
             };
 
-            let rank_style = Style::default()
-                .fg(rank_color)
-                .add_modifier(if agent.rank <= 3 {
-                    Modifier::BOLD
-                } else {
-                    Modifier::empty()
-                });
+            let rank_style = Style::default().fg(rank_color).add_modifier(if rank <= 3 {
+                Modifier::BOLD
+            } else {
+                Modifier::empty()
+            });
 
             let name_style = if is_selected {
                 Style::default()
@@ -68,7 +72,7 @@ pub fn render_leaderboard(frame: &mut Frame, app: &App) {
                 .as_ref()
                 .and_then(|o| o.x_verified)
                 .unwrap_or(false);
-            let verified_badge = if verified { " ★" } else { "" };
+            let verified_badge = verified_badge_span(app, verified);
 
             let x_handle = agent
                 .owner
@@ -77,12 +81,56 @@ pub fn render_leaderboard(frame: &mut Frame, app: &App) {
                 .map(|h| format!("@{}", h))
                 .unwrap_or_default();
 
-            let claimed = if agent.is_claimed { "" } else { " (unclaimed)" };
+            // Movement since the last load: ▲/▼ with delta, or NEW for entrants
+            let movement = if has_previous {
+                match app.previous_leaderboard_ranks.get(&agent.id) {
+                    None => Some(Span::styled(
+                        "  NEW",
+                        Style::default()
+                            .fg(MOLTBOOK_TEAL)
+                            .add_modifier(Modifier::BOLD),
+                    )),
+                    Some(&prev) if prev > agent.rank => Some(Span::styled(
+                        format!("  ▲ {}", prev - agent.rank),
+                        Style::default().fg(Color::Green),
+                    )),
+                    Some(&prev) if prev < agent.rank => Some(Span::styled(
+                        format!("  ▼ {}", agent.rank - prev),
+                        Style::default().fg(MOLTBOOK_RED),
+                    )),
+                    Some(_) => None,
+                }
+            } else {
+                None
+            };
+            let row_style = if highlight_changes && movement.is_some() {
+                Style::default().bg(Color::Rgb(40, 40, 15))
+            } else {
+                Style::default()
+            };
 
-            // Blank line for visual spacing
-            let blank_line = Line::from(vec![Span::raw("")]);
+            let mut karma_spans = vec![
+                Span::raw("     "),
+                Span::styled(
+                    format!("↑ {} karma", format_number_with_commas(agent.karma)),
+                    Style::default().fg(MOLTBOOK_YELLOW),
+                ),
+            ];
+            if metric != LeaderboardMetric::Karma {
+                let value = match metric.value(agent) {
+                    Some(n) => format!(
+                        " • {} {}",
+                        format_number_with_commas(n),
+                        metric.as_str().to_lowercase()
+                    ),
+                    None => format!(" • {} n/a", metric.as_str().to_lowercase()),
+                };
+                karma_spans.push(Span::styled(value, Style::default().fg(MOLTBOOK_TEAL)));
+            }
+            karma_spans.extend(movement);
 
-            if agent.rank <= 3 {
+            // Block-letter names for the top 3, unless the font failed to load
+            if rank <= 3 && figlet_available() {
                 // pixeloidbold at 0.5x scale (matches bit tool output)
                 let figlet_lines = render_figlet_name(&agent.name, 12, true);
 
@@ -91,12 +139,14 @@ pub fn render_leaderboard(frame: &mut Frame, app: &App) {
                 let mut lines = Vec::new();
 
                 // Add top padding before each top-3 entry
-                lines.push(Line::from(vec![Span::raw("")]));
+                if !app.dense_spacing {
+                    lines.push(Line::from(vec![Span::raw("")]));
+                }
 
                 // First line: rank + first figlet line
                 if let Some(first) = figlet_lines.first() {
                     lines.push(Line::from(vec![
-                        Span::styled(format!(" {:>2}  ", agent.rank), rank_style),
+                        Span::styled(format!(" {:>2}  ", rank), rank_style),
                         Span::styled(first.clone(), art_style),
                     ]));
                 }
@@ -112,31 +162,27 @@ pub fn render_leaderboard(frame: &mut Frame, app: &App) {
                 // Handle line (on its own for vertical centering)
                 lines.push(Line::from(vec![
                     Span::raw("     "),
-                    Span::styled(verified_badge, Style::default().fg(MOLTBOOK_TEAL)),
-                    Span::styled(x_handle.clone(), Style::default().fg(MOLTBOOK_TEAL)),
-                    Span::styled(claimed, Style::default().fg(MOLTBOOK_TEAL)),
+                    verified_badge,
+                    Span::styled(x_handle, Style::default().fg(MOLTBOOK_TEAL)),
+                    claimed_status_span(agent.is_claimed),
                 ]));
 
                 // Karma line
-                lines.push(Line::from(vec![
-                    Span::raw("     "),
-                    Span::styled(
-                        format!("↑ {} karma", format_number_with_commas(agent.karma)),
-                        Style::default().fg(MOLTBOOK_YELLOW),
-                    ),
-                ]));
+                lines.push(Line::from(karma_spans));
 
                 // Blank line for top 3 spacing
-                lines.push(blank_line);
+                if !app.dense_spacing {
+                    lines.push(Line::from(vec![Span::raw("")]));
+                }
 
-                ListItem::new(lines)
+                ListItem::new(lines).style(row_style)
             } else {
                 // Regular text rendering for ranks 4-10
                 let rank_star = "";
 
                 // Line 1: Name only
                 let name_line = Line::from(vec![
-                    Span::styled(format!(" {:>2}  ", agent.rank), rank_style),
+                    Span::styled(format!(" {:>2}  ", rank), rank_style),
                     Span::styled(rank_star, rank_style),
                     Span::styled(&agent.name, name_style),
                 ]);
@@ -144,46 +190,64 @@ pub fn render_leaderboard(frame: &mut Frame, app: &App) {
                 // Line 2: Handle in teal
                 let handle_line = Line::from(vec![
                     Span::raw("     "),
-                    Span::styled(verified_badge, Style::default().fg(MOLTBOOK_TEAL)),
-                    Span::styled(x_handle.clone(), Style::default().fg(MOLTBOOK_TEAL)),
-                    Span::styled(claimed, Style::default().fg(MOLTBOOK_TEAL)),
+                    verified_badge,
+                    Span::styled(x_handle, Style::default().fg(MOLTBOOK_TEAL)),
+                    claimed_status_span(agent.is_claimed),
                 ]);
 
                 // Line 3: Karma in gold
-                let karma_line = Line::from(vec![
-                    Span::raw("     "),
-                    Span::styled(
-                        format!("↑ {} karma", format_number_with_commas(agent.karma)),
-                        Style::default().fg(MOLTBOOK_YELLOW),
-                    ),
-                ]);
+                let karma_line = Line::from(karma_spans);
 
-                ListItem::new(vec![name_line, handle_line, karma_line, blank_line])
+                let mut lines = vec![name_line, handle_line, karma_line];
+                // Blank line for visual spacing
+                if !app.dense_spacing {
+                    lines.push(Line::from(vec![Span::raw("")]));
+                }
+                ListItem::new(lines).style(row_style)
             }
         })
         .collect();
 
     let list_block = Block::default()
-        .title(" ★ Top 10 Agents ★ ")
+        .title(format!(" ★ Top 10 Agents by {} ★ ", metric.as_str()))
         .borders(Borders::ALL)
         .border_style(Style::default().fg(MOLTBOOK_RED));
 
-    let list = List::new(items)
-        .block(list_block)
-        .highlight_style(Style::default().bg(Color::Rgb(30, 30, 30)));
+    if items.is_empty() && !app.is_loading {
+        let hint = if app.leaderboard.is_empty() {
+            "Press r to retry"
+        } else {
+            "Every agent here is unclaimed. Press u to show them"
+        };
+        render_empty_list(frame, list_block, chunks[1], "No agents to rank", hint);
+    } else {
+        let list = List::new(items)
+            .block(list_block)
+            .highlight_style(Style::default().bg(Color::Rgb(30, 30, 30)));
 
-    let mut list_state = ListState::default();
-    list_state.select(Some(app.leaderboard_selected));
-    frame.render_stateful_widget(list, chunks[1], &mut list_state);
+        let mut list_state = ListState::default();
+        list_state.select(Some(app.leaderboard_selected));
+        frame.render_stateful_widget(list, chunks[1], &mut list_state);
+    }
 
     // Footer
-    let footer = Paragraph::new("j/k: Nav • 1-7: Screens • ?: Help")
-        .style(Style::default().fg(Color::DarkGray))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(MOLTBOOK_RED)),
-        );
+    let unclaimed_hint = if app.hide_unclaimed {
+        "u: Show unclaimed"
+    } else {
+        "u: Hide unclaimed"
+    };
+    let footer = Paragraph::new(app.keymap.hint(&format!(
+        "j/k: Nav • m: Rank by {} • {} • {}: Screens • ?: Help",
+        metric.cycle_next().as_str(),
+        unclaimed_hint,
+        nav_keys_label(app)
+    )))
+    .style(Style::default().fg(Color::DarkGray))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(MOLTBOOK_RED)),
+    );
     frame.render_widget(footer, chunks[2]);
 
     if app.error_message.is_some() {
@@ -1,8 +1,11 @@
 mod agent_profile;
+mod bookmarks;
 mod feed;
+mod history;
 mod leaderboard;
 mod post_detail;
 mod recent_agents;
+mod saved_list;
 mod settings;
 mod setup;
 mod stats;
@@ -10,9 +13,11 @@ mod submolts;
 mod top_pairings;
 
 pub use agent_profile::render_agent_profile;
-pub use feed::render_feed;
+pub use bookmarks::render_bookmarks;
+pub use feed::{feed_panes, feed_row_height, render_feed};
+pub use history::render_history;
 pub use leaderboard::render_leaderboard;
-pub use post_detail::render_post_detail;
+pub use post_detail::{post_content_max_scroll, render_post_detail, thread_as_text};
 pub use recent_agents::render_recent_agents;
 pub use settings::render_settings;
 pub use setup::render_setup;
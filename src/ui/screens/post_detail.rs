@@ -1,24 +1,35 @@
-use crate::api::Comment;
-use crate::app::App;
+use crate::api::{Comment, Post};
+use crate::app::{App, CommentView};
 
 use crate::ui::colors::{MOLTBOOK_RED, MOLTBOOK_TEAL};
+use crate::ui::fonts::SPINNER_FRAMES;
 use crate::ui::overlays::render_error;
-use crate::ui::utils::format_number_with_commas;
+use crate::ui::utils::{
+    format_number_with_commas, humanize_date, read_time_label, submolt_label, wrap_text,
+};
 
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap},
     Frame,
 };
 
+/// Column width of the focus view when no `max_comment_width` is configured
+const FOCUS_COLUMN_WIDTH: u16 = 80;
+
 pub fn render_post_detail(frame: &mut Frame, app: &App) {
+    if app.focus_mode {
+        render_focus_view(frame, app);
+        return;
+    }
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3),
-            Constraint::Length(15),
+            Constraint::Length(app.visible_header_height(3)),
+            Constraint::Length(app.post_block_height()),
             Constraint::Min(0),
             Constraint::Length(3),
         ])
@@ -41,85 +52,78 @@ pub fn render_post_detail(frame: &mut Frame, app: &App) {
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(MOLTBOOK_RED)),
         );
-    frame.render_widget(header, chunks[0]);
+    if !app.reading_mode {
+        frame.render_widget(header, chunks[0]);
+    }
 
     // Post content
     if let Some(ref post) = app.current_post {
-        let submolt = post
-            .submolt
-            .as_ref()
-            .map(|s| format!("m/{}", s.name))
-            .unwrap_or_else(|| "m/unknown".to_string());
-
-        let content = post.content.clone().unwrap_or_default();
-
-        // Build author/submolt line dynamically
-        let mut info_spans: Vec<Span> = Vec::new();
-        if let Some(ref author) = post.author {
-            info_spans.push(Span::styled("by ", Style::default().fg(Color::DarkGray)));
-            info_spans.push(Span::styled(
-                format!("u/{}", author.name),
-                Style::default().fg(Color::White),
-            ));
-            info_spans.push(Span::styled(" in ", Style::default().fg(Color::DarkGray)));
-        } else {
-            info_spans.push(Span::styled("in ", Style::default().fg(Color::DarkGray)));
-        }
-        info_spans.push(Span::styled(&submolt, Style::default().fg(MOLTBOOK_TEAL)));
-        info_spans.push(Span::styled(" • ", Style::default().fg(Color::DarkGray)));
-        info_spans.push(Span::styled(
-            format!("{} upvotes", format_number_with_commas(post.score())),
-            Style::default().fg(Color::White),
-        ));
-        info_spans.push(Span::styled(" • ", Style::default().fg(Color::DarkGray)));
-        info_spans.push(Span::styled(
-            format!("{} comments", format_number_with_commas(post.comment_count)),
-            Style::default().fg(Color::White),
-        ));
-
-        // Start with metadata line
-        let mut post_lines = vec![
-            Line::from(info_spans),
-            Line::from(""), // Blank line after metadata
-        ];
+        let info_line = post_info_line(app, post);
 
-        // Split content into multiple lines preserving paragraphs
-        for line in content.lines() {
-            post_lines.push(Line::from(Span::styled(
-                line.to_string(),
-                Style::default().fg(Color::White),
-            )));
-        }
-
-        // Add URL if present
-        if let Some(ref url) = post.url {
-            post_lines.push(Line::from("")); // Blank line before URL
-            post_lines.push(Line::from(Span::styled(
-                url.clone(),
-                Style::default().fg(Color::Cyan),
-            )));
-        }
-
-        let post_widget = Paragraph::new(post_lines)
-            .block(
+        // Collapsed: only the metadata line as a summary
+        if app.post_collapsed {
+            let summary = Paragraph::new(info_line).block(
                 Block::default()
-                    .title("Post")
+                    .title("Post (p to expand)")
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(MOLTBOOK_RED)),
-            )
-            .wrap(Wrap { trim: false });
-        frame.render_widget(post_widget, chunks[1]);
+            );
+            frame.render_widget(summary, chunks[1]);
+        } else {
+            let (title, border_color) = if app.post_content_focused {
+                ("Post (Tab: comments)", MOLTBOOK_TEAL)
+            } else {
+                ("Post", MOLTBOOK_RED)
+            };
+            let inner_width = chunks[1].width.saturating_sub(2) as usize;
+            let max_scroll = post_content_max_scroll(app);
+            let scroll = app.post_content_scroll.min(max_scroll);
+
+            let post_widget = Paragraph::new(post_content_lines(info_line, post, inner_width))
+                .block(
+                    Block::default()
+                        .title(title)
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(border_color)),
+                )
+                .wrap(Wrap { trim: false })
+                .scroll((scroll.min(u16::MAX as usize) as u16, 0));
+            frame.render_widget(post_widget, chunks[1]);
+
+            // Scroll indicator when the body doesn't fit
+            if max_scroll > 0 {
+                let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                    .begin_symbol(Some("▲"))
+                    .end_symbol(Some("▼"));
+                let mut scrollbar_state = ScrollbarState::new(max_scroll + 1).position(scroll);
+                frame.render_stateful_widget(
+                    scrollbar,
+                    chunks[1].inner(Margin {
+                        horizontal: 0,
+                        vertical: 1,
+                    }),
+                    &mut scrollbar_state,
+                );
+            }
+        }
     }
 
     // Comments
     render_comments(frame, app, chunks[2]);
 
-    // Footer with refresh countdown
-    let countdown = app.seconds_until_refresh();
-    let footer_text = format!(
-        "j/k: Nav • Enter: Collapse • Esc: Back • ?: Help • Refresh {}s",
-        countdown
-    );
+    // Footer with refresh countdown, or a spinner while comments reload
+    let refresh_text = if app.is_loading && app.is_background_loading {
+        format!(
+            "{} Refreshing comments...",
+            SPINNER_FRAMES[app.spinner_frame]
+        )
+    } else {
+        format!("Refresh {}s", app.seconds_until_refresh())
+    };
+    let footer_text = app.keymap.hint(&format!(
+        "j/k: Nav • Enter: Collapse • p: Post • z: Header • Z: Focus • Esc: Back • ?: Help • {}",
+        refresh_text
+    ));
     let footer = Paragraph::new(footer_text)
         .style(Style::default().fg(Color::DarkGray))
         .block(
@@ -135,221 +139,408 @@ pub fn render_post_detail(frame: &mut Frame, app: &App) {
     }
 }
 
-/// Wraps text to fit within max_width, returning individual lines
-fn wrap_text(text: &str, max_width: usize) -> Vec<String> {
-    if max_width == 0 {
-        return vec![text.to_string()];
+/// Author, submolt, score and comment count line at the top of the post block
+fn post_info_line(app: &App, post: &Post) -> Line<'static> {
+    let mut info_spans: Vec<Span> = Vec::new();
+    if let Some(ref author) = post.author {
+        info_spans.push(Span::styled("by ", Style::default().fg(Color::DarkGray)));
+        info_spans.push(Span::styled(
+            format!("u/{}", author.name),
+            Style::default().fg(Color::White),
+        ));
+        info_spans.push(Span::styled(" in ", Style::default().fg(Color::DarkGray)));
+    } else {
+        info_spans.push(Span::styled("in ", Style::default().fg(Color::DarkGray)));
     }
+    info_spans.push(Span::styled(
+        submolt_label(post),
+        Style::default().fg(MOLTBOOK_TEAL),
+    ));
+    info_spans.push(Span::styled(" • ", Style::default().fg(Color::DarkGray)));
+    info_spans.push(Span::styled(
+        format!("{} upvotes", format_number_with_commas(post.score())),
+        Style::default().fg(Color::White),
+    ));
+    info_spans.push(Span::styled(" • ", Style::default().fg(Color::DarkGray)));
+    info_spans.push(Span::styled(
+        format!("{} comments", format_number_with_commas(post.comment_count)),
+        Style::default().fg(Color::White),
+    ));
+    if let Some(read_time) = read_time_label(post).filter(|_| app.show_read_time) {
+        info_spans.push(Span::styled(" • ", Style::default().fg(Color::DarkGray)));
+        info_spans.push(Span::styled(read_time, Style::default().fg(Color::White)));
+    }
+    Line::from(info_spans)
+}
 
-    let mut result = Vec::new();
-    for line in text.lines() {
-        if line.is_empty() {
-            result.push(String::new());
-            continue;
-        }
+/// Lines of the expanded post block: metadata, then the body wrapped to
+/// `width`, then the link. Pre-wrapping keeps the line count exact for scrolling
+fn post_content_lines(info_line: Line<'static>, post: &Post, width: usize) -> Vec<Line<'static>> {
+    let mut lines = vec![info_line, Line::from("")];
 
-        let mut current_line = String::new();
-        for word in line.split_whitespace() {
-            if current_line.is_empty() {
-                // First word - take it even if too long
-                current_line = word.to_string();
-            } else if current_line.len() + 1 + word.len() <= max_width {
-                // Word fits with space
-                current_line.push(' ');
-                current_line.push_str(word);
-            } else {
-                // Word doesn't fit, start new line
-                result.push(current_line);
-                current_line = word.to_string();
-            }
-        }
-        if !current_line.is_empty() {
-            result.push(current_line);
+    if let Some(ref content) = post.content {
+        for body_line in wrap_text(content, width) {
+            lines.push(Line::from(Span::styled(
+                body_line,
+                Style::default().fg(Color::White),
+            )));
         }
     }
 
-    if result.is_empty() {
-        result.push(String::new());
+    if let Some(ref url) = post.url {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            url.clone(),
+            Style::default().fg(Color::Cyan),
+        )));
     }
-    result
+    lines
 }
 
-fn render_comments(frame: &mut Frame, app: &App, area: Rect) {
-    let mut lines: Vec<Line> = Vec::new();
-    let selected_id = app.get_selected_comment_id();
-    let mut visible_comment_count = 0;
+/// Furthest the post block can scroll for the current post and terminal width
+pub fn post_content_max_scroll(app: &App) -> usize {
+    let Some(ref post) = app.current_post else {
+        return 0;
+    };
+    let (width, _) = app.last_frame_area.unwrap_or((80, 24));
+    let inner_width = width.saturating_sub(2) as usize;
+    let inner_height = app.post_block_height().saturating_sub(2) as usize;
+    let lines = post_content_lines(post_info_line(app, post), post, inner_width);
+    // The metadata line is the only one that may still wrap when drawn
+    let rows: usize = lines
+        .iter()
+        .map(|line| line.width().div_ceil(inner_width.max(1)).max(1))
+        .sum();
+    rows.saturating_sub(inner_height)
+}
+
+/// Area of the focus view's text column: centered, capped in width, with a
+/// one-line margin top and bottom
+fn focus_column(area: Rect, app: &App) -> Rect {
+    let max_width = app
+        .max_comment_width
+        .map_or(FOCUS_COLUMN_WIDTH, |w| w.min(u16::MAX as usize) as u16);
+    let width = area.width.saturating_sub(4).min(max_width);
+    let x = area.x + (area.width - width) / 2;
+    Rect::new(x, area.y + 1, width, area.height.saturating_sub(2))
+}
+
+/// The post as plain wrapped lines for the focus view and the feed's
+/// preview pane: title, byline, body
+pub fn post_text_lines(post: &Post, width: usize) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    for title_line in wrap_text(&post.title, width) {
+        lines.push(Line::from(Span::styled(
+            title_line,
+            Style::default()
+                .fg(MOLTBOOK_TEAL)
+                .add_modifier(Modifier::BOLD),
+        )));
+    }
+
+    let byline = match post.author {
+        Some(ref author) => format!("u/{} in {}", author.name, submolt_label(post)),
+        None => submolt_label(post),
+    };
+    lines.push(Line::from(Span::styled(
+        byline,
+        Style::default().fg(Color::DarkGray),
+    )));
+    lines.push(Line::from(""));
+
+    for body_line in wrap_text(post.content.as_deref().unwrap_or_default(), width) {
+        lines.push(Line::from(Span::styled(
+            body_line,
+            Style::default().fg(Color::White),
+        )));
+    }
+    if let Some(ref url) = post.url {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            url.clone(),
+            Style::default().fg(Color::Cyan),
+        )));
+    }
+    lines
+}
+
+/// Distraction-free view: just the post, centered in a single column
+fn render_focus_view(frame: &mut Frame, app: &App) {
+    let Some(ref post) = app.current_post else {
+        return;
+    };
+    let column = focus_column(frame.area(), app);
+    let lines = post_text_lines(post, column.width as usize);
+
+    frame.render_widget(Paragraph::new(lines), column);
+}
+
+/// Tree connector strings for a comment at `depth`: the prefix drawn from its
+/// ancestors, its own branch (`├─`/`└─`), and the continuation used for its
+/// content lines. `prefix_stack` holds whether each ancestor has more siblings.
+fn tree_connectors(
+    prefix_stack: &[bool],
+    depth: usize,
+    is_last: bool,
+) -> (String, &'static str, String) {
+    let mut prefix = String::new();
+    for has_more in prefix_stack.iter().take(depth) {
+        prefix.push_str(if *has_more { "│ " } else { "  " });
+    }
+
+    let branch = if depth == 0 {
+        ""
+    } else if is_last {
+        "└─"
+    } else {
+        "├─"
+    };
+
+    let mut continuation = prefix.clone();
+    if depth > 0 {
+        continuation.push_str(if is_last { "  " } else { "│ " });
+    }
+
+    (prefix, branch, continuation)
+}
+
+/// A comment placed in the flattened tree, with its connector strings from
+/// `tree_connectors`
+struct FlatComment<'a> {
+    comment: &'a Comment,
+    prefix: String,
+    branch: &'static str,
+    continuation: String,
+}
 
-    // Build the prefix strings for each depth level
-    // prefix_stack tracks whether we're at the last sibling at each depth
-    fn flatten_comments(
-        comments: &[Comment],
-        lines: &mut Vec<Line<'static>>,
+/// Flatten a comment tree into display order. Comments failing `shown` are
+/// left out but their replies are kept; replies of comments failing
+/// `expanded` are left out. Both the on-screen tree and the text export are
+/// built from this.
+fn flatten_comments<'a>(
+    comments: &'a [Comment],
+    shown: &dyn Fn(&Comment) -> bool,
+    expanded: &dyn Fn(&Comment) -> bool,
+) -> Vec<FlatComment<'a>> {
+    // prefix_stack tracks whether each ancestor depth has more siblings
+    fn walk<'a>(
+        comments: &'a [Comment],
         depth: usize,
-        prefix_stack: &mut Vec<bool>, // true if this depth level has more siblings
-        app: &App,
-        selected_id: &Option<String>,
-        visible_count: &mut usize,
-        available_width: usize,
+        prefix_stack: &mut Vec<bool>,
+        shown: &dyn Fn(&Comment) -> bool,
+        expanded: &dyn Fn(&Comment) -> bool,
+        out: &mut Vec<FlatComment<'a>>,
     ) {
         let len = comments.len();
         for (i, comment) in comments.iter().enumerate() {
             let is_last = i == len - 1;
-            let is_selected = selected_id.as_ref() == Some(&comment.id);
-            let is_collapsed = app.is_comment_collapsed(&comment.id);
-            let reply_count = count_total_comments(&comment.replies);
-
-            // Build the prefix for this comment
-            let mut prefix = String::new();
-            for (d, has_more) in prefix_stack.iter().enumerate() {
-                if d < depth {
-                    if *has_more {
-                        prefix.push_str("│ ");
-                    } else {
-                        prefix.push_str("  ");
-                    }
-                }
+
+            // Below the score filter: skip the comment but keep its replies
+            if !shown(comment) {
+                walk(&comment.replies, depth, prefix_stack, shown, expanded, out);
+                continue;
             }
 
-            // Add the branch character for this comment
-            let branch = if depth == 0 {
-                ""
-            } else if is_last {
-                "└─"
+            let (prefix, branch, continuation) = tree_connectors(prefix_stack, depth, is_last);
+            out.push(FlatComment {
+                comment,
+                prefix,
+                branch,
+                continuation,
+            });
+
+            if expanded(comment) && !comment.replies.is_empty() {
+                prefix_stack.push(!is_last);
+                walk(
+                    &comment.replies,
+                    depth + 1,
+                    prefix_stack,
+                    shown,
+                    expanded,
+                    out,
+                );
+                prefix_stack.pop();
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(comments, 0, &mut Vec::new(), shown, expanded, &mut out);
+    out
+}
+
+/// Render a comment tree as indented plain text using the same connectors as
+/// the on-screen tree. Collapsed state is ignored so the whole thread is included.
+fn flatten_comments_text(comments: &[Comment], out: &mut String) {
+    for flat in flatten_comments(comments, &|_| true, &|_| true) {
+        let comment = flat.comment;
+        let header = match comment.author {
+            Some(ref author) => format!("{} • ↑ {}", author.name, comment.score()),
+            None => format!("↑ {}", comment.score()),
+        };
+        out.push_str(&format!("{}{}{}\n", flat.prefix, flat.branch, header));
+        for content_line in comment.content.lines() {
+            let line = format!("{}{}", flat.continuation, content_line);
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+}
+
+/// Export a post's full comment thread as plain text, headed by the post
+/// title and URL
+pub fn thread_as_text(post: &Post, comments: &[Comment]) -> String {
+    let mut out = format!(
+        "{}\nhttps://www.moltbook.com/posts/{}\n",
+        post.title, post.id
+    );
+    if let Some(ref url) = post.url {
+        out.push_str(&format!("Link: {}\n", url));
+    }
+    out.push_str(&format!("{} comments\n\n", count_total_comments(comments)));
+
+    flatten_comments_text(comments, &mut out);
+    out
+}
+
+fn render_comments(frame: &mut Frame, app: &App, area: Rect) {
+    let mut lines: Vec<Line> = Vec::new();
+    let selected_id = app.get_selected_comment_id();
+
+    // The flat view is a single level of reply-less comments, oldest first
+    let flat: Vec<Comment>;
+    let comments = match app.comment_view {
+        CommentView::Tree => &app.comments,
+        CommentView::Flat => {
+            flat = app
+                .flat_comments()
+                .into_iter()
+                .filter(|c| app.comment_passes_filter(c))
+                .map(|c| Comment {
+                    replies: Vec::new(),
+                    ..c.clone()
+                })
+                .collect();
+            &flat
+        }
+    };
+
+    let available_width = area.width as usize;
+    let flattened = flatten_comments(
+        comments,
+        &|comment| app.comment_passes_filter(comment),
+        &|comment| !app.is_comment_collapsed(&comment.id),
+    );
+    for FlatComment {
+        comment,
+        prefix,
+        branch,
+        continuation,
+    } in flattened
+    {
+        let is_selected = selected_id.as_ref() == Some(&comment.id);
+        let is_collapsed = app.is_comment_collapsed(&comment.id);
+        let reply_count = count_total_comments(&comment.replies);
+
+        // Selection indicator
+        let selection_marker = if is_selected { "▶ " } else { "  " };
+
+        // When collapsed, show only a compact summary line
+        if is_collapsed {
+            let total_hidden = reply_count + 1; // +1 for this comment
+            let summary = if total_hidden == 1 {
+                format!("[+1 comment hidden • ↑ {}]", comment.score())
             } else {
-                "├─"
+                format!("[+{} comments hidden]", total_hidden)
             };
 
-            // Selection indicator
-            let selection_marker = if is_selected { "▶ " } else { "  " };
+            let summary_style = if is_selected {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else {
+                Style::default().fg(Color::Yellow)
+            };
 
-            // When collapsed with replies, show only a compact summary line
-            if is_collapsed && reply_count > 0 {
-                let total_hidden = count_total_comments(&comment.replies) + 1; // +1 for this comment
-                let summary = format!("[+{} comments hidden]", total_hidden);
+            let line = Line::from(vec![
+                Span::styled(selection_marker, Style::default().fg(MOLTBOOK_TEAL)),
+                Span::styled(prefix, Style::default().fg(Color::DarkGray)),
+                Span::styled(branch, Style::default().fg(Color::DarkGray)),
+                Span::styled(summary, summary_style),
+            ]);
+            lines.push(line);
+            lines.push(Line::from("")); // Spacing
+        } else {
+            // Expanded: show full comment
+            let collapse_indicator = if !comment.replies.is_empty() {
+                "[-] ".to_string()
+            } else {
+                "".to_string()
+            };
 
-                let summary_style = if is_selected {
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD | Modifier::REVERSED)
-                } else {
-                    Style::default().fg(Color::Yellow)
-                };
-
-                let line = Line::from(vec![
-                    Span::styled(selection_marker, Style::default().fg(MOLTBOOK_TEAL)),
-                    Span::styled(prefix.clone(), Style::default().fg(Color::DarkGray)),
-                    Span::styled(branch, Style::default().fg(Color::DarkGray)),
-                    Span::styled(summary, summary_style),
-                ]);
-                lines.push(line);
-                *visible_count += 1;
-                lines.push(Line::from("")); // Spacing
+            let header_style = if is_selected {
+                Style::default()
+                    .fg(MOLTBOOK_TEAL)
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED)
             } else {
-                // Expanded: show full comment
-                let collapse_indicator = if !comment.replies.is_empty() {
-                    "[-] ".to_string()
-                } else {
-                    "".to_string()
-                };
+                Style::default()
+                    .fg(MOLTBOOK_TEAL)
+                    .add_modifier(Modifier::BOLD)
+            };
 
-                let header_style = if is_selected {
-                    Style::default()
-                        .fg(MOLTBOOK_TEAL)
-                        .add_modifier(Modifier::BOLD | Modifier::REVERSED)
-                } else {
-                    Style::default()
-                        .fg(MOLTBOOK_TEAL)
-                        .add_modifier(Modifier::BOLD)
-                };
-
-                // Build header text based on whether author is present
-                let header_text = if let Some(ref author) = comment.author {
-                    format!("{} • ↑ {}", author.name, comment.score())
-                } else {
-                    format!("↑ {}", comment.score())
-                };
-
-                let header = Line::from(vec![
-                    Span::styled(selection_marker, Style::default().fg(MOLTBOOK_TEAL)),
-                    Span::styled(prefix.clone(), Style::default().fg(Color::DarkGray)),
-                    Span::styled(branch, Style::default().fg(Color::DarkGray)),
-                    Span::styled(collapse_indicator, Style::default().fg(Color::Yellow)),
-                    Span::styled(header_text, header_style),
-                ]);
-                lines.push(header);
-                *visible_count += 1;
-
-                // Build content prefix (continues the tree lines)
-                let mut content_prefix = String::from("  "); // space for selection marker
-                for (d, has_more) in prefix_stack.iter().enumerate() {
-                    if d < depth {
-                        if *has_more {
-                            content_prefix.push_str("│ ");
-                        } else {
-                            content_prefix.push_str("  ");
-                        }
-                    }
-                }
-                // Add continuation for this level
-                if depth > 0 {
-                    if is_last {
-                        content_prefix.push_str("  ");
-                    } else {
-                        content_prefix.push_str("│ ");
-                    }
-                }
+            // Build header text based on whether author is present
+            let header_text = if let Some(ref author) = comment.author {
+                format!("{} • ↑ {}", author.name, comment.score())
+            } else {
+                format!("↑ {}", comment.score())
+            };
 
-                // Calculate text width: total - prefix - 2 for border
-                let text_width = available_width
-                    .saturating_sub(content_prefix.len())
-                    .saturating_sub(2);
-
-                // Render content lines with manual wrapping
-                for content_line in comment.content.lines() {
-                    for wrapped_line in wrap_text(content_line, text_width) {
-                        lines.push(Line::from(vec![
-                            Span::styled(
-                                content_prefix.clone(),
-                                Style::default().fg(Color::DarkGray),
-                            ),
-                            Span::styled(wrapped_line, Style::default().fg(Color::White)),
-                        ]));
-                    }
+            let mut header_spans = vec![
+                Span::styled(selection_marker, Style::default().fg(MOLTBOOK_TEAL)),
+                Span::styled(prefix, Style::default().fg(Color::DarkGray)),
+                Span::styled(branch, Style::default().fg(Color::DarkGray)),
+                Span::styled(collapse_indicator, Style::default().fg(Color::Yellow)),
+                Span::styled(header_text, header_style),
+            ];
+
+            // Age goes last and is dropped when it would overflow the line
+            let age = humanize_date(&comment.created_at);
+            if !age.is_empty() {
+                let age_span =
+                    Span::styled(format!(" • {}", age), Style::default().fg(Color::DarkGray));
+                let used: usize = header_spans.iter().map(|s| s.width()).sum();
+                if used + age_span.width() <= available_width.saturating_sub(2) {
+                    header_spans.push(age_span);
                 }
-
-                lines.push(Line::from("")); // Spacing
             }
 
-            // Recurse into replies if not collapsed
-            if !is_collapsed && !comment.replies.is_empty() {
-                prefix_stack.push(!is_last);
-                flatten_comments(
-                    &comment.replies,
-                    lines,
-                    depth + 1,
-                    prefix_stack,
-                    app,
-                    selected_id,
-                    visible_count,
-                    available_width,
-                );
-                prefix_stack.pop();
+            lines.push(Line::from(header_spans));
+
+            // Build content prefix (continues the tree lines)
+            let content_prefix = format!("  {}", continuation); // space for selection marker
+
+            // Calculate text width: total - prefix - 2 for border, capped so
+            // long comments stay readable on wide terminals
+            let text_width = available_width
+                .saturating_sub(content_prefix.len())
+                .saturating_sub(2)
+                .min(app.max_comment_width.unwrap_or(usize::MAX));
+
+            // Render content lines with manual wrapping
+            for content_line in comment.content.lines() {
+                for wrapped_line in wrap_text(content_line, text_width) {
+                    lines.push(Line::from(vec![
+                        Span::styled(content_prefix.clone(), Style::default().fg(Color::DarkGray)),
+                        Span::styled(wrapped_line, Style::default().fg(Color::White)),
+                    ]));
+                }
             }
+
+            lines.push(Line::from("")); // Spacing
         }
     }
 
-    let mut prefix_stack = Vec::new();
-    flatten_comments(
-        &app.comments,
-        &mut lines,
-        0,
-        &mut prefix_stack,
-        app,
-        &selected_id,
-        &mut visible_comment_count,
-        area.width as usize,
-    );
-
     // Apply scroll based on selected comment to keep it visible
     let visible_height = area.height.saturating_sub(2) as usize; // account for borders
     let lines_per_comment = 3; // header + avg content + spacing
@@ -368,8 +559,16 @@ fn render_comments(frame: &mut Frame, app: &App, area: Rect) {
     let comments_widget = Paragraph::new(visible_lines).block(
         Block::default()
             .title(format!(
-                "Comments ({}) - j/k: nav, Enter: collapse",
-                count_total_comments(&app.comments)
+                "Comments ({}, {}{}) - {}: nav, {}: collapse, v: view",
+                count_total_comments(&app.comments),
+                app.comment_view.as_str(),
+                if app.min_comment_score > 0 {
+                    format!(", score ≥ {}", app.min_comment_score)
+                } else {
+                    String::new()
+                },
+                app.keymap.label("j/k"),
+                app.keymap.label("Enter")
             ))
             .borders(Borders::ALL)
             .border_style(Style::default().fg(MOLTBOOK_RED)),
@@ -4,7 +4,10 @@ use crate::config::RowDisplay;
 use crate::ui::colors::{MOLTBOOK_RED, MOLTBOOK_TEAL, MOLTBOOK_YELLOW};
 use crate::ui::header::render_shared_header;
 use crate::ui::overlays::render_error;
-use crate::ui::utils::{format_number_with_commas, humanize_date};
+use crate::ui::utils::{
+    claimed_status_span, format_number_with_commas, humanize_date, nav_keys_label,
+    render_empty_list, verified_badge_span,
+};
 
 use ratatui::{
     layout::{Constraint, Direction, Layout},
@@ -14,7 +17,7 @@ use ratatui::{
     Frame,
 };
 
-pub fn render_recent_agents(frame: &mut Frame, app: &App) {
+pub fn render_recent_agents(frame: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -25,16 +28,11 @@ pub fn render_recent_agents(frame: &mut Frame, app: &App) {
         .split(frame.area());
 
     // Header with ANSI logo and nav tabs
-    let header = Paragraph::new(render_shared_header(Screen::RecentAgents, app)).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(MOLTBOOK_RED)),
-    );
-    frame.render_widget(header, chunks[0]);
+    render_shared_header(frame, app, Screen::RecentAgents, chunks[0]);
 
     // Recent agents list
-    let items: Vec<ListItem> = app
-        .recent_agents
+    let visible_agents = app.visible_recent_agents();
+    let items: Vec<ListItem> = visible_agents
         .iter()
         .enumerate()
         .map(|(i, agent)| {
@@ -56,7 +54,7 @@ pub fn render_recent_agents(frame: &mut Frame, app: &App) {
                 .as_ref()
                 .and_then(|o| o.x_verified)
                 .unwrap_or(false);
-            let verified_badge = if verified { "★" } else { "" };
+            let verified_badge = verified_badge_span(app, verified);
 
             let x_handle = agent
                 .owner
@@ -65,8 +63,6 @@ pub fn render_recent_agents(frame: &mut Frame, app: &App) {
                 .map(|h| format!("@{}", h))
                 .unwrap_or_default();
 
-            let claimed = if agent.is_claimed { "" } else { " (unclaimed)" };
-
             // Line 1: Rank + Name
             let name_line = Line::from(vec![
                 Span::styled(format!(" {:>2}  ", rank), rank_style),
@@ -76,9 +72,9 @@ pub fn render_recent_agents(frame: &mut Frame, app: &App) {
             // Line 2: Verified badge + Handle + Claimed status
             let handle_line = Line::from(vec![
                 Span::raw("     "),
-                Span::styled(verified_badge, Style::default().fg(MOLTBOOK_TEAL)),
+                verified_badge,
                 Span::styled(x_handle, Style::default().fg(MOLTBOOK_TEAL)),
-                Span::styled(claimed, Style::default().fg(MOLTBOOK_TEAL)),
+                claimed_status_span(agent.is_claimed),
             ]);
 
             // Line 3: Karma + Creation time
@@ -95,36 +91,57 @@ pub fn render_recent_agents(frame: &mut Frame, app: &App) {
             ]);
 
             // Build lines based on row_display setting
-            let lines = match app.row_display {
-                RowDisplay::Compact => vec![name_line, karma_line, Line::from("")],
-                RowDisplay::Normal => vec![name_line, handle_line, karma_line, Line::from("")],
-                RowDisplay::Comfortable => vec![name_line, handle_line, karma_line, Line::from("")],
+            let mut lines = match app.row_display {
+                RowDisplay::Dense | RowDisplay::Compact => vec![name_line, karma_line],
+                RowDisplay::Normal => vec![name_line, handle_line, karma_line],
+                RowDisplay::Comfortable => vec![name_line, handle_line, karma_line],
             };
+            if !app.dense_spacing {
+                lines.push(Line::from(""));
+            }
             ListItem::new(lines)
         })
         .collect();
 
     let list_block = Block::default()
-        .title(format!("Recent Agents ({})", app.recent_agents.len()))
+        .title(format!("Recent Agents ({})", visible_agents.len()))
         .borders(Borders::ALL)
         .border_style(Style::default().fg(MOLTBOOK_RED));
 
-    let list = List::new(items)
-        .block(list_block)
-        .highlight_style(Style::default().bg(Color::Rgb(30, 30, 30)));
-
-    let mut list_state = ListState::default();
-    list_state.select(Some(app.recent_selected));
-    frame.render_stateful_widget(list, chunks[1], &mut list_state);
+    if items.is_empty() && !app.is_loading {
+        let hint = if app.recent_agents.is_empty() {
+            "Press r to retry"
+        } else {
+            "Every recent agent is unclaimed. Press u to show them"
+        };
+        render_empty_list(frame, list_block, chunks[1], "No recent agents", hint);
+    } else {
+        let list = List::new(items)
+            .block(list_block)
+            .highlight_style(Style::default().bg(Color::Rgb(30, 30, 30)));
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(app.recent_selected));
+        frame.render_stateful_widget(list, chunks[1], &mut list_state);
+    }
 
     // Footer
-    let footer = Paragraph::new("j/k: Nav • 1-7: Screens • ?: Help")
-        .style(Style::default().fg(Color::DarkGray))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(MOLTBOOK_RED)),
-        );
+    let unclaimed_hint = if app.hide_unclaimed {
+        "u: Show unclaimed"
+    } else {
+        "u: Hide unclaimed"
+    };
+    let footer = Paragraph::new(app.keymap.hint(&format!(
+        "j/k: Nav • {} • {}: Screens • ?: Help",
+        unclaimed_hint,
+        nav_keys_label(app)
+    )))
+    .style(Style::default().fg(Color::DarkGray))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(MOLTBOOK_RED)),
+    );
     frame.render_widget(footer, chunks[2]);
 
     if app.error_message.is_some() {
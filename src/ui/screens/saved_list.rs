@@ -0,0 +1,136 @@
+//! The History and Bookmarks lists, laid out like the feed: a title line, then
+//! a meta line
+
+use crate::api::Post;
+use crate::app::{App, Screen};
+
+use crate::ui::colors::{MOLTBOOK_RED, MOLTBOOK_TEAL};
+use crate::ui::header::render_shared_header;
+use crate::ui::overlays::render_error;
+use crate::ui::utils::{format_number_with_commas, humanize_date, submolt_label};
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Title and meta styles for a row, lighter on the selected one (the DarkGray
+/// highlight hides DarkGray text)
+fn row_styles(is_selected: bool) -> (Style, Style) {
+    if is_selected {
+        (
+            Style::default()
+                .fg(MOLTBOOK_TEAL)
+                .add_modifier(Modifier::BOLD),
+            Style::default().fg(Color::Gray),
+        )
+    } else {
+        (
+            Style::default().fg(Color::White),
+            Style::default().fg(Color::DarkGray),
+        )
+    }
+}
+
+/// Row for `post`; `with_comments` adds its comment count to the meta line
+pub fn post_row(post: &Post, is_selected: bool, with_comments: bool) -> Vec<Line<'static>> {
+    let (title_style, meta_style) = row_styles(is_selected);
+
+    let mut meta = vec![
+        Span::raw("    "),
+        Span::styled(submolt_label(post), Style::default().fg(MOLTBOOK_TEAL)),
+    ];
+    if let Some(author) = &post.author {
+        meta.push(Span::styled(format!(" • u/{}", author.name), meta_style));
+    }
+    let mut details = format!(
+        " • {} • {} pts",
+        humanize_date(&post.created_at),
+        format_number_with_commas(post.score())
+    );
+    if with_comments {
+        details.push_str(&format!(
+            " • {} comments",
+            format_number_with_commas(post.comment_count)
+        ));
+    }
+    meta.push(Span::styled(details, meta_style));
+
+    vec![
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled(post.title.clone(), title_style),
+        ]),
+        Line::from(meta),
+    ]
+}
+
+/// Row with `title` over a plain `meta` line
+pub fn text_row(title: String, meta: &'static str, is_selected: bool) -> Vec<Line<'static>> {
+    let (title_style, meta_style) = row_styles(is_selected);
+    vec![
+        Line::from(vec![Span::raw("  "), Span::styled(title, title_style)]),
+        Line::from(vec![Span::raw("    "), Span::styled(meta, meta_style)]),
+    ]
+}
+
+/// Draw `screen` as the shared header, `rows` in a list titled `title` with
+/// `selected` highlighted, and `footer` below
+pub fn render_saved_list(
+    frame: &mut Frame,
+    app: &mut App,
+    screen: Screen,
+    rows: Vec<Vec<Line<'static>>>,
+    selected: usize,
+    title: String,
+    footer: &str,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(11), // Logo + tagline + stats + nav tabs
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .split(frame.area());
+
+    render_shared_header(frame, app, screen, chunks[0]);
+
+    let items: Vec<ListItem> = rows
+        .into_iter()
+        .map(|mut lines| {
+            if !app.dense_spacing {
+                lines.push(Line::from(""));
+            }
+            ListItem::new(lines)
+        })
+        .collect();
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(MOLTBOOK_RED)),
+        )
+        .highlight_style(Style::default().bg(Color::Rgb(30, 30, 30)));
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(selected));
+    frame.render_stateful_widget(list, chunks[1], &mut list_state);
+
+    let footer = Paragraph::new(app.keymap.hint(footer))
+        .style(Style::default().fg(Color::DarkGray))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(MOLTBOOK_RED)),
+        );
+    frame.render_widget(footer, chunks[2]);
+
+    if app.error_message.is_some() {
+        render_error(frame, app);
+    }
+}
@@ -4,6 +4,7 @@ use crate::config::RowDisplay;
 use crate::ui::colors::{MOLTBOOK_RED, MOLTBOOK_TEAL};
 use crate::ui::header::render_shared_header;
 use crate::ui::overlays::render_error;
+use crate::ui::utils::nav_keys_label;
 
 use ratatui::{
     layout::{Constraint, Direction, Layout},
@@ -13,7 +14,7 @@ use ratatui::{
     Frame,
 };
 
-pub fn render_settings(frame: &mut Frame, app: &App) {
+pub fn render_settings(frame: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -24,12 +25,7 @@ pub fn render_settings(frame: &mut Frame, app: &App) {
         .split(frame.area());
 
     // Header with ANSI logo and nav tabs
-    let header = Paragraph::new(render_shared_header(Screen::Settings, app)).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(MOLTBOOK_RED)),
-    );
-    frame.render_widget(header, chunks[0]);
+    render_shared_header(frame, app, Screen::Settings, chunks[0]);
 
     // Settings content
     let make_option = |label: &str, is_selected: bool, is_current: bool| -> Span<'static> {
@@ -65,6 +61,12 @@ pub fn render_settings(frame: &mut Frame, app: &App) {
     let row_display_selected = app.settings_selected == 0;
     let row_display_line = Line::from(vec![
         Span::raw("    "),
+        make_option(
+            "Dense",
+            row_display_selected,
+            app.row_display == RowDisplay::Dense,
+        ),
+        Span::raw("  "),
         make_option(
             "Compact",
             row_display_selected,
@@ -104,6 +106,9 @@ pub fn render_settings(frame: &mut Frame, app: &App) {
         ),
     ]);
 
+    // API Key setting (index 2)
+    let api_key_selected = app.settings_selected == 2;
+
     let settings_lines = vec![
         Line::from(""),
         Line::from(vec![
@@ -144,14 +149,32 @@ pub fn render_settings(frame: &mut Frame, app: &App) {
             Style::default().fg(Color::DarkGray),
         )),
         Line::from(""),
+        Line::from(vec![
+            Span::raw("  "),
+            if api_key_selected {
+                Span::styled(
+                    "API Key",
+                    Style::default()
+                        .fg(MOLTBOOK_TEAL)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::styled("API Key", Style::default().fg(Color::White))
+            },
+        ]),
+        Line::from(Span::styled(
+            "    Enter to set or replace the key used for the following feed and reports",
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(""),
         Line::from(""),
         Line::from(vec![
             Span::styled("  ", Style::default()),
             Span::styled("←/→", Style::default().fg(MOLTBOOK_TEAL)),
             Span::styled(" Change  ", Style::default().fg(Color::DarkGray)),
-            Span::styled("j/k", Style::default().fg(MOLTBOOK_TEAL)),
+            Span::styled(app.keymap.label("j/k"), Style::default().fg(MOLTBOOK_TEAL)),
             Span::styled(" Navigate  ", Style::default().fg(Color::DarkGray)),
-            Span::styled("Esc", Style::default().fg(MOLTBOOK_TEAL)),
+            Span::styled(app.keymap.label("Esc"), Style::default().fg(MOLTBOOK_TEAL)),
             Span::styled(" Back", Style::default().fg(Color::DarkGray)),
         ]),
     ];
@@ -165,7 +188,11 @@ pub fn render_settings(frame: &mut Frame, app: &App) {
     frame.render_widget(settings_widget, chunks[1]);
 
     // Footer
-    let footer = Paragraph::new("1-7: Navigate • ?: Help • q: Quit")
+    let footer_text = app.keymap.hint(&format!(
+        "{}: Navigate • ?: Help • q: Quit",
+        nav_keys_label(app)
+    ));
+    let footer = Paragraph::new(footer_text)
         .style(Style::default().fg(Color::DarkGray))
         .block(
             Block::default()
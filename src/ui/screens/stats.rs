@@ -3,7 +3,7 @@ use crate::app::{App, Screen};
 use crate::ui::colors::{MOLTBOOK_BLUE, MOLTBOOK_RED, MOLTBOOK_TEAL, MOLTBOOK_YELLOW};
 use crate::ui::header::render_shared_header;
 use crate::ui::overlays::render_error;
-use crate::ui::utils::format_number_with_commas;
+use crate::ui::utils::{format_number_with_commas, nav_keys_label};
 
 use ratatui::{
     layout::{Constraint, Direction, Layout},
@@ -13,7 +13,7 @@ use ratatui::{
     Frame,
 };
 
-pub fn render_stats(frame: &mut Frame, app: &App) {
+pub fn render_stats(frame: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -24,12 +24,7 @@ pub fn render_stats(frame: &mut Frame, app: &App) {
         .split(frame.area());
 
     // Header with ANSI logo and nav tabs
-    let header = Paragraph::new(render_shared_header(Screen::Stats, app)).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(MOLTBOOK_RED)),
-    );
-    frame.render_widget(header, chunks[0]);
+    render_shared_header(frame, app, Screen::Stats, chunks[0]);
 
     // Stats content
     let stats_lines = if let Some(ref stats) = app.stats {
@@ -108,16 +103,33 @@ pub fn render_stats(frame: &mut Frame, app: &App) {
         vec![Line::from(""), Line::from("  Loading stats...")]
     };
 
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[1]);
+
     let stats_widget = Paragraph::new(stats_lines).block(
         Block::default()
             .title("Stats")
             .borders(Borders::ALL)
             .border_style(Style::default().fg(MOLTBOOK_RED)),
     );
-    frame.render_widget(stats_widget, chunks[1]);
+    frame.render_widget(stats_widget, columns[0]);
+
+    let network_widget = Paragraph::new(network_lines(app)).block(
+        Block::default()
+            .title("Network")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(MOLTBOOK_RED)),
+    );
+    frame.render_widget(network_widget, columns[1]);
 
     // Footer
-    let footer = Paragraph::new("1-7: Navigate • ?: Help • q: Quit")
+    let footer_text = app.keymap.hint(&format!(
+        "{}: Navigate • ?: Help • q: Quit",
+        nav_keys_label(app)
+    ));
+    let footer = Paragraph::new(footer_text)
         .style(Style::default().fg(Color::DarkGray))
         .block(
             Block::default()
@@ -130,3 +142,95 @@ pub fn render_stats(frame: &mut Frame, app: &App) {
         render_error(frame, app);
     }
 }
+
+/// Request totals and the latest request timings, to tell a slow network from a slow app
+fn network_lines(app: &App) -> Vec<Line<'static>> {
+    let Ok(metrics) = app.request_metrics.lock() else {
+        return Vec::new();
+    };
+    if metrics.total == 0 {
+        return vec![Line::from(""), Line::from("  No requests yet")];
+    }
+
+    let label =
+        |text: &str| Span::styled(format!("  {:<13}", text), Style::default().fg(Color::Gray));
+    let value = |text: String, color: Color| {
+        Span::styled(
+            text,
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )
+    };
+    let average = metrics.average_latency().unwrap_or_default().as_millis();
+    let error_color = if metrics.failures > 0 {
+        MOLTBOOK_RED
+    } else {
+        MOLTBOOK_TEAL
+    };
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            label("Requests:"),
+            value(
+                format_number_with_commas(metrics.total as i64),
+                MOLTBOOK_BLUE,
+            ),
+        ]),
+        Line::from(vec![
+            label("Avg latency:"),
+            value(format!("{} ms", average), MOLTBOOK_YELLOW),
+        ]),
+        Line::from(vec![
+            label("Errors:"),
+            value(
+                format!("{} ({:.1}%)", metrics.failures, metrics.error_rate()),
+                error_color,
+            ),
+        ]),
+        Line::from(vec![
+            label("Retries used:"),
+            value(metrics.retries.to_string(), MOLTBOOK_TEAL),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "  Recent requests",
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+
+    for record in metrics.recent() {
+        let (status, status_color) = if record.ok {
+            ("ok", MOLTBOOK_TEAL)
+        } else {
+            ("failed", MOLTBOOK_RED)
+        };
+        let retries = match record.retries {
+            0 => String::new(),
+            1 => " (1 retry)".to_string(),
+            n => format!(" ({} retries)", n),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {}  ", record.finished_at.format("%H:%M:%S")),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled(
+                format!("{:<14}", record.endpoint),
+                Style::default().fg(Color::White),
+            ),
+            Span::styled(
+                format!("{:>6} ms  ", record.latency.as_millis()),
+                Style::default().fg(MOLTBOOK_YELLOW),
+            ),
+            Span::styled(
+                format!("{}{}", status, retries),
+                Style::default().fg(status_color),
+            ),
+        ]));
+    }
+
+    lines
+}
@@ -4,13 +4,15 @@ use crate::config::RowDisplay;
 use crate::ui::colors::{MOLTBOOK_RED, MOLTBOOK_TEAL, MOLTBOOK_YELLOW};
 use crate::ui::header::render_shared_header;
 use crate::ui::overlays::{render_error, render_submolt_detail_modal};
-use crate::ui::utils::humanize_number;
+use crate::ui::utils::{humanize_number, render_empty_list};
 
 use ratatui::{
     layout::{Constraint, Direction, Layout, Margin},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Padding, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap},
+    widgets::{
+        Block, Borders, Padding, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap,
+    },
     Frame,
 };
 
@@ -25,19 +27,35 @@ pub fn render_submolts(frame: &mut Frame, app: &mut App) {
         .split(frame.area());
 
     // Header with ANSI logo and nav tabs
-    let header = Paragraph::new(render_shared_header(Screen::Submolts, app)).block(
+    render_shared_header(frame, app, Screen::Submolts, chunks[0]);
+
+    // Footer
+    let footer = Paragraph::new(
+        app.keymap
+            .hint("h/j/k/l: Nav • Enter: View Posts • Space: Details • p: Pin • ?: Help"),
+    )
+    .style(Style::default().fg(Color::DarkGray))
+    .block(
         Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(MOLTBOOK_RED)),
     );
-    frame.render_widget(header, chunks[0]);
+    frame.render_widget(footer, chunks[2]);
 
     // Count featured submolts (already sorted: featured first)
-    let featured_count = app.submolts.iter().filter(|s| s.featured_at.is_some()).count();
+    let featured_count = app
+        .submolts
+        .iter()
+        .filter(|s| s.featured_at.is_some())
+        .count();
 
     // Submolts grid with scrolling
     let title = if featured_count > 0 {
-        format!("Submolts ({}) - {} Featured", app.submolts.len(), featured_count)
+        format!(
+            "Submolts ({}) - {} Featured",
+            app.submolts.len(),
+            featured_count
+        )
     } else {
         format!("Submolts ({})", app.submolts.len())
     };
@@ -46,13 +64,27 @@ pub fn render_submolts(frame: &mut Frame, app: &mut App) {
         .borders(Borders::ALL)
         .border_style(Style::default().fg(MOLTBOOK_RED));
 
+    if app.submolts.is_empty() && !app.is_loading {
+        render_empty_list(
+            frame,
+            grid_block,
+            chunks[1],
+            "No submolts",
+            "Press r to retry or Esc to go back",
+        );
+        if app.error_message.is_some() {
+            render_error(frame, app);
+        }
+        return;
+    }
+
     let grid_area = grid_block.inner(chunks[1]);
     frame.render_widget(grid_block, chunks[1]);
 
     // Calculate dynamic row counts based on row_display
     let row_height = match app.row_display {
-        RowDisplay::Compact => 4u16,     // name + subs + minimal padding
-        RowDisplay::Normal => 6u16,      // name + 2 desc lines + subs + spacing
+        RowDisplay::Dense | RowDisplay::Compact => 4u16, // name + subs + minimal padding
+        RowDisplay::Normal => 6u16,                      // name + 2 desc lines + subs + spacing
         RowDisplay::Comfortable => 7u16, // name + 2 desc lines + subs + blank + spacing
     };
     let visible_rows = (grid_area.height / row_height).max(1) as usize;
@@ -107,19 +139,24 @@ pub fn render_submolts(frame: &mut Frame, app: &mut App) {
 
                 let desc = submolt.description.as_deref().unwrap_or("No description");
 
-                // Build name line with star for featured submolts
-                let name_line = if is_featured {
-                    Line::from(vec![
-                        Span::styled("★ ", Style::default().fg(MOLTBOOK_YELLOW)),
-                        Span::styled(format!("m/{}", submolt.name), name_style),
-                    ])
-                } else {
-                    Line::from(Span::styled(format!("m/{}", submolt.name), name_style))
-                };
+                // Build name line with the pin number for pinned submolts
+                // ('N jumps to it from the feed) and a star for featured ones
+                let mut name_spans = Vec::new();
+                if let Some(pin) = app.pinned_submolts.iter().position(|p| *p == submolt.name) {
+                    name_spans.push(Span::styled(
+                        format!("◆{} ", pin + 1),
+                        Style::default().fg(MOLTBOOK_RED),
+                    ));
+                }
+                if is_featured {
+                    name_spans.push(Span::styled("★ ", Style::default().fg(MOLTBOOK_YELLOW)));
+                }
+                name_spans.push(Span::styled(format!("m/{}", submolt.name), name_style));
+                let name_line = Line::from(name_spans);
 
                 // Build cell content based on row_display setting
                 let cell_content = match app.row_display {
-                    RowDisplay::Compact => vec![
+                    RowDisplay::Dense | RowDisplay::Compact => vec![
                         name_line,
                         Line::from(Span::styled(
                             format!("{} subs", humanize_number(submolt.subscriber_count)),
@@ -179,16 +216,6 @@ pub fn render_submolts(frame: &mut Frame, app: &mut App) {
         );
     }
 
-    // Footer
-    let footer = Paragraph::new("h/j/k/l: Nav • Enter: View Posts • Space: Details • ?: Help")
-        .style(Style::default().fg(Color::DarkGray))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(MOLTBOOK_RED)),
-        );
-    frame.render_widget(footer, chunks[2]);
-
     if app.error_message.is_some() {
         render_error(frame, app);
     }
@@ -1,9 +1,11 @@
 use crate::app::{App, Screen};
 
-use crate::ui::colors::{MOLTBOOK_RED, MOLTBOOK_TEAL, MOLTBOOK_YELLOW};
+use crate::ui::colors::{MOLTBOOK_RED, MOLTBOOK_TEAL};
 use crate::ui::header::render_shared_header;
 use crate::ui::overlays::render_error;
-use crate::ui::utils::format_follower_count;
+use crate::ui::utils::{
+    format_follower_count, nav_keys_label, render_empty_list, verified_badge_span,
+};
 
 use ratatui::{
     layout::{Constraint, Direction, Layout},
@@ -13,7 +15,7 @@ use ratatui::{
     Frame,
 };
 
-pub fn render_top_pairings(frame: &mut Frame, app: &App) {
+pub fn render_top_pairings(frame: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -24,12 +26,7 @@ pub fn render_top_pairings(frame: &mut Frame, app: &App) {
         .split(frame.area());
 
     // Header with ANSI logo and nav tabs
-    let header = Paragraph::new(render_shared_header(Screen::TopPairings, app)).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(MOLTBOOK_RED)),
-    );
-    frame.render_widget(header, chunks[0]);
+    render_shared_header(frame, app, Screen::TopPairings, chunks[0]);
 
     // Top pairings list
     let items: Vec<ListItem> = app
@@ -62,16 +59,11 @@ pub fn render_top_pairings(frame: &mut Frame, app: &App) {
                 Style::default().fg(Color::White)
             };
 
-            let verified_badge = if human.x_verified { " ★" } else { "" };
-
-            // Blank line for visual spacing
-            let blank_line = Line::from(vec![Span::raw("")]);
-
-            // Line 1: Rank + @handle + verified badge
+            // Line 1: Rank + verified badge + @handle
             let handle_line = Line::from(vec![
                 Span::styled(format!(" {:>2}  ", human.rank), rank_style),
+                verified_badge_span(app, human.x_verified),
                 Span::styled(format!("@{}", human.x_handle), name_style),
-                Span::styled(verified_badge, Style::default().fg(MOLTBOOK_YELLOW)),
             ]);
 
             // Line 2: Display name
@@ -95,7 +87,12 @@ pub fn render_top_pairings(frame: &mut Frame, app: &App) {
                 ),
             ]);
 
-            ListItem::new(vec![handle_line, display_name_line, agent_line, blank_line])
+            let mut lines = vec![handle_line, display_name_line, agent_line];
+            // Blank line for visual spacing
+            if !app.dense_spacing {
+                lines.push(Line::from(""));
+            }
+            ListItem::new(lines)
         })
         .collect();
 
@@ -104,16 +101,30 @@ pub fn render_top_pairings(frame: &mut Frame, app: &App) {
         .borders(Borders::ALL)
         .border_style(Style::default().fg(MOLTBOOK_RED));
 
-    let list = List::new(items)
-        .block(list_block)
-        .highlight_style(Style::default().bg(Color::Rgb(30, 30, 30)));
-
-    let mut list_state = ListState::default();
-    list_state.select(Some(app.top_pairings_selected));
-    frame.render_stateful_widget(list, chunks[1], &mut list_state);
+    if items.is_empty() && !app.is_loading {
+        render_empty_list(
+            frame,
+            list_block,
+            chunks[1],
+            "No pairings yet",
+            "Press r to retry or Esc to go back",
+        );
+    } else {
+        let list = List::new(items)
+            .block(list_block)
+            .highlight_style(Style::default().bg(Color::Rgb(30, 30, 30)));
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(app.top_pairings_selected));
+        frame.render_stateful_widget(list, chunks[1], &mut list_state);
+    }
 
     // Footer
-    let footer = Paragraph::new("j/k: Nav • 1-7: Screens • ?: Help")
+    let footer_text = app.keymap.hint(&format!(
+        "j/k: Nav • {}: Screens • ?: Help",
+        nav_keys_label(app)
+    ));
+    let footer = Paragraph::new(footer_text)
         .style(Style::default().fg(Color::DarkGray))
         .block(
             Block::default()
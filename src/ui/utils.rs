@@ -1,17 +1,110 @@
+use crate::api::Post;
+use crate::app::App;
+
+use super::colors::{MOLTBOOK_TEAL, MOLTBOOK_YELLOW};
+
 use chrono::{DateTime, Utc};
 use ratatui::{
-    layout::Rect,
+    layout::{Alignment, Rect},
     style::{Color, Modifier, Style},
-    text::Span,
+    text::{Line, Span},
+    widgets::{Block, Paragraph},
+    Frame,
 };
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-pub fn format_count(n: u64) -> String {
-    if n >= 1_000_000 {
-        format!("{:.1}M", n as f64 / 1_000_000.0)
-    } else if n >= 1_000 {
-        format!("{:.1}K", n as f64 / 1_000.0)
+/// `m/<name>` label for a post's submolt, falling back to `m/unknown` when the
+/// submolt is missing or has no name (e.g. it was deleted)
+pub fn submolt_label(post: &Post) -> String {
+    match post.submolt.as_ref().map(|s| s.name.trim()) {
+        Some(name) if !name.is_empty() => format!("m/{}", name),
+        _ => "m/unknown".to_string(),
+    }
+}
+
+/// Verified star shown before an X handle, empty when the owner isn't
+/// verified or the badge is turned off in the config
+pub fn verified_badge_span(app: &App, verified: bool) -> Span<'static> {
+    if !verified || !app.show_verified_badge {
+        return Span::raw("");
+    }
+    let color = app
+        .badge_color
+        .map(|(r, g, b)| Color::Rgb(r, g, b))
+        .unwrap_or(MOLTBOOK_TEAL);
+    Span::styled("★ ", Style::default().fg(color))
+}
+
+/// " (unclaimed)" marker after an agent's name or handle, empty once claimed
+pub fn claimed_status_span(is_claimed: bool) -> Span<'static> {
+    if is_claimed {
+        Span::raw("")
     } else {
-        n.to_string()
+        Span::styled(" (unclaimed)", Style::default().fg(Color::DarkGray))
+    }
+}
+
+/// Reading speed used for read time estimates
+const READ_TIME_WORDS_PER_MINUTE: usize = 200;
+
+/// Estimated minutes to read `content`, rounded to the nearest minute.
+/// Short texts (under half a minute) come out as 0
+pub fn read_time_minutes(content: &str) -> u32 {
+    let words = content.split_whitespace().count();
+    ((words + READ_TIME_WORDS_PER_MINUTE / 2) / READ_TIME_WORDS_PER_MINUTE) as u32
+}
+
+/// Number keys that switch between the header's nav tabs, e.g. "1-8"
+pub fn nav_keys_label(app: &App) -> String {
+    match app.nav_tabs.len() {
+        1 => "1".to_string(),
+        count => format!("1-{}", count),
+    }
+}
+
+/// "~N min" label for a post, or `None` for link-only and short posts
+pub fn read_time_label(post: &Post) -> Option<String> {
+    let minutes = read_time_minutes(post.content.as_deref()?);
+    (minutes > 0).then(|| format!("~{} min", minutes))
+}
+
+/// Host of a link post's URL without a leading "www.", or `None` if the URL
+/// can't be parsed
+pub fn url_domain(url: &str) -> Option<String> {
+    let (scheme, rest) = url.trim().split_once("://")?;
+    if !scheme.eq_ignore_ascii_case("http") && !scheme.eq_ignore_ascii_case("https") {
+        return None;
+    }
+    let authority = rest.split(['/', '?', '#']).next()?;
+    // Drop any credentials and the port
+    let host = authority.rsplit('@').next()?;
+    let host = host.split(':').next()?.to_ascii_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+    let valid = host.contains('.')
+        && host.split('.').all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+    valid.then(|| host.to_string())
+}
+
+/// Format a count, switching to K/M form with one decimal once it reaches
+/// `compact_threshold`
+pub fn format_count(n: u64, compact_threshold: u64) -> String {
+    if n < compact_threshold {
+        return format_number_with_commas(i64::try_from(n).unwrap_or(i64::MAX));
+    }
+    if n < 1_000 {
+        return n.to_string();
+    }
+
+    // Round to tenths before picking the unit, so 999_999 shows as "1.0M"
+    // rather than "1000.0K"
+    let tenths_k = n.saturating_add(50) / 100;
+    if tenths_k < 10_000 {
+        format!("{}.{}K", tenths_k / 10, tenths_k % 10)
+    } else {
+        let tenths_m = n.saturating_add(50_000) / 100_000;
+        format!("{}.{}M", tenths_m / 10, tenths_m % 10)
     }
 }
 
@@ -20,7 +113,7 @@ pub fn format_number_with_commas(n: i64) -> String {
     let mut result = String::new();
     let chars: Vec<char> = s.chars().collect();
     for (i, c) in chars.iter().enumerate() {
-        if i > 0 && (chars.len() - i) % 3 == 0 {
+        if i > 0 && (chars.len() - i).is_multiple_of(3) {
             result.push(',');
         }
         result.push(*c);
@@ -33,51 +126,75 @@ pub fn format_number_with_commas(n: i64) -> String {
 }
 
 pub fn humanize_number(n: i64) -> String {
-    let abs = n.abs();
-    let (value, suffix) = if abs >= 1_000_000 {
-        (abs as f64 / 1_000_000.0, "M")
-    } else if abs >= 1_000 {
-        (abs as f64 / 1_000.0, "K")
-    } else {
+    let abs = n.unsigned_abs();
+    if abs < 1_000 {
         return n.to_string();
+    }
+
+    // Round before choosing the unit and precision, so 9_999 shows as "10K"
+    // rather than "10.0K" and 999_500 as "1.0M" rather than "1000K"
+    let (unit, suffix) = if (abs + 500) / 1_000 < 1_000 {
+        (1_000, "K")
+    } else {
+        (1_000_000, "M")
     };
 
     let sign = if n < 0 { "-" } else { "" };
-    if value >= 10.0 {
-        format!("{}{:.0}{}", sign, value, suffix)
+    let tenths = (abs + unit / 20) / (unit / 10);
+    if tenths < 100 {
+        format!("{}{}.{}{}", sign, tenths / 10, tenths % 10, suffix)
     } else {
-        format!("{}{:.1}{}", sign, value, suffix)
+        format!("{}{}{}", sign, (abs + unit / 2) / unit, suffix)
     }
 }
 
-pub fn humanize_date(iso_date: &str) -> String {
-    let parsed = DateTime::parse_from_rfc3339(iso_date)
+/// Seconds since an API timestamp, or None if it doesn't parse
+fn seconds_since(iso_date: &str) -> Option<i64> {
+    let date = DateTime::parse_from_rfc3339(iso_date)
         .or_else(|_| DateTime::parse_from_str(iso_date, "%Y-%m-%dT%H:%M:%S%.fZ"))
-        .map(|dt| dt.with_timezone(&Utc));
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()?;
+    Some(Utc::now().signed_duration_since(date).num_seconds())
+}
 
-    let Ok(date) = parsed else {
-        return String::new();
-    };
+pub fn humanize_date(iso_date: &str) -> String {
+    seconds_since(iso_date)
+        .map(humanize_elapsed)
+        .unwrap_or_default()
+}
 
-    let now = Utc::now();
-    let duration = now.signed_duration_since(date);
+/// `humanize_date` plus a color for how fresh it is: yellow under an hour,
+/// white under a day. None for anything older (or unparseable), so the
+/// caller's usual metadata color applies
+pub fn humanize_date_with_color(iso_date: &str) -> (String, Option<Color>) {
+    let Some(seconds) = seconds_since(iso_date) else {
+        return (String::new(), None);
+    };
+    let color = match seconds {
+        s if s < 60 * 60 => Some(MOLTBOOK_YELLOW),
+        s if s < 24 * 60 * 60 => Some(Color::White),
+        _ => None,
+    };
+    (humanize_elapsed(seconds), color)
+}
 
-    let seconds = duration.num_seconds();
+/// "just now", "5m ago", "3h ago"... for something `seconds` old
+pub fn humanize_elapsed(seconds: i64) -> String {
     if seconds < 60 {
         return "just now".to_string();
     }
 
-    let minutes = duration.num_minutes();
+    let minutes = seconds / 60;
     if minutes < 60 {
         return format!("{}m ago", minutes);
     }
 
-    let hours = duration.num_hours();
+    let hours = minutes / 60;
     if hours < 24 {
         return format!("{}h ago", hours);
     }
 
-    let days = duration.num_days();
+    let days = hours / 24;
     if days < 30 {
         return format!("{}d ago", days);
     }
@@ -96,6 +213,31 @@ pub fn centered_fixed_rect(width: u16, height: u16, r: Rect) -> Rect {
     Rect::new(x, y, width.min(r.width), height.min(r.height))
 }
 
+/// A "nothing here" message centered in an empty list's block, with a hint on
+/// what to try next, so an empty result doesn't look like it's still loading
+pub fn render_empty_list(frame: &mut Frame, block: Block, area: Rect, message: &str, hint: &str) {
+    let top_padding = area.height.saturating_sub(2 + 3) / 2;
+    let mut lines = vec![Line::from(""); top_padding as usize];
+    lines.push(Line::from(Span::styled(
+        message.to_string(),
+        Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    )));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        hint.to_string(),
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .block(block),
+        area,
+    );
+}
+
 pub fn format_follower_count(n: i64) -> String {
     if n >= 1_000_000 {
         format!("{:.1}M", n as f64 / 1_000_000.0)
@@ -171,3 +313,170 @@ pub fn parse_simple_markdown(text: &str) -> Vec<Span<'static>> {
     }
     spans
 }
+
+/// Wraps text to fit within max_width, returning individual lines
+pub fn wrap_text(text: &str, max_width: usize) -> Vec<String> {
+    if max_width == 0 {
+        return vec![text.to_string()];
+    }
+
+    let mut result = Vec::new();
+    for line in text.lines() {
+        if line.is_empty() {
+            result.push(String::new());
+            continue;
+        }
+
+        let mut current_line = String::new();
+        for word in line.split_whitespace() {
+            if current_line.is_empty() {
+                // First word - take it even if too long
+                current_line = word.to_string();
+            } else if current_line.len() + 1 + word.len() <= max_width {
+                // Word fits with space
+                current_line.push(' ');
+                current_line.push_str(word);
+            } else {
+                // Word doesn't fit, start new line
+                result.push(current_line);
+                current_line = word.to_string();
+            }
+        }
+        if !current_line.is_empty() {
+            result.push(current_line);
+        }
+    }
+
+    if result.is_empty() {
+        result.push(String::new());
+    }
+    result
+}
+
+/// `text` padded with spaces to exactly `width` terminal columns, or cut to
+/// fit with a trailing `…`. Wide characters such as CJK and emoji count as two
+pub fn fit_to_width(text: &str, width: usize) -> String {
+    let mut fitted = if text.width() > width {
+        let mut used = 0;
+        let cut: String = text
+            .chars()
+            .take_while(|c| {
+                used += c.width().unwrap_or(0);
+                used < width
+            })
+            .collect();
+        format!("{}…", cut.trim_end())
+    } else {
+        text.to_string()
+    };
+    let padding = width.saturating_sub(fitted.width());
+    fitted.push_str(&" ".repeat(padding));
+    fitted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn humanize_number_below_a_thousand_is_unchanged() {
+        assert_eq!(humanize_number(0), "0");
+        assert_eq!(humanize_number(999), "999");
+        assert_eq!(humanize_number(-999), "-999");
+    }
+
+    #[test]
+    fn humanize_number_thousands() {
+        assert_eq!(humanize_number(1_000), "1.0K");
+        assert_eq!(humanize_number(1_050), "1.1K");
+        assert_eq!(humanize_number(9_949), "9.9K");
+        assert_eq!(humanize_number(9_950), "10K");
+        assert_eq!(humanize_number(9_999), "10K");
+        assert_eq!(humanize_number(10_000), "10K");
+        assert_eq!(humanize_number(999_499), "999K");
+    }
+
+    #[test]
+    fn humanize_number_millions() {
+        assert_eq!(humanize_number(999_500), "1.0M");
+        assert_eq!(humanize_number(1_000_000), "1.0M");
+        assert_eq!(humanize_number(9_950_000), "10M");
+        assert_eq!(humanize_number(25_400_000), "25M");
+    }
+
+    #[test]
+    fn humanize_number_negative() {
+        assert_eq!(humanize_number(-1_500), "-1.5K");
+        assert_eq!(humanize_number(-9_999), "-10K");
+        assert_eq!(humanize_number(-999_500), "-1.0M");
+    }
+
+    #[test]
+    fn fit_to_width_pads_short_text() {
+        assert_eq!(fit_to_width("hi", 5), "hi   ");
+        assert_eq!(fit_to_width("日本", 5), "日本 ");
+    }
+
+    #[test]
+    fn fit_to_width_cuts_by_display_width() {
+        assert_eq!(fit_to_width("hello world", 8), "hello w…");
+        // Each of these is two columns wide, so three and the ellipsis fill 7
+        assert_eq!(fit_to_width("日本語のタイトル", 8), "日本語… ");
+        assert_eq!(fit_to_width("🦀🦀🦀🦀🦀", 6), "🦀🦀… ");
+        assert_eq!(fit_to_width("🦀🦀🦀🦀🦀", 6).width(), 6);
+    }
+
+    #[test]
+    fn url_domain_strips_www_credentials_and_port() {
+        assert_eq!(
+            url_domain("https://www.example.com/path?q=1#top").as_deref(),
+            Some("example.com")
+        );
+        assert_eq!(
+            url_domain("http://user:p@ss@Docs.Example.org:8080/a").as_deref(),
+            Some("docs.example.org")
+        );
+        assert_eq!(
+            url_domain("  HTTPS://news.ycombinator.com  ").as_deref(),
+            Some("news.ycombinator.com")
+        );
+        // Only a leading "www." is dropped
+        assert_eq!(url_domain("https://wwwx.io").as_deref(), Some("wwwx.io"));
+    }
+
+    #[test]
+    fn url_domain_rejects_other_schemes() {
+        assert_eq!(url_domain("ftp://files.example.com/x"), None);
+        assert_eq!(url_domain("javascript://example.com/%0aalert(1)"), None);
+        assert_eq!(url_domain("mailto:someone@example.com"), None);
+    }
+
+    #[test]
+    fn url_domain_rejects_garbage() {
+        assert_eq!(url_domain(""), None);
+        assert_eq!(url_domain("not a url"), None);
+        assert_eq!(url_domain("https://"), None);
+        assert_eq!(url_domain("https://localhost:3000"), None);
+        assert_eq!(url_domain("https://exa mple.com"), None);
+        assert_eq!(url_domain("https://example..com"), None);
+        assert_eq!(url_domain("https://[::1]:8080/"), None);
+    }
+
+    #[test]
+    fn format_count_rolls_over_to_the_next_unit() {
+        assert_eq!(format_count(999_999, 1_000), "1.0M");
+        assert_eq!(format_count(999_949, 1_000), "999.9K");
+        assert_eq!(format_count(1_234, 1_000), "1.2K");
+        assert_eq!(format_count(999, 1_000), "999");
+        // Below the threshold counts stay exact
+        assert_eq!(format_count(999_999, 1_000_000), "999,999");
+    }
+
+    #[test]
+    fn format_count_keeps_a_decimal_on_two_digit_values() {
+        assert_eq!(format_count(12_345, 1_000), "12.3K");
+        assert_eq!(format_count(99_960, 1_000), "100.0K");
+        assert_eq!(format_count(25_400_000, 1_000), "25.4M");
+        assert_eq!(format_count(10_000_000, 1_000), "10.0M");
+    }
+}